@@ -0,0 +1,90 @@
+// เทียบเวลาคำนวณตำแหน่งดาวทีละดวง (Planet::longitude) กับคำนวณสถานะโลกครั้งเดียวต่อเวลา (SolarState)
+// cargo bench --bench positions
+//
+// ตารางดาวรายวัน: ทุกดวงพร้อมสถานะการเดิน วันละครั้งตลอดหนึ่งปี
+// การค้นหา: ราศีของทุกดวงทุก 6 ชั่วโมงตลอดสิบปี (แบบที่ ingress ไล่หา) และ find_ingresses หนึ่งปี
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use horathai::ephemeris::ephemeris;
+use horathai::ingress::find_ingresses;
+use horathai::motion::{motion_state, MotionState, MotionThresholds};
+use horathai::planet::Planet;
+use horathai::{calculate_bodies, calculate_jd, SolarState};
+
+const ROUNDS: usize = 5;
+
+// เวลาที่เร็วที่สุดจากหลายรอบ ลดผลของงานอื่นในเครื่อง
+fn best_of(mut work: impl FnMut()) -> Duration {
+    (0..ROUNDS).map(|_| {
+        let start = Instant::now();
+        work();
+        start.elapsed()
+    }).min().unwrap()
+}
+
+fn report(name: &str, per_planet: Duration, shared: Duration) {
+    println!(
+        "{:<28} {:>10.2} ms {:>10.2} ms {:>8.1}x",
+        name,
+        per_planet.as_secs_f64() * 1000.0,
+        shared.as_secs_f64() * 1000.0,
+        per_planet.as_secs_f64() / shared.as_secs_f64(),
+    );
+}
+
+fn main() {
+    let thresholds = MotionThresholds::default();
+    let start = calculate_jd(1, 1, 2567, 6, 0).unwrap();
+
+    println!("{:<28} {:>13} {:>13} {:>9}", "", "ทีละดวง", "SolarState", "เร็วขึ้น");
+
+    // ตารางดาวรายวันหนึ่งปี แบบเดิมเรียกฟังก์ชันดาวสามครั้งต่อดวงต่อวัน (ตำแหน่ง และ ±12 ชั่วโมงสำหรับสถานะการเดิน)
+    let per_planet = best_of(|| {
+        for day in 0..366 {
+            let jd = start + day as f64;
+            for planet in Planet::ALL {
+                let longitude = planet.longitude(jd);
+                let motion = match planet.mean_motion() {
+                    Some(mean) => motion_state(|t| planet.longitude(t), jd, mean, &thresholds),
+                    None => MotionState::Normal,
+                };
+                black_box((longitude, motion));
+            }
+        }
+    });
+    let shared = best_of(|| {
+        for day in 0..366 {
+            black_box(calculate_bodies(start + day as f64, &thresholds));
+        }
+    });
+    report("ephemeris 366 days", per_planet, shared);
+    let table = best_of(|| {
+        black_box(ephemeris((1, 1, 2567), 366, 6, 0, &thresholds));
+    });
+    println!("{:<28} {:>27.2} ms", "ephemeris() 366 days", table.as_secs_f64() * 1000.0);
+
+    // ไล่ราศีทุก 6 ชั่วโมงสิบปี
+    let steps = 4 * 3653;
+    let sign = |longitude: f64| (longitude / 30.0) as usize % 12;
+    let per_planet = best_of(|| {
+        for i in 0..steps {
+            let jd = start + i as f64 * 0.25;
+            for planet in Planet::ALL {
+                black_box(sign(planet.longitude(jd)));
+            }
+        }
+    });
+    let shared = best_of(|| {
+        for i in 0..steps {
+            let state = SolarState::at(start + i as f64 * 0.25);
+            black_box(state.longitudes().map(sign));
+        }
+    });
+    report("sign scan 10 years", per_planet, shared);
+    let search = best_of(|| {
+        black_box(find_ingresses(&Planet::ALL, start, 366.0));
+    });
+    println!("{:<28} {:>27.2} ms", "find_ingresses() 366 days", search.as_secs_f64() * 1000.0);
+}
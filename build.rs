@@ -0,0 +1,164 @@
+// สร้าง horathai.h จาก src/ffi.rs ลงใน OUT_DIR ให้ header ตรงกับไลบรารีเสมอ
+// build script เขียนนอก OUT_DIR ไม่ได้ ไฟล์ include/horathai.h ที่แจกไปกับซอร์สจึงคัดลอกจากที่นี่
+// และ tests/header.rs ตรวจว่ายังตรงกัน
+// อ่านเฉพาะรูปแบบที่ ffi.rs ใช้: pub const, #[repr(C)] pub struct และ pub extern "C" fn
+// ความเห็น // ที่อยู่ติดกับรายการเหล่านี้ (หรือเป็นย่อหน้าแยก) คัดลอกไปใส่ header ด้วย
+
+use std::path::Path;
+
+const SOURCE: &str = "src/ffi.rs";
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", SOURCE);
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let source = std::fs::read_to_string(SOURCE).expect("อ่าน src/ffi.rs ไม่ได้");
+    let header = generate(&source);
+    let out_dir = std::env::var_os("OUT_DIR").expect("cargo ไม่ได้กำหนด OUT_DIR");
+    std::fs::write(Path::new(&out_dir).join("horathai.h"), header).expect("เขียน horathai.h ไม่ได้");
+}
+
+fn c_type(rust: &str) -> String {
+    let rust = rust.trim();
+    if let Some(inner) = rust.strip_prefix("*const ") {
+        return format!("const {} *", c_type(inner));
+    }
+    if let Some(inner) = rust.strip_prefix("*mut ") {
+        return format!("{} *", c_type(inner));
+    }
+    match rust {
+        "f64" => "double",
+        "f32" => "float",
+        "i32" => "int32_t",
+        "u32" => "uint32_t",
+        "i64" => "int64_t",
+        "u64" => "uint64_t",
+        "u8" => "uint8_t",
+        "usize" => "size_t",
+        "bool" => "bool",
+        "c_char" => "char",
+        other => other,
+    }
+    .to_string()
+}
+
+// ชื่อกับชนิดเป็นการประกาศแบบ C เช่น ("bodies", "[HorathaiBody; N]") -> "HorathaiBody bodies[N]"
+fn c_declaration(name: &str, rust: &str) -> String {
+    let rust = rust.trim();
+    if let Some((element, length)) = rust.strip_prefix('[').and_then(|r| r.strip_suffix(']')).and_then(|r| r.split_once(';')) {
+        return format!("{} {}[{}]", c_type(element), name, length.trim());
+    }
+    let c = c_type(rust);
+    if c.ends_with('*') { format!("{}{}", c, name) } else { format!("{} {}", c, name) }
+}
+
+// แยกโค้ดกับความเห็นท้ายบรรทัด
+fn split_comment(line: &str) -> (&str, Option<&str>) {
+    match line.find("//") {
+        Some(i) => (line[..i].trim_end(), Some(line[i..].trim())),
+        None => (line.trim_end(), None),
+    }
+}
+
+fn with_comment(code: String, comment: Option<&str>) -> String {
+    match comment {
+        Some(comment) => format!("{} {}", code, comment),
+        None => code,
+    }
+}
+
+fn generate(source: &str) -> String {
+    let mut output = String::from("// สร้างจาก src/ffi.rs โดย build.rs ห้ามแก้ไขไฟล์นี้ด้วยมือ\n\n");
+    output.push_str("#ifndef HORATHAI_H\n#define HORATHAI_H\n\n");
+    output.push_str("#include <stdbool.h>\n#include <stddef.h>\n#include <stdint.h>\n\n");
+    output.push_str("#ifdef __cplusplus\nextern \"C\" {\n#endif\n\n");
+
+    let lines: Vec<&str> = source.lines().collect();
+    let mut comments: Vec<&str> = Vec::new();
+    let mut repr_c = false;
+    let mut i = 0;
+    while i < lines.len() {
+        let line = lines[i].trim();
+        i += 1;
+
+        if line.starts_with("//") {
+            comments.push(line);
+            continue;
+        }
+        if line.starts_with("#!") {
+            // ความเห็นของ attribute ทั้งไฟล์ใช้เฉพาะฝั่ง Rust
+            comments.clear();
+            continue;
+        }
+        if line.starts_with("#[") {
+            repr_c |= line == "#[repr(C)]";
+            continue;
+        }
+        if line.is_empty() {
+            // ย่อหน้าความเห็นที่ไม่ติดกับรายการใด
+            if !comments.is_empty() {
+                output.push_str(&comments.join("\n"));
+                output.push_str("\n\n");
+                comments.clear();
+            }
+            continue;
+        }
+
+        let item = if let Some(rest) = line.strip_prefix("pub const ") {
+            let (code, comment) = split_comment(rest);
+            let (name, value) = code.split_once(':').and_then(|(n, r)| Some((n, r.split_once('=')?.1))).expect("pub const ต้องมีชนิดและค่า");
+            Some(with_comment(format!("#define {} {}", name.trim(), value.trim().trim_end_matches(';')), comment) + "\n")
+        } else if let (true, Some(rest)) = (repr_c, line.strip_prefix("pub struct ")) {
+            let name = rest.trim_end_matches('{').trim();
+            let mut fields = Vec::new();
+            while i < lines.len() && lines[i].trim() != "}" {
+                let (code, comment) = split_comment(lines[i].trim());
+                if let Some((field, rust)) = code.strip_prefix("pub ").and_then(|f| f.split_once(':')) {
+                    fields.push(with_comment(format!("    {};", c_declaration(field.trim(), rust.trim_end_matches(','))), comment));
+                }
+                i += 1;
+            }
+            i += 1;
+            Some(format!("typedef struct {} {{\n{}\n}} {};\n", name, fields.join("\n"), name))
+        } else if line.starts_with("pub extern \"C\" fn ") || line.starts_with("pub unsafe extern \"C\" fn ") {
+            // ลายเซ็นอาจยาวหลายบรรทัด อ่านจนถึง {
+            let mut signature = line.to_string();
+            while !signature.ends_with('{') && i < lines.len() {
+                signature.push(' ');
+                signature.push_str(lines[i].trim());
+                i += 1;
+            }
+            let signature = signature.trim_end_matches('{').trim();
+            let rest = &signature[signature.find("fn ").unwrap() + 3..];
+            let (name, rest) = rest.split_once('(').unwrap();
+            let (params, result) = rest.rsplit_once(')').unwrap();
+            let params: Vec<String> = params.split(',').map(str::trim).filter(|p| !p.is_empty()).map(|p| {
+                let (name, rust) = p.split_once(':').unwrap();
+                c_declaration(name.trim(), rust)
+            }).collect();
+            let result = result.trim().strip_prefix("->").map_or("void".to_string(), c_type);
+            let params = if params.is_empty() { "void".to_string() } else { params.join(", ") };
+            let separator = if result.ends_with('*') { "" } else { " " };
+            Some(format!("{}{}{}({});\n", result, separator, name.trim(), params))
+        } else {
+            None
+        };
+        repr_c = false;
+
+        if let Some(item) = item {
+            for comment in &comments {
+                output.push_str(comment);
+                output.push('\n');
+            }
+            output.push_str(&item);
+            // ค่าคงที่ติดกันไม่ต้องเว้นบรรทัด
+            if !item.starts_with("#define") || lines.get(i).is_some_and(|next| next.trim().is_empty()) {
+                output.push('\n');
+            }
+        }
+        comments.clear();
+    }
+
+    output.push_str("#ifdef __cplusplus\n}\n#endif\n\n#endif\n");
+    output
+}
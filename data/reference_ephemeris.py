@@ -0,0 +1,186 @@
+# สร้าง data/reference_ephemeris.csv สำหรับ horathai accuracy
+#   python3 data/reference_ephemeris.py data/reference_ephemeris.csv
+#
+# ทุก ๆ 45.6789 วัน ตั้งแต่ 1 ม.ค. 2443 ถึง 31 ธ.ค. 2643 เวลาไทย (UTC+7) แปลงเป็น TT ด้วย ΔT โดยประมาณ
+#   ดาวเคราะห์: ตำแหน่งเห็นจากโลก (แก้ light-time) จาก Keplerian elements โดยประมาณของ JPL
+#     (Standish, "Keplerian Elements for Approximate Positions of the Major Planets" ตารางที่ 1)
+#     ค่าชุดนี้ใช้ได้เฉพาะ ค.ศ. 1800-2050 แถวหลังจากนั้นเว้นช่องดาวเคราะห์ว่าง
+#   อาทิตย์และจันทร์: อนุกรมของ Meeus (Astronomical Algorithms บทที่ 25 และ 47) ราหู: จุดโหนดขึ้นเฉลี่ยของจันทร์
+#   ทุกค่าแปลงเป็นกรอบสุริยวิถีของ J2000 แล้วลบอยนางศ์ลาหิรี 23.85306 องศา
+# ไม่ใช้ไลบรารีภายนอก
+
+import math
+import sys
+
+R = math.radians
+
+# (a, e, I, L, ลองจิจูด perihelion, ลองจิจูด node) ณ J2000 และอัตราเปลี่ยนต่อศตวรรษ
+ELEMENTS = {
+    'mercury': [(0.38709927, 0.20563593, 7.00497902, 252.25032350, 77.45779628, 48.33076593),
+                (0.00000037, 0.00001906, -0.00594749, 149472.67411175, 0.16047689, -0.12534081)],
+    'venus': [(0.72333566, 0.00677672, 3.39467605, 181.97909950, 131.60246718, 76.67984255),
+              (0.00000390, -0.00004107, -0.00078890, 58517.81538729, 0.00268329, -0.27769418)],
+    'earth': [(1.00000261, 0.01671123, -0.00001531, 100.46457166, 102.93768193, 0.0),
+              (0.00000562, -0.00004392, -0.01294668, 35999.37244981, 0.32327364, 0.0)],
+    'mars': [(1.52371034, 0.09339410, 1.84969142, -4.55343205, -23.94362959, 49.55953891),
+             (0.00001847, 0.00007882, -0.00813131, 19140.30268499, 0.44441088, -0.29257343)],
+    'jupiter': [(5.20288700, 0.04838624, 1.30439695, 34.39644051, 14.72847983, 100.47390909),
+                (-0.00011607, -0.00013253, -0.00183714, 3034.74612775, 0.21252668, 0.20469106)],
+    'saturn': [(9.53667594, 0.05386179, 2.48599187, 49.95424423, 92.59887831, 113.66242448),
+               (-0.00125060, -0.00050991, 0.00193609, 1222.49362201, -0.41897216, -0.28867794)],
+    'uranus': [(19.18916464, 0.04725744, 0.77263783, 313.23810451, 170.95427630, 74.01692503),
+               (-0.00196176, -0.00004397, -0.00242939, 428.48202785, 0.40805281, 0.04240589)],
+}
+# ปี ค.ศ. สุดท้ายที่ ELEMENTS ใช้ได้
+ELEMENTS_VALID_TO = 2050
+
+LAHIRI_J2000 = 23.85306
+START = (1, 1, 2443)
+END = (31, 12, 2643)
+STEP_DAYS = 45.6789
+COLUMNS = ['sun', 'moon', 'mars', 'mercury', 'jupiter', 'venus', 'saturn', 'rahu', 'uranus']
+PLANETS = ['mars', 'mercury', 'jupiter', 'venus', 'saturn', 'uranus']
+
+
+def heliocentric(planet, t):
+    base, rate = ELEMENTS[planet]
+    a, e, inclination, mean_longitude, perihelion, node = [x + y * t for x, y in zip(base, rate)]
+    argument = perihelion - node
+    m = (mean_longitude - perihelion + 180) % 360 - 180
+    anomaly = m + math.degrees(e) * math.sin(R(m))
+    for _ in range(20):
+        delta = m - (anomaly - math.degrees(e) * math.sin(R(anomaly)))
+        anomaly += delta / (1 - e * math.cos(R(anomaly)))
+    xp = a * (math.cos(R(anomaly)) - e)
+    yp = a * math.sqrt(1 - e * e) * math.sin(R(anomaly))
+    cw, sw = math.cos(R(argument)), math.sin(R(argument))
+    co, so = math.cos(R(node)), math.sin(R(node))
+    ci, si = math.cos(R(inclination)), math.sin(R(inclination))
+    x = (cw * co - sw * so * ci) * xp + (-sw * co - cw * so * ci) * yp
+    y = (cw * so + sw * co * ci) * xp + (-sw * so + cw * co * ci) * yp
+    z = (sw * si) * xp + (cw * si) * yp
+    return x, y, z
+
+
+# ลองจิจูดเห็นจากโลก ในกรอบสุริยวิถีของ J2000
+def geocentric(planet, jd):
+    t = (jd - 2451545.0) / 36525
+    ex, ey, ez = heliocentric('earth', t)
+    light_time = 0.0
+    for _ in range(3):
+        x, y, z = heliocentric(planet, t - light_time / 36525)
+        dx, dy, dz = x - ex, y - ey, z - ez
+        light_time = 0.0057755183 * math.sqrt(dx * dx + dy * dy + dz * dz)
+    return math.degrees(math.atan2(dy, dx)) % 360
+
+
+# ค่าไอยนะรวมจาก J2000 ถึงวันนั้น ใช้ย้ายค่าของวันนั้นกลับไปกรอบ J2000
+def precession(t):
+    return (5029.0966 * t + 1.11113 * t * t) / 3600
+
+
+def sun(jd):
+    t = (jd - 2451545.0) / 36525
+    l0 = 280.46646 + 36000.76983 * t + 0.0003032 * t * t
+    m = 357.52911 + 35999.05029 * t - 0.0001537 * t * t
+    c = ((1.914602 - 0.004817 * t - 0.000014 * t * t) * math.sin(R(m))
+         + (0.019993 - 0.000101 * t) * math.sin(R(2 * m)) + 0.000289 * math.sin(R(3 * m)))
+    return (l0 + c - precession(t)) % 360
+
+
+# Meeus ตาราง 47.A: (D, M, M', F, สัมประสิทธิ์ของ sin หน่วย 1e-6 องศา)
+MOON_TERMS = [
+    (0, 0, 1, 0, 6288774), (2, 0, -1, 0, 1274027), (2, 0, 0, 0, 658314), (0, 0, 2, 0, 213618),
+    (0, 1, 0, 0, -185116), (0, 0, 0, 2, -114332), (2, 0, -2, 0, 58793), (2, -1, -1, 0, 57066),
+    (2, 0, 1, 0, 53322), (2, -1, 0, 0, 45758), (0, 1, -1, 0, -40923), (1, 0, 0, 0, -34720),
+    (0, 1, 1, 0, -30383), (2, 0, 0, -2, 15327), (0, 0, 1, 2, -12528), (0, 0, 1, -2, 10980),
+    (4, 0, -1, 0, 10675), (0, 0, 3, 0, 10034), (4, 0, -2, 0, 8548), (2, 1, -1, 0, -7888),
+    (2, 1, 0, 0, -6766), (1, 0, -1, 0, -5163), (1, 1, 0, 0, 4987), (2, -1, 1, 0, 4036),
+    (2, 0, 2, 0, 3994), (4, 0, 0, 0, 3861), (2, 0, -3, 0, 3665), (0, 1, -2, 0, -2689),
+    (2, 0, -1, 2, -2602), (2, -1, -2, 0, 2390), (1, 0, 1, 0, -2348), (2, -2, 0, 0, 2236),
+    (0, 1, 2, 0, -2120), (0, 2, 0, 0, -2069), (2, -2, -1, 0, 2048), (2, 0, 1, -2, -1773),
+    (2, 0, 0, 2, -1595), (4, -1, -1, 0, 1215), (0, 0, 2, 2, -1110), (3, 0, -1, 0, -892),
+    (2, 1, 1, 0, -810), (4, -1, -2, 0, 759), (0, 2, -1, 0, -713), (2, 2, -1, 0, -700),
+    (2, 1, -2, 0, 691), (2, -1, 0, -2, 596), (4, 0, 1, 0, 549), (0, 0, 4, 0, 537),
+    (4, -1, 0, 0, 520), (1, 0, -2, 0, -487), (2, 1, 0, -2, -399), (0, 0, 2, -2, -381),
+    (1, 1, 1, 0, 351), (3, 0, -2, 0, -340), (4, 0, -3, 0, 330), (2, -1, 2, 0, 327),
+    (0, 2, 1, 0, -323), (1, 1, -1, 0, 299), (2, 0, 3, 0, 294),
+]
+
+
+def moon(jd):
+    t = (jd - 2451545.0) / 36525
+    mean_longitude = 218.3164477 + 481267.88123421 * t - 0.0015786 * t * t
+    d = 297.8501921 + 445267.1114034 * t - 0.0018819 * t * t
+    m = 357.5291092 + 35999.0502909 * t - 0.0001536 * t * t
+    mp = 134.9633964 + 477198.8675055 * t + 0.0087414 * t * t
+    f = 93.2720950 + 483202.0175233 * t - 0.0036539 * t * t
+    e = 1 - 0.002516 * t - 0.0000074 * t * t
+    total = 0
+    for cd, cm, cmp, cf, coefficient in MOON_TERMS:
+        total += coefficient * e ** abs(cm) * math.sin(R(cd * d + cm * m + cmp * mp + cf * f))
+    a1 = 119.75 + 131.849 * t
+    a2 = 53.09 + 479264.290 * t
+    total += 3958 * math.sin(R(a1)) + 1962 * math.sin(R(mean_longitude - f)) + 318 * math.sin(R(a2))
+    return (mean_longitude + total / 1e6 - precession(t)) % 360
+
+
+def rahu(jd):
+    t = (jd - 2451545.0) / 36525
+    return (125.0445479 - 1934.1362891 * t + 0.0020754 * t * t - precession(t)) % 360
+
+
+# ΔT (วินาที แปลงเป็นวัน) สูตรพาราโบลาของ Morrison และ Stephenson
+def delta_t(year):
+    u = (year - 1820) / 100
+    return (-20 + 32 * u * u) / 86400
+
+
+# Julian Day ของเวลานาฬิกา (ไม่แปลงเขตเวลา) เหมือน julian_day ใน src/lib.rs ช่วงนี้เป็นปฏิทินเกรกอเรียนทั้งหมด
+def julian_day(d, m, y_be, hour, minute):
+    y = y_be - 543
+    if m <= 2:
+        y -= 1
+        m += 12
+    a = y // 100
+    b = 2 - a + a // 4
+    return math.floor(365.25 * (y + 4716)) + math.floor(30.6001 * (m + 1)) + d + b - 1524.5 + hour / 24 + minute / 1440
+
+
+# ตรงข้ามกับ julian_day ปัดเป็นนาทีที่ใกล้ที่สุด เหมือน jd_to_date
+def date_from_jd(jd):
+    z, minute = divmod(round((jd + 0.5) * 1440), 1440)
+    alpha = math.floor((z - 1867216.25) / 36524.25)
+    a = z + 1 + alpha - alpha // 4
+    b = a + 1524
+    c = math.floor((b - 122.1) / 365.25)
+    d = math.floor(365.25 * c)
+    e = math.floor((b - d) / 30.6001)
+    day = b - d - math.floor(30.6001 * e)
+    month = e - 1 if e < 14 else e - 13
+    year = c - 4716 if month > 2 else c - 4715
+    return day, month, year + 543, minute // 60, minute % 60
+
+
+def main(path):
+    rows = ['date,time,' + ','.join(COLUMNS)]
+    jd = julian_day(*START, 0, 0) + 0.25
+    end = julian_day(*END, 23, 59)
+    while jd < end:
+        day, month, year_be, hour, minute = date_from_jd(jd)
+        year = year_be - 543
+        thai = julian_day(day, month, year_be, hour, minute)
+        tt = thai - 7 / 24 + delta_t(year + month / 12)
+        values = {'sun': sun(tt), 'moon': moon(tt), 'rahu': rahu(tt)}
+        if year <= ELEMENTS_VALID_TO:
+            for planet in PLANETS:
+                values[planet] = geocentric(planet, tt)
+        cells = ['' if key not in values else f'{(values[key] - LAHIRI_J2000) % 360:.3f}' for key in COLUMNS]
+        rows.append(f'{day}/{month}/{year_be},{hour:02}:{minute:02},' + ','.join(cells))
+        jd += STEP_DAYS
+    with open(path, 'w', newline='\n') as output:
+        output.write('\n'.join(rows) + '\n')
+
+
+if __name__ == '__main__':
+    main(sys.argv[1] if len(sys.argv) > 1 else 'data/reference_ephemeris.csv')
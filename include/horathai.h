@@ -0,0 +1,95 @@
+// สร้างจาก src/ffi.rs โดย build.rs ห้ามแก้ไขไฟล์นี้ด้วยมือ
+
+#ifndef HORATHAI_H
+#define HORATHAI_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// ส่วนเชื่อมต่อภาษา C สำหรับโปรแกรมที่ไม่ได้เขียนด้วย Rust (เช่น C++)
+// build.rs อ่าน src/ffi.rs แล้วสร้าง horathai.h ใน OUT_DIR ทุกครั้งที่ build
+// แก้ไฟล์นี้แล้วให้คัดลอกไปแทน include/horathai.h ด้วย (tests/header.rs ตรวจว่าตรงกัน)
+//
+// การจัดการหน่วยความจำ:
+//   ผลลัพธ์ทุกตัวเป็น struct ที่ผู้เรียกจองเอง (บน stack ได้) แล้วส่ง pointer มาให้เขียน
+//   ไลบรารีไม่ malloc ให้ จึงไม่มีอะไรต้อง free
+//   ข้อความ (const char *) ชี้ไปยังข้อมูลคงที่ในไลบรารี ใช้ได้ตลอดอายุโปรแกรม ห้าม free
+//   ข้อความทั้งหมดเป็น UTF-8
+// ทุกฟังก์ชันที่คืน int32_t คืน HORATHAI_OK หรือรหัสข้อผิดพลาด และไม่แตะ out เมื่อผิดพลาด
+// ดาวระบุด้วยเลขประจำดาวแบบไทย ๑ อาทิตย์ ... ๙ เกตุ ๐ มฤตยู
+
+#define HORATHAI_OK 0
+#define HORATHAI_ERROR_NULL_POINTER 1
+#define HORATHAI_ERROR_INVALID_DATE 2
+#define HORATHAI_ERROR_INVALID_TIME 3
+#define HORATHAI_ERROR_INVALID_COORDINATES 4
+#define HORATHAI_ERROR_UNKNOWN_PLANET 5
+#define HORATHAI_ERROR_UNKNOWN_PROVINCE 6
+#define HORATHAI_ERROR_YEAR_OUT_OF_RANGE 7 // ปี พ.ศ. นอกช่วงที่คำนวณได้ (ไม่เกิน 10542)
+
+// สถานะการเดินของดาวใน HorathaiBody.motion
+#define HORATHAI_MOTION_NORMAL 0
+#define HORATHAI_MOTION_RETROGRADE 1 // พักร์
+#define HORATHAI_MOTION_SLOW 2 // มนฑ์
+#define HORATHAI_MOTION_FAST 3 // เสริด
+
+#define HORATHAI_PLANET_COUNT 10
+
+typedef struct HorathaiBody {
+    int32_t planet; // เลขประจำดาว 1-9 และ 0
+    double longitude; // องศานับจาก 0 เมษ (0-360)
+    int32_t motion; // HORATHAI_MOTION_*
+} HorathaiBody;
+
+typedef struct HorathaiChart {
+    double jd; // Julian Day ตามเวลาไทย (UTC+7)
+    double local_mean_minutes; // เวลาท้องถิ่น นาทีนับจากเที่ยงคืน
+    double lagna; // ลองจิจูดลัคนา
+    int32_t weekday_civil; // วันตามปฏิทิน 0 = อาทิตย์
+    int32_t weekday_thai; // วันทางโหราศาสตร์ (เปลี่ยนตอนอาทิตย์ขึ้น)
+    int32_t rahu_night; // 1 = พุธกลางคืน (ราหู)
+    HorathaiBody bodies[HORATHAI_PLANET_COUNT]; // เรียงตามเลขดาว 1-9 และ 0
+} HorathaiChart;
+
+typedef struct HorathaiProvince {
+    const char *key; // ชื่อที่ใช้ค้น เช่น "bangkok"
+    const char *name; // ชื่อไทย
+    const char *english_name;
+    double latitude;
+    double longitude;
+} HorathaiProvince;
+
+// Julian Day (เวลาไทย) ของวันเวลาปี พ.ศ. สำหรับส่งต่อให้ horathai_planet_longitude
+int32_t horathai_julian_day(uint32_t day, uint32_t month, uint32_t year_be, uint32_t hour, uint32_t minute, double *out);
+
+// ลองจิจูดของดาวหนึ่งดวง (เลขประจำดาว 0-9) ณ Julian Day ใด ๆ
+int32_t horathai_planet_longitude(int32_t planet, double jd, double *out);
+
+// ลองจิจูดของดาวทุกดวง ณ Julian Day เดียว เร็วกว่าเรียก horathai_planet_longitude ทีละดวง
+// out ต้องมีที่ว่าง HORATHAI_PLANET_COUNT ค่า เรียงตามเลขดาว 1-9 และ 0 เหมือน HorathaiChart.bodies
+int32_t horathai_positions(double jd, double *out);
+
+// ดวงชะตาทั้งดวงจากวันเวลาเกิด (ปี พ.ศ. เวลาไทย) และพิกัดที่เกิด
+int32_t horathai_chart(uint32_t day, uint32_t month, uint32_t year_be, uint32_t hour, uint32_t minute, double latitude, double longitude, HorathaiChart *out);
+
+size_t horathai_province_count(void);
+
+// จังหวัดลำดับที่ index (0 ถึง horathai_province_count() - 1)
+int32_t horathai_province_at(size_t index, HorathaiProvince *out);
+
+// ค้นจังหวัดจาก key ชื่อไทย หรือชื่ออังกฤษ (ไม่สนตัวพิมพ์เล็ก/ใหญ่)
+int32_t horathai_province_find(const char *name, HorathaiProvince *out);
+
+// คำอธิบายรหัสข้อผิดพลาด (ภาษาอังกฤษ ข้อความคงที่ ห้าม free)
+const char *horathai_error_message(int32_t code);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
@@ -0,0 +1,268 @@
+// ความแม่นของฟังก์ชันตำแหน่งดาว เทียบกับตารางอ้างอิงความละเอียดสูง
+// บอกความคลาดเคลื่อนสูงสุด เฉลี่ย และ RMS ของดาวแต่ละดวง ทั้งช่วงและแยกทีละสิบปี (พ.ศ. 2440-2449, 2450-2459, ...)
+// เพื่อให้รู้ว่าดาวใดเชื่อได้ในช่วงปีใด
+//
+// ตารางที่แนบมา data/reference_ephemeris.csv สร้างด้วย data/reference_ephemeris.py
+// ทุก ๆ 45.68 วัน ตั้งแต่ 1 ม.ค. 2443 ถึง 31 ธ.ค. 2643 เวลาไทย
+//   ดาวเคราะห์: ตำแหน่งเห็นจากโลก (แก้ light-time) จาก Keplerian elements โดยประมาณของ JPL (Standish)
+//     ซึ่งใช้ได้เฉพาะ ค.ศ. 1800-2050 จึงมีค่าถึง พ.ศ. 2593 เท่านั้น รายงานบอกช่วงที่ตรวจได้จริงของดาวเหล่านี้
+//   อาทิตย์และจันทร์: อนุกรมของ Meeus (Astronomical Algorithms บทที่ 25 และ 47) ราหู: จุดโหนดขึ้นเฉลี่ยของจันทร์
+//   แปลงเป็นนิรายนะด้วยอยนางศ์ลาหิรี (23.853 องศา ณ J2000) ตารางเองคลาดไม่เกินราว 0.01 องศาสำหรับอาทิตย์ จันทร์ ราหู
+//   และ 0.2 องศาสำหรับดาวเคราะห์ เกตุไทยไม่มีค่าทางดาราศาสตร์ให้เทียบ
+// ใช้ตารางอื่นแทนได้ (เช่นที่สร้างจาก JPL DE440) ถ้าคอลัมน์เป็น date, time (เวลาไทย) แล้วตามด้วยชื่อดาวตาม Planet::key()
+// ช่องว่างคือไม่มีค่าอ้างอิงของดาวนั้น ณ เวลานั้น
+
+use crate::csv;
+use crate::json::Value;
+use crate::locale::{Lang, Message};
+use crate::planet::Planet;
+use crate::table::{Align, Table};
+use crate::{julian_day, thaidate};
+
+pub const BUNDLED_REFERENCE: &str = include_str!("../data/reference_ephemeris.csv");
+
+pub struct ReferenceRow {
+    pub date: (u32, u32, u32), // วัน เดือน ปี พ.ศ.
+    pub time: (u32, u32),
+    pub longitudes: Vec<Option<f64>>, // เรียงตาม Reference::planets None คือช่องว่าง
+}
+
+pub struct Reference {
+    pub planets: Vec<Planet>,
+    pub rows: Vec<ReferenceRow>,
+}
+
+#[derive(Debug)]
+pub enum ReferenceError {
+    NotReferenceTable, // ว่าง หรือไม่ขึ้นต้นด้วยคอลัมน์ date, time
+    UnknownColumn(String),
+    InvalidField { line: usize, column: String },
+}
+
+impl ReferenceError {
+    // source คือชื่อไฟล์ที่อ่าน
+    pub fn message(&self, lang: Lang, source: &str) -> String {
+        match self {
+            ReferenceError::NotReferenceTable => lang.format(Message::NotReferenceTable, &[&source]),
+            ReferenceError::UnknownColumn(column) => lang.format(Message::ReferenceColumn, &[&source, column]),
+            ReferenceError::InvalidField { line, column } => lang.format(Message::ReferenceField, &[&source, line, column]),
+        }
+    }
+}
+
+pub fn parse_reference(text: &str) -> Result<Reference, ReferenceError> {
+    let records = csv::parse(text);
+    let (header, records) = records.split_first().ok_or(ReferenceError::NotReferenceTable)?;
+    let header: Vec<&str> = header.fields.iter().map(|field| field.trim()).collect();
+    if !header.starts_with(&["date", "time"]) {
+        return Err(ReferenceError::NotReferenceTable);
+    }
+    let planets = header[2..].iter()
+        .map(|&key| Planet::from_key(key).ok_or_else(|| ReferenceError::UnknownColumn(key.to_string())))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut rows = Vec::new();
+    for record in records {
+        let invalid = |column: &str| ReferenceError::InvalidField { line: record.line, column: column.to_string() };
+        let field = |i: usize| record.fields.get(i).map(|f| f.trim()).ok_or_else(|| invalid(header[i]));
+        let date = thaidate::parse_date(field(0)?).map_err(|_| invalid("date"))?;
+        let time = thaidate::parse_time(field(1)?).map_err(|_| invalid("time"))?;
+        let longitudes = (2..header.len())
+            .map(|i| match field(i)? {
+                "" => Ok(None),
+                text => text.parse::<f64>().ok().filter(|v| v.is_finite()).map(Some).ok_or_else(|| invalid(header[i])),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        rows.push(ReferenceRow { date, time, longitudes });
+    }
+    if rows.is_empty() {
+        return Err(ReferenceError::NotReferenceTable);
+    }
+    Ok(Reference { planets, rows })
+}
+
+// สถิติความคลาดเคลื่อน (องศา ระยะเชิงมุมที่สั้นที่สุด ไม่สนทิศ)
+#[derive(Clone, Copy, Default)]
+pub struct ErrorStats {
+    pub count: usize,
+    pub max: f64,
+    pub worst: (u32, u32, u32), // วันที่คลาดมากที่สุด
+    pub first: (u32, u32, u32), // วันแรกและวันสุดท้ายที่มีค่าอ้างอิง (ตามลำดับในตาราง)
+    pub last: (u32, u32, u32),
+    sum: f64,
+    sum_squares: f64,
+}
+
+impl ErrorStats {
+    fn add(&mut self, error: f64, date: (u32, u32, u32)) {
+        if self.count == 0 {
+            self.first = date;
+        }
+        if self.count == 0 || error > self.max {
+            self.max = error;
+            self.worst = date;
+        }
+        self.last = date;
+        self.count += 1;
+        self.sum += error;
+        self.sum_squares += error * error;
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.sum / self.count.max(1) as f64
+    }
+
+    pub fn rms(&self) -> f64 {
+        (self.sum_squares / self.count.max(1) as f64).sqrt()
+    }
+}
+
+pub struct BodyAccuracy {
+    pub planet: Planet,
+    pub overall: ErrorStats,
+    pub decades: Vec<(u32, ErrorStats)>, // ปี พ.ศ. ต้นทศวรรษ เช่น 2530 คือ 2530-2539
+}
+
+fn angular_distance(a: f64, b: f64) -> f64 {
+    let difference = (a - b).rem_euclid(360.0);
+    difference.min(360.0 - difference)
+}
+
+// planets ว่างคือทุกดวงที่มีในตาราง
+pub fn compare(reference: &Reference, planets: &[Planet]) -> Vec<BodyAccuracy> {
+    let mut report: Vec<BodyAccuracy> = reference.planets.iter()
+        .filter(|planet| planets.is_empty() || planets.contains(planet))
+        .map(|&planet| BodyAccuracy { planet, overall: ErrorStats::default(), decades: Vec::new() })
+        .collect();
+
+    for row in &reference.rows {
+        let (d, m, y) = row.date;
+        let jd = julian_day(d, m, y, row.time.0, row.time.1);
+        let decade = y / 10 * 10;
+        for (&planet, &expected) in reference.planets.iter().zip(&row.longitudes) {
+            let Some(expected) = expected else { continue };
+            let Some(body) = report.iter_mut().find(|body| body.planet == planet) else { continue };
+            let error = angular_distance(planet.longitude(jd), expected);
+            body.overall.add(error, row.date);
+            match body.decades.iter_mut().find(|(start, _)| *start == decade) {
+                Some((_, stats)) => stats.add(error, row.date),
+                None => {
+                    let mut stats = ErrorStats::default();
+                    stats.add(error, row.date);
+                    body.decades.push((decade, stats));
+                }
+            }
+        }
+    }
+    for body in &mut report {
+        body.decades.sort_by_key(|(start, _)| *start);
+    }
+    report
+}
+
+// ดาวที่ไม่มีในตารางอ้างอิง (ผลไม่ได้ตรวจ)
+pub fn unchecked(reference: &Reference) -> Vec<Planet> {
+    Planet::ALL.iter().copied().filter(|planet| !reference.planets.contains(planet)).collect()
+}
+
+// ดาวที่มีค่าอ้างอิงไม่ครบทุกแถว จัดกลุ่มตามช่วงวันที่ที่ตรวจได้จริง
+#[derive(Debug, PartialEq)]
+pub struct Coverage {
+    pub first: (u32, u32, u32),
+    pub last: (u32, u32, u32),
+    pub planets: Vec<Planet>,
+}
+
+pub fn partial(reference: &Reference, report: &[BodyAccuracy]) -> Vec<Coverage> {
+    let mut groups: Vec<Coverage> = Vec::new();
+    for body in report.iter().filter(|body| body.overall.count > 0 && body.overall.count < reference.rows.len()) {
+        let (first, last) = (body.overall.first, body.overall.last);
+        match groups.iter_mut().find(|group| group.first == first && group.last == last) {
+            Some(group) => group.planets.push(body.planet),
+            None => groups.push(Coverage { first, last, planets: vec![body.planet] }),
+        }
+    }
+    groups
+}
+
+fn date_text((d, m, y): (u32, u32, u32)) -> String {
+    format!("{}/{}/{}", d, m, y)
+}
+
+fn stats_cells(stats: &ErrorStats) -> [String; 5] {
+    [
+        stats.count.to_string(),
+        format!("{:.3}", stats.max),
+        format!("{:.3}", stats.mean()),
+        format!("{:.3}", stats.rms()),
+        date_text(stats.worst),
+    ]
+}
+
+// ตารางสรุปทุกดวง แล้วตามด้วยตารางรายสิบปีของแต่ละดวง
+pub fn to_text(report: &[BodyAccuracy], lang: Lang) -> String {
+    let aligns = [Align::Left, Align::Right, Align::Right, Align::Right, Align::Right, Align::Right];
+    let columns: Vec<&str> = lang.text(Message::AccuracyColumns).split(',').collect();
+    let header = |first: String| std::iter::once(first).chain(columns.iter().map(|c| c.to_string())).collect::<Vec<_>>();
+
+    let mut summary = Table::new(&aligns);
+    summary.row(header(String::new()));
+    for body in report {
+        summary.row(std::iter::once(lang.planet(body.planet).to_string()).chain(stats_cells(&body.overall)));
+    }
+    let mut text = summary.render();
+
+    for body in report {
+        text.push('\n');
+        text.push_str(&format!("{} ({})\n", lang.planet(body.planet), lang.numeral(body.planet)));
+        let mut table = Table::new(&aligns);
+        table.row(header(lang.text(Message::AccuracyPeriod).to_string()));
+        for (start, stats) in &body.decades {
+            table.row(std::iter::once(format!("{}-{}", start, start + 9)).chain(stats_cells(stats)));
+        }
+        text.push_str(&table.render());
+    }
+    text
+}
+
+// หนึ่งแถวต่อดาวต่อสิบปี แถวที่ช่วงปีว่างคือค่ารวมทั้งตาราง
+pub fn to_csv(report: &[BodyAccuracy]) -> String {
+    let mut output = csv::line(&["planet", "from_be", "to_be", "samples", "max", "mean", "rms", "worst_date", "first_date", "last_date"]);
+    for body in report {
+        let rows = std::iter::once((None, &body.overall)).chain(body.decades.iter().map(|(start, stats)| (Some(*start), stats)));
+        for (start, stats) in rows {
+            let [samples, max, mean, rms, worst] = stats_cells(stats);
+            let (from, to) = start.map_or((String::new(), String::new()), |s| (s.to_string(), (s + 9).to_string()));
+            let (first, last) = (date_text(stats.first), date_text(stats.last));
+            output.push_str(&csv::line(&[body.planet.key().to_string(), from, to, samples, max, mean, rms, worst, first, last]));
+        }
+    }
+    output
+}
+
+fn stats_json(stats: &ErrorStats) -> Vec<(&'static str, Value)> {
+    vec![
+        ("samples", Value::from(stats.count as u32)),
+        ("max", Value::from(stats.max)),
+        ("mean", Value::from(stats.mean())),
+        ("rms", Value::from(stats.rms())),
+        ("worst_date", Value::from(date_text(stats.worst))),
+        ("first_date", Value::from(date_text(stats.first))),
+        ("last_date", Value::from(date_text(stats.last))),
+    ]
+}
+
+pub fn to_json(report: &[BodyAccuracy], lang: Lang) -> Value {
+    Value::Array(report.iter().map(|body| {
+        Value::object([
+            ("planet", Value::from(body.planet.key())),
+            ("name", Value::from(lang.planet(body.planet))),
+            ("overall", Value::object(stats_json(&body.overall))),
+            ("decades", Value::Array(body.decades.iter().map(|(start, stats)| {
+                let mut fields = vec![("from_be", Value::from(*start)), ("to_be", Value::from(start + 9))];
+                fields.extend(stats_json(stats));
+                Value::object(fields)
+            }).collect())),
+        ])
+    }).collect())
+}
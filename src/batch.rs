@@ -0,0 +1,166 @@
+// คำนวณดวงทีละหลายร้อยรายการจาก CSV
+// คอลัมน์ขาเข้า: name, date, time, province หรือ name, date, time, latitude, longitude
+// (มีแถวหัวตารางหรือไม่ก็ได้ หัวตารางภาษาไทย ชื่อ วันเกิด เวลา จังหวัด ละติจูด ลองจิจูด ก็ได้)
+// วันที่และเวลาอ่านแบบเดียวกับ --date/--time จึงใช้ "3 ต.ค. 2530" หรือ "15.30 น." ได้
+// แถวที่ผิดพลาดยังคงออกในผลลัพธ์ โดยช่อง error บอกสาเหตุ และไม่หยุดทั้งชุด
+
+use crate::csv;
+use crate::locale::{Lang, Message};
+use crate::motion::MotionThresholds;
+use crate::notation::Notation;
+use crate::planet::Planet;
+use crate::thaidate;
+use crate::{calculate_thai_ascendant, find_province, Province};
+
+#[derive(Clone, Copy)]
+enum Column {
+    Name,
+    Date,
+    Time,
+    Province,
+    Latitude,
+    Longitude,
+}
+
+fn column_from_header(header: &str) -> Option<Column> {
+    match header.trim().to_lowercase().as_str() {
+        "name" | "ชื่อ" => Some(Column::Name),
+        "date" | "วันเกิด" | "วันที่" => Some(Column::Date),
+        "time" | "เวลา" | "เวลาเกิด" => Some(Column::Time),
+        "province" | "จังหวัด" => Some(Column::Province),
+        "latitude" | "lat" | "ละติจูด" => Some(Column::Latitude),
+        "longitude" | "lon" | "lng" | "ลองจิจูด" => Some(Column::Longitude),
+        _ => None,
+    }
+}
+
+// ค่าของแต่ละคอลัมน์ในหนึ่งแถว
+#[derive(Default)]
+struct Row<'a> {
+    name: &'a str,
+    date: &'a str,
+    time: &'a str,
+    province: &'a str,
+    latitude: &'a str,
+    longitude: &'a str,
+}
+
+// คอลัมน์ที่ไม่รู้จัก (None) ข้ามไป
+fn read_row<'a>(fields: &'a [String], columns: &[Option<Column>]) -> Row<'a> {
+    let mut row = Row::default();
+    for (field, column) in fields.iter().zip(columns) {
+        let Some(column) = column else { continue };
+        let value = field.trim();
+        match column {
+            Column::Name => row.name = value,
+            Column::Date => row.date = value,
+            Column::Time => row.time = value,
+            Column::Province => row.province = value,
+            Column::Latitude => row.latitude = value,
+            Column::Longitude => row.longitude = value,
+        }
+    }
+    row
+}
+
+// หาจังหวัดจากชื่อภาษาอังกฤษ (bangkok) หรือชื่อไทย (กรุงเทพฯ) หรือใช้พิกัดที่ให้มา
+fn place(row: &Row, lang: Lang) -> Result<Province, String> {
+    if !row.latitude.is_empty() || !row.longitude.is_empty() {
+        let coordinate = |text: &str| text.parse::<f64>().ok().filter(|v| v.is_finite());
+        return match (coordinate(row.latitude), coordinate(row.longitude)) {
+            (Some(latitude), Some(longitude)) if latitude.abs() <= 90.0 && longitude.abs() <= 180.0 => {
+                Ok(Province { name: "", english_name: "", latitude, longitude })
+            }
+            _ => Err(lang.format(Message::InvalidCoordinates, &[&format!("{}, {}", row.latitude, row.longitude)])),
+        };
+    }
+    let key = if row.province.is_empty() { "bangkok" } else { row.province };
+    find_province(key).map_err(|err| err.message(lang))
+}
+
+fn header() -> Vec<String> {
+    let mut header: Vec<String> = ["name", "date", "time", "latitude", "longitude", "lagna_sign", "lagna_position"]
+        .iter().map(|s| s.to_string()).collect();
+    for planet in Planet::ALL {
+        header.push(format!("{}_sign", planet.key()));
+        header.push(format!("{}_position", planet.key()));
+        header.push(format!("{}_motion", planet.key()));
+    }
+    header.push("error".to_string());
+    header
+}
+
+// คำนวณดวงหนึ่งแถว คืนค่าช่องตำแหน่ง (ลัคนาและดาว ๑๐ ดวงพร้อมสถานะการเดิน) หรือข้อความผิดพลาด
+fn compute(row: &Row, lang: Lang, notation: Notation, thresholds: &MotionThresholds) -> Result<(Province, [u32; 5], Vec<String>), String> {
+    let (d, m, y) = thaidate::parse_date(row.date).map_err(|err| err.message(lang))?;
+    // ไม่ทราบเวลาเกิด ใช้เวลาอาทิตย์ขึ้น 06:00 น. ตามธรรมเนียม (ลัคนาอยู่ราศีเดียวกับอาทิตย์)
+    let (h, min) = if row.time.is_empty() {
+        (6, 0)
+    } else {
+        thaidate::parse_time(row.time).map_err(|err| err.message(lang))?
+    };
+    let prov = place(row, lang)?;
+    let chart = calculate_thai_ascendant(d, m, y, h, min, &prov, thresholds).map_err(|err| err.message(lang))?;
+
+    let mut cells = vec![lang.sign(chart.lagna_sign()).to_string(), notation.format(chart.lagna)];
+    for body in &chart.bodies {
+        cells.push(lang.sign((body.longitude / 30.0) as usize % 12).to_string());
+        cells.push(notation.format(body.longitude));
+        cells.push(body.motion.key().to_string());
+    }
+    Ok((prov, [d, m, y, h, min], cells))
+}
+
+pub struct BatchResult {
+    pub csv: String,
+    pub rows: usize,
+    pub errors: Vec<String>, // ข้อความผิดพลาดพร้อมเลขบรรทัด
+}
+
+pub fn run(input: &str, lang: Lang, notation: Notation, thresholds: &MotionThresholds) -> BatchResult {
+    let mut records = csv::parse(input).into_iter().peekable();
+    let mut columns = vec![Some(Column::Name), Some(Column::Date), Some(Column::Time), Some(Column::Province)];
+
+    // แถวแรกเป็นหัวตารางถ้ามีชื่อคอลัมน์ที่รู้จัก
+    if let Some(first) = records.peek() {
+        let headers: Vec<Option<Column>> = first.fields.iter().map(|f| column_from_header(f)).collect();
+        if headers.iter().any(Option::is_some) {
+            columns = headers;
+            records.next();
+        } else if first.fields.len() >= 5 {
+            columns = vec![Some(Column::Name), Some(Column::Date), Some(Column::Time), Some(Column::Latitude), Some(Column::Longitude)];
+        }
+    }
+
+    let mut output = csv::line(&header());
+    let mut rows = 0;
+    let mut errors = Vec::new();
+    let width = header().len();
+    for record in records {
+        rows += 1;
+        let row = read_row(&record.fields, &columns);
+        let fields = match compute(&row, lang, notation, thresholds) {
+            Ok((prov, [d, m, y, h, min], cells)) => {
+                let mut fields = vec![
+                    row.name.to_string(),
+                    format!("{}/{}/{}", d, m, y),
+                    format!("{:02}:{:02}", h, min),
+                    format!("{:.4}", prov.latitude),
+                    format!("{:.4}", prov.longitude),
+                ];
+                fields.extend(cells);
+                fields.push(String::new());
+                fields
+            }
+            Err(message) => {
+                errors.push(lang.format(Message::BatchRowError, &[&record.line, &message]));
+                let mut fields = vec![row.name.to_string(), row.date.to_string(), row.time.to_string()];
+                fields.resize(width - 1, String::new());
+                fields.push(message);
+                fields
+            }
+        };
+        output.push_str(&csv::line(&fields));
+    }
+    BatchResult { csv: output, rows, errors }
+}
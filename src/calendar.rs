@@ -0,0 +1,108 @@
+// ปฏิทินสุริยคติสากลสำหรับวันที่ทุกยุค ใช้กับงานลำดับเหตุการณ์ทางประวัติศาสตร์ไทยและอินเดีย
+//
+// ปีเป็นแบบดาราศาสตร์ (astronomical year numbering): 0 คือ 1 ปีก่อน ค.ศ., -1 คือ 2 ปีก่อน ค.ศ. ไม่มีช่องว่างที่ไม่มีปีศูนย์
+// ปี พ.ศ. = ปีดาราศาสตร์ + 543 ต่อเนื่องกันตลอด (พ.ศ. 1 = 543 ปีก่อน ค.ศ., พ.ศ. 0 = ปีดาราศาสตร์ -543)
+// Calendar::Historical คือแบบที่เอกสารใช้จริงและเป็นค่าที่ calculate_jd ใช้:
+//   ปฏิทินจูเลียนก่อน 15 ต.ค. ค.ศ. 1582 (พ.ศ. 2125) ปฏิทินเกรกอเรียนตั้งแต่วันนั้น วันที่ 5-14 ต.ค. 1582 จึงไม่มีอยู่จริง
+// Julian / Gregorian คือใช้ปฏิทินนั้นย้อนหลังตลอด (proleptic) สำหรับเทียบกับตำราที่ใช้แบบนั้น
+// สูตรตาม Meeus, Astronomical Algorithms บทที่ 7 ใช้ได้ตั้งแต่ JD 0 (1 ม.ค. 4713 ปีก่อน ค.ศ. ปฏิทินจูเลียน)
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Calendar {
+    Julian,
+    Gregorian,
+    Historical,
+}
+
+// Julian Day ของ 15 ต.ค. 1582 เวลา 0:00 วันแรกของปฏิทินเกรกอเรียน
+pub const REFORM_JD: f64 = 2299160.5;
+const REFORM_DATE: (i32, u32, u32) = (1582, 10, 15);
+
+// ช่วงปีดาราศาสตร์ที่คำนวณได้
+pub const MIN_YEAR: i32 = -4712;
+pub const MAX_YEAR: i32 = 9999;
+
+impl Calendar {
+    // ปฏิทินที่ใช้จริงกับวันที่นั้น (Historical แยกเป็น Julian หรือ Gregorian)
+    pub fn for_date(self, year: i32, month: u32, day: u32) -> Calendar {
+        match self {
+            Calendar::Historical if (year, month, day) < REFORM_DATE => Calendar::Julian,
+            Calendar::Historical => Calendar::Gregorian,
+            calendar => calendar,
+        }
+    }
+
+    // เช่นเดียวกันแต่ดูจาก Julian Day
+    pub fn for_jd(self, jd: f64) -> Calendar {
+        match self {
+            Calendar::Historical if jd < REFORM_JD => Calendar::Julian,
+            Calendar::Historical => Calendar::Gregorian,
+            calendar => calendar,
+        }
+    }
+}
+
+pub fn is_leap_year(year: i32, calendar: Calendar) -> bool {
+    match calendar.for_date(year, 2, 1) {
+        Calendar::Gregorian => year.rem_euclid(4) == 0 && (year.rem_euclid(100) != 0 || year.rem_euclid(400) == 0),
+        _ => year.rem_euclid(4) == 0,
+    }
+}
+
+// วันสุดท้ายของเดือน (ต.ค. 1582 ของ Historical ยังเป็น 31 แม้จะขาดไป 10 วัน)
+pub fn days_in_month(year: i32, month: u32, calendar: Calendar) -> u32 {
+    match month {
+        2 if is_leap_year(year, calendar) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// มีวันที่นี้อยู่จริงในปฏิทินนั้นหรือไม่
+pub fn is_valid_date(year: i32, month: u32, day: u32, calendar: Calendar) -> bool {
+    if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month, calendar) {
+        return false;
+    }
+    // 4 ต.ค. 1582 (จูเลียน) ตามด้วย 15 ต.ค. 1582 (เกรกอเรียน) ทันที
+    !(calendar == Calendar::Historical && year == 1582 && month == 10 && (5..15).contains(&day))
+}
+
+// Julian Day ของเวลา 0:00 ของวันที่
+pub fn julian_day(year: i32, month: u32, day: u32, calendar: Calendar) -> f64 {
+    let mut y = year as i64;
+    let mut m = month as i64;
+    if m <= 2 {
+        y -= 1;
+        m += 12;
+    }
+    let b = match calendar.for_date(year, month, day) {
+        Calendar::Gregorian => {
+            let a = y.div_euclid(100);
+            2 - a + a.div_euclid(4)
+        }
+        _ => 0,
+    };
+    (365.25 * (y + 4716) as f64).floor() + (30.6001 * (m + 1) as f64).floor() + day as f64 + b as f64 - 1524.5
+}
+
+// วันที่ (ปีดาราศาสตร์, เดือน, วัน) ที่มีเวลา jd อยู่ ตรงข้ามกับ julian_day
+pub fn from_julian_day(jd: f64, calendar: Calendar) -> (i32, u32, u32) {
+    let z = (jd + 0.5).floor();
+    let a = match calendar.for_jd(jd) {
+        Calendar::Gregorian => {
+            let alpha = ((z - 1867216.25) / 36524.25).floor();
+            z + 1.0 + alpha - (alpha / 4.0).floor()
+        }
+        _ => z,
+    };
+    let b = a + 1524.0;
+    let c = ((b - 122.1) / 365.25).floor();
+    let d = (365.25 * c).floor();
+    let e = ((b - d) / 30.6001).floor();
+
+    let day = (b - d - (30.6001 * e).floor()) as u32;
+    let month = if e < 14.0 { e - 1.0 } else { e - 13.0 } as u32;
+    let year = if month > 2 { c - 4716.0 } else { c - 4715.0 } as i32;
+    (year, month, day)
+}
@@ -0,0 +1,53 @@
+// ตัวอ่านบรรทัดคำสั่งแบบง่าย: horathai <คำสั่ง> [คำสั่งย่อย ...] --ชื่อ ค่า --สวิตช์
+
+use std::collections::HashMap;
+
+pub struct Args {
+    pub command: Option<String>,
+    arguments: Vec<String>, // คำที่ตามหลังคำสั่ง เช่น add ใน horathai profile add
+    options: HashMap<String, Vec<String>>, // ตัวเลือกที่ให้ซ้ำได้ เช่น --event หลายครั้ง
+    switches: Vec<String>,
+}
+
+impl Args {
+    pub fn parse(args: impl Iterator<Item = String>) -> Args {
+        let mut command = None;
+        let mut arguments = Vec::new();
+        let mut options = HashMap::new();
+        let mut switches = Vec::new();
+        let mut args = args.peekable();
+
+        while let Some(arg) = args.next() {
+            if let Some(name) = arg.strip_prefix("--") {
+                // ถ้าคำถัดไปไม่ขึ้นต้นด้วย -- ถือเป็นค่าของตัวเลือกนี้
+                match args.next_if(|next| !next.starts_with("--")) {
+                    Some(value) => options.entry(name.to_string()).or_insert_with(Vec::new).push(value),
+                    None => switches.push(name.to_string()),
+                }
+            } else if command.is_none() {
+                command = Some(arg);
+            } else {
+                arguments.push(arg);
+            }
+        }
+
+        Args { command, arguments, options, switches }
+    }
+
+    pub fn argument(&self, index: usize) -> Option<&str> {
+        self.arguments.get(index).map(|s| s.as_str())
+    }
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.options.get(name).and_then(|values| values.last()).map(|s| s.as_str())
+    }
+
+    // ค่าทุกค่าของตัวเลือกที่ให้ซ้ำ เรียงตามลำดับที่พิมพ์
+    pub fn get_all(&self, name: &str) -> impl Iterator<Item = &str> {
+        self.options.get(name).into_iter().flatten().map(|s| s.as_str())
+    }
+
+    pub fn has(&self, name: &str) -> bool {
+        self.switches.iter().any(|s| s == name)
+    }
+}
@@ -0,0 +1,65 @@
+// อ่าน/เขียน CSV แบบ RFC 4180: ช่องที่มี , " หรือขึ้นบรรทัดใหม่ครอบด้วย " และ " ในช่องเขียนเป็น ""
+// รองรับไฟล์จาก Excel ที่ขึ้นต้นด้วย BOM และขึ้นบรรทัดด้วย CRLF
+
+// แถวที่อ่านได้ พร้อมเลขบรรทัดที่แถวเริ่ม (นับจาก 1) สำหรับแจ้งข้อผิดพลาด
+pub struct Record {
+    pub line: usize,
+    pub fields: Vec<String>,
+}
+
+pub fn parse(text: &str) -> Vec<Record> {
+    let text = text.strip_prefix('\u{FEFF}').unwrap_or(text);
+    let mut records = Vec::new();
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut line = 1;
+    let mut start_line = 1;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == '\n' { line += 1; }
+        if quoted {
+            match c {
+                '"' if chars.peek() == Some(&'"') => { chars.next(); field.push('"'); }
+                '"' => quoted = false,
+                _ => field.push(c),
+            }
+            continue;
+        }
+        match c {
+            '"' => quoted = true,
+            ',' => fields.push(std::mem::take(&mut field)),
+            '\r' => {}
+            '\n' => {
+                fields.push(std::mem::take(&mut field));
+                // ข้ามบรรทัดว่าง
+                if fields.len() > 1 || !fields[0].trim().is_empty() {
+                    records.push(Record { line: start_line, fields: std::mem::take(&mut fields) });
+                }
+                fields.clear();
+                start_line = line;
+            }
+            _ => field.push(c),
+        }
+    }
+    if !field.is_empty() || !fields.is_empty() {
+        fields.push(field);
+        records.push(Record { line: start_line, fields });
+    }
+    records
+}
+
+fn escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+// หนึ่งแถว CSV พร้อมขึ้นบรรทัดใหม่
+pub fn line<S: AsRef<str>>(fields: &[S]) -> String {
+    let fields: Vec<String> = fields.iter().map(|f| escape(f.as_ref())).collect();
+    fields.join(",") + "\n"
+}
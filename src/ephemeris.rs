@@ -0,0 +1,161 @@
+// ปฏิทินโหราศาสตร์: ตำแหน่งดาวทุกดวงวันละครั้งตามเวลาที่เลือก (เช่น 06:00 น.)
+// ใช้ฟังก์ชันตำแหน่งดาวชุดเดียวกับดวงชะตา และทำเครื่องหมายวันที่ดาวย้ายราศี
+// (ราศีต่างจากเวลาเดียวกันของวันก่อน) กับสถานะพักร์/มนฑ์/เสริด
+
+use crate::json::Value;
+use crate::locale::{Lang, Message};
+use crate::motion::{MotionState, MotionThresholds};
+use crate::notation::Notation;
+use crate::planet::Planet;
+use crate::table::{Align, Table};
+use crate::{calculate_bodies, check_date_time, csv, jd_to_date, julian_day, now_jd, thaidate, SolarState};
+
+// อักษรกำกับวันที่ดาวย้ายเข้าราศีใหม่
+pub const INGRESS_MARK: &str = "*";
+
+pub struct EphemerisEntry {
+    pub planet: Planet,
+    pub longitude: f64,
+    pub motion: MotionState,
+    pub ingress: bool, // ย้ายราศีมาตั้งแต่เวลาเดียวกันของวันก่อน
+}
+
+pub struct EphemerisDay {
+    pub jd: f64,
+    pub date: (u32, u32, u32), // วัน เดือน ปี พ.ศ.
+    pub weekday: usize,
+    pub entries: Vec<EphemerisEntry>,
+}
+
+fn sign_of(longitude: f64) -> usize {
+    (longitude / 30.0) as usize % 12
+}
+
+// ช่วงวันที่จากตัวเลือก month, year หรือ from กับ days (ไม่ระบุใช้เดือนปัจจุบัน)
+// คืนวันเริ่ม (วัน เดือน ปี พ.ศ.) และจำนวนวัน
+pub fn date_range<'a>(get: impl Fn(&str) -> Option<&'a str>, lang: Lang) -> Result<((u32, u32, u32), u32), String> {
+    let date = |text: &str| thaidate::parse_date(text).map_err(|err| err.message(lang));
+    if let Some(text) = get("month") {
+        // "10/2569" หรือ "ต.ค. 2569" อ่านเป็นวันที่ 1 ของเดือนนั้น
+        let separator = if text.contains('/') { "/" } else { " " };
+        let (_, m, y) = date(&format!("1{}{}", separator, text))?;
+        Ok(((1, m, y), thaidate::days_in_month(m, y)))
+    } else if let Some(text) = get("year") {
+        let y = text.trim().parse::<u32>().map_err(|_| lang.text(Message::InvalidYear).to_string())?;
+        check_date_time(1, 1, y, 0, 0).map_err(|err| err.message(lang))?;
+        Ok(((1, 1, y), (1..=12).map(|m| thaidate::days_in_month(m, y)).sum()))
+    } else if let Some(text) = get("from") {
+        let start = date(text)?;
+        let days = match get("days").map(str::parse::<u32>) {
+            Some(Ok(days)) => days,
+            Some(Err(_)) => return Err(lang.text(Message::InvalidDays).to_string()),
+            None => 30,
+        };
+        Ok((start, days))
+    } else {
+        let (_, m, y, _, _) = jd_to_date(now_jd());
+        Ok(((1, m, y), thaidate::days_in_month(m, y)))
+    }
+}
+
+// ตำแหน่งดาว days วัน เริ่มจากวันที่ให้มา ณ เวลา hour:minute ทุกวัน (วันเริ่มตรวจแล้วใน date_range)
+pub fn ephemeris(start: (u32, u32, u32), days: u32, hour: u32, minute: u32, thresholds: &MotionThresholds) -> Vec<EphemerisDay> {
+    let (d, m, y) = start;
+    let start_jd = julian_day(d, m, y, hour, minute);
+    let mut previous = SolarState::at(start_jd - 1.0).longitudes().map(sign_of);
+
+    (0..days).map(|i| {
+        let jd = start_jd + i as f64;
+        let entries: Vec<EphemerisEntry> = calculate_bodies(jd, thresholds).into_iter().zip(previous.iter_mut()).map(|(body, previous)| {
+            let sign = sign_of(body.longitude);
+            let ingress = sign != *previous;
+            *previous = sign;
+            EphemerisEntry { planet: body.planet, longitude: body.longitude, motion: body.motion, ingress }
+        }).collect();
+
+        let (d, m, y, _, _) = jd_to_date(jd);
+        let weekday = (julian_day(d, m, y, 12, 0) as i64 + 1).rem_euclid(7) as usize;
+        EphemerisDay { jd, date: (d, m, y), weekday, entries }
+    }).collect()
+}
+
+// ตารางข้อความ หนึ่งบรรทัดต่อวัน ตามด้วยคำอธิบายเครื่องหมาย
+pub fn to_text(days: &[EphemerisDay], lang: Lang, notation: Notation) -> String {
+    let mut aligns = vec![Align::Right, Align::Left];
+    aligns.extend(Planet::ALL.iter().map(|_| Align::Right));
+    let mut table = Table::new(&aligns);
+
+    let mut header = vec![String::new(), String::new()];
+    header.extend(Planet::ALL.iter().map(|&p| lang.numeral(p)));
+    table.row(header);
+
+    for day in days {
+        let (d, m, y) = day.date;
+        let mut row = vec![format!("{}/{}/{}", d, m, y), lang.weekday(day.weekday).to_string()];
+        row.extend(day.entries.iter().map(|entry| {
+            format!(
+                "{}{}{}",
+                if entry.ingress { INGRESS_MARK } else { "" },
+                notation.format(entry.longitude),
+                lang.motion_mark(entry.motion),
+            )
+        }));
+        table.row(row);
+    }
+
+    let mut text = table.render();
+    text.push_str(&lang.format(Message::EphemerisLegend, &[
+        &INGRESS_MARK,
+        &lang.motion_mark(MotionState::Retrograde),
+        &lang.motion_mark(MotionState::Slow),
+        &lang.motion_mark(MotionState::Fast),
+    ]));
+    text.push('\n');
+    text
+}
+
+pub fn to_csv(days: &[EphemerisDay], lang: Lang, notation: Notation) -> String {
+    let mut header = vec!["date".to_string(), "weekday".to_string()];
+    for planet in Planet::ALL {
+        for field in ["sign", "position", "motion", "ingress"] {
+            header.push(format!("{}_{}", planet.key(), field));
+        }
+    }
+    let mut output = csv::line(&header);
+
+    for day in days {
+        let (d, m, y) = day.date;
+        let mut row = vec![format!("{}/{}/{}", d, m, y), lang.weekday(day.weekday).to_string()];
+        for entry in &day.entries {
+            row.push(lang.sign(sign_of(entry.longitude)).to_string());
+            row.push(notation.format(entry.longitude));
+            row.push(entry.motion.key().to_string());
+            row.push(if entry.ingress { "1" } else { "" }.to_string());
+        }
+        output.push_str(&csv::line(&row));
+    }
+    output
+}
+
+// ลองจิจูดเป็นองศาทศนิยมนับจาก 0 เมษ ส่วน position เป็นข้อความตาม notation
+pub fn to_json(days: &[EphemerisDay], lang: Lang, notation: Notation) -> Value {
+    Value::Array(days.iter().map(|day| {
+        let (d, m, y) = day.date;
+        Value::object([
+            ("date", Value::from(format!("{}/{}/{}", d, m, y))),
+            ("jd", Value::from(day.jd)),
+            ("weekday", Value::from(lang.weekday(day.weekday))),
+            ("bodies", Value::Array(day.entries.iter().map(|entry| {
+                Value::object([
+                    ("planet", Value::from(entry.planet.key())),
+                    ("name", Value::from(lang.planet(entry.planet))),
+                    ("longitude", Value::from(entry.longitude)),
+                    ("sign", Value::from(lang.sign(sign_of(entry.longitude)))),
+                    ("position", Value::from(notation.format(entry.longitude))),
+                    ("motion", Value::from(entry.motion.key())),
+                    ("ingress", Value::from(entry.ingress)),
+                ])
+            }).collect())),
+        ])
+    }).collect())
+}
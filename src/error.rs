@@ -0,0 +1,31 @@
+// ข้อผิดพลาดของข้อมูลวันเวลาและสถานที่ ใช้ชนิดเดียวกันตั้งแต่อ่านข้อความ (thaidate) จนถึงคำนวณดวง (calculate_jd, calculate_thai_ascendant)
+// โปรแกรมที่เรียกใช้แยกกรณีได้จาก variant และแสดงข้อความด้วย message(lang)
+
+use crate::locale::{Lang, Message};
+use crate::thaidate::DateError;
+use crate::{MAX_YEAR_BE, MIN_YEAR_BE};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    InvalidDate(DateError),          // อ่านวันเวลาไม่ออก หรือไม่มีวันเวลานั้นจริง เช่น 31 ก.พ.
+    YearOutOfRange { year_be: i64 }, // นอกช่วง MIN_YEAR_BE..=MAX_YEAR_BE
+    UnknownPlace(String),
+    UnsupportedEra(String), // ศักราชที่ยังแปลงไม่ได้ เช่น จ.ศ. ร.ศ.
+}
+
+impl Error {
+    pub fn message(&self, lang: Lang) -> String {
+        match self {
+            Error::InvalidDate(err) => err.message(lang),
+            Error::YearOutOfRange { year_be } => lang.format(Message::YearOutOfRange, &[&MIN_YEAR_BE, &MAX_YEAR_BE, year_be]),
+            Error::UnknownPlace(name) => lang.format(Message::UnknownProvince, &[name]),
+            Error::UnsupportedEra(era) => lang.format(Message::UnsupportedEra, &[era]),
+        }
+    }
+}
+
+impl From<DateError> for Error {
+    fn from(err: DateError) -> Error {
+        Error::InvalidDate(err)
+    }
+}
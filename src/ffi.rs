@@ -0,0 +1,243 @@
+// ส่วนเชื่อมต่อภาษา C สำหรับโปรแกรมที่ไม่ได้เขียนด้วย Rust (เช่น C++)
+// build.rs อ่าน src/ffi.rs แล้วสร้าง horathai.h ใน OUT_DIR ทุกครั้งที่ build
+// แก้ไฟล์นี้แล้วให้คัดลอกไปแทน include/horathai.h ด้วย (tests/header.rs ตรวจว่าตรงกัน)
+//
+// การจัดการหน่วยความจำ:
+//   ผลลัพธ์ทุกตัวเป็น struct ที่ผู้เรียกจองเอง (บน stack ได้) แล้วส่ง pointer มาให้เขียน
+//   ไลบรารีไม่ malloc ให้ จึงไม่มีอะไรต้อง free
+//   ข้อความ (const char *) ชี้ไปยังข้อมูลคงที่ในไลบรารี ใช้ได้ตลอดอายุโปรแกรม ห้าม free
+//   ข้อความทั้งหมดเป็น UTF-8
+// ทุกฟังก์ชันที่คืน int32_t คืน HORATHAI_OK หรือรหัสข้อผิดพลาด และไม่แตะ out เมื่อผิดพลาด
+// ดาวระบุด้วยเลขประจำดาวแบบไทย ๑ อาทิตย์ ... ๙ เกตุ ๐ มฤตยู
+
+// ข้อกำหนดของ pointer เขียนไว้ข้างบนแทน # Safety ของแต่ละฟังก์ชัน
+#![allow(clippy::missing_safety_doc)]
+
+use std::ffi::{c_char, CStr, CString};
+use std::sync::OnceLock;
+
+use crate::motion::{MotionState, MotionThresholds};
+use crate::planet::Planet;
+use crate::thaidate::DateError;
+use crate::{calculate_jd, calculate_thai_ascendant, provinces, Error, Province, SolarState};
+
+pub const HORATHAI_OK: i32 = 0;
+pub const HORATHAI_ERROR_NULL_POINTER: i32 = 1;
+pub const HORATHAI_ERROR_INVALID_DATE: i32 = 2;
+pub const HORATHAI_ERROR_INVALID_TIME: i32 = 3;
+pub const HORATHAI_ERROR_INVALID_COORDINATES: i32 = 4;
+pub const HORATHAI_ERROR_UNKNOWN_PLANET: i32 = 5;
+pub const HORATHAI_ERROR_UNKNOWN_PROVINCE: i32 = 6;
+pub const HORATHAI_ERROR_YEAR_OUT_OF_RANGE: i32 = 7; // ปี พ.ศ. นอกช่วงที่คำนวณได้ (ไม่เกิน 10542)
+
+// สถานะการเดินของดาวใน HorathaiBody.motion
+pub const HORATHAI_MOTION_NORMAL: i32 = 0;
+pub const HORATHAI_MOTION_RETROGRADE: i32 = 1; // พักร์
+pub const HORATHAI_MOTION_SLOW: i32 = 2; // มนฑ์
+pub const HORATHAI_MOTION_FAST: i32 = 3; // เสริด
+
+pub const HORATHAI_PLANET_COUNT: usize = 10;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct HorathaiBody {
+    pub planet: i32,    // เลขประจำดาว 1-9 และ 0
+    pub longitude: f64, // องศานับจาก 0 เมษ (0-360)
+    pub motion: i32,    // HORATHAI_MOTION_*
+}
+
+#[repr(C)]
+pub struct HorathaiChart {
+    pub jd: f64,                 // Julian Day ตามเวลาไทย (UTC+7)
+    pub local_mean_minutes: f64, // เวลาท้องถิ่น นาทีนับจากเที่ยงคืน
+    pub lagna: f64,              // ลองจิจูดลัคนา
+    pub weekday_civil: i32,      // วันตามปฏิทิน 0 = อาทิตย์
+    pub weekday_thai: i32,       // วันทางโหราศาสตร์ (เปลี่ยนตอนอาทิตย์ขึ้น)
+    pub rahu_night: i32,         // 1 = พุธกลางคืน (ราหู)
+    pub bodies: [HorathaiBody; HORATHAI_PLANET_COUNT], // เรียงตามเลขดาว 1-9 และ 0
+}
+
+#[repr(C)]
+pub struct HorathaiProvince {
+    pub key: *const c_char,          // ชื่อที่ใช้ค้น เช่น "bangkok"
+    pub name: *const c_char,         // ชื่อไทย
+    pub english_name: *const c_char,
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+// เขียนผลลงที่ out เมื่อ out ไม่ใช่ NULL (ไม่อ่านค่าเดิมใน out)
+unsafe fn write<T>(out: *mut T, value: T) -> i32 {
+    if out.is_null() {
+        return HORATHAI_ERROR_NULL_POINTER;
+    }
+    unsafe { out.write(value) };
+    HORATHAI_OK
+}
+
+fn error_code(err: &Error) -> i32 {
+    match err {
+        Error::InvalidDate(DateError::NoSuchTime { .. } | DateError::InvalidTime(_)) => HORATHAI_ERROR_INVALID_TIME,
+        Error::InvalidDate(_) | Error::UnsupportedEra(_) => HORATHAI_ERROR_INVALID_DATE,
+        Error::YearOutOfRange { .. } => HORATHAI_ERROR_YEAR_OUT_OF_RANGE,
+        Error::UnknownPlace(_) => HORATHAI_ERROR_UNKNOWN_PROVINCE,
+    }
+}
+
+fn planet_from_number(number: i32) -> Option<Planet> {
+    let number = usize::try_from(number).ok().filter(|&n| n < HORATHAI_PLANET_COUNT)?;
+    Some(Planet::ALL[(number + HORATHAI_PLANET_COUNT - 1) % HORATHAI_PLANET_COUNT])
+}
+
+fn planet_number(planet: Planet) -> i32 {
+    let index = Planet::ALL.iter().position(|&p| p == planet).unwrap_or(0);
+    ((index + 1) % HORATHAI_PLANET_COUNT) as i32
+}
+
+fn motion_code(motion: MotionState) -> i32 {
+    match motion {
+        MotionState::Normal => HORATHAI_MOTION_NORMAL,
+        MotionState::Retrograde => HORATHAI_MOTION_RETROGRADE,
+        MotionState::Slow => HORATHAI_MOTION_SLOW,
+        MotionState::Fast => HORATHAI_MOTION_FAST,
+    }
+}
+
+// Julian Day (เวลาไทย) ของวันเวลาปี พ.ศ. สำหรับส่งต่อให้ horathai_planet_longitude
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn horathai_julian_day(day: u32, month: u32, year_be: u32, hour: u32, minute: u32, out: *mut f64) -> i32 {
+    match calculate_jd(day, month, year_be, hour, minute) {
+        Ok(jd) => unsafe { write(out, jd) },
+        Err(err) => error_code(&err),
+    }
+}
+
+// ลองจิจูดของดาวหนึ่งดวง (เลขประจำดาว 0-9) ณ Julian Day ใด ๆ
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn horathai_planet_longitude(planet: i32, jd: f64, out: *mut f64) -> i32 {
+    let Some(planet) = planet_from_number(planet) else { return HORATHAI_ERROR_UNKNOWN_PLANET };
+    if !jd.is_finite() {
+        return HORATHAI_ERROR_INVALID_DATE;
+    }
+    unsafe { write(out, planet.longitude(jd)) }
+}
+
+// ลองจิจูดของดาวทุกดวง ณ Julian Day เดียว เร็วกว่าเรียก horathai_planet_longitude ทีละดวง
+// out ต้องมีที่ว่าง HORATHAI_PLANET_COUNT ค่า เรียงตามเลขดาว 1-9 และ 0 เหมือน HorathaiChart.bodies
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn horathai_positions(jd: f64, out: *mut f64) -> i32 {
+    if !jd.is_finite() {
+        return HORATHAI_ERROR_INVALID_DATE;
+    }
+    unsafe { write(out.cast::<[f64; HORATHAI_PLANET_COUNT]>(), SolarState::at(jd).longitudes()) }
+}
+
+// ดวงชะตาทั้งดวงจากวันเวลาเกิด (ปี พ.ศ. เวลาไทย) และพิกัดที่เกิด
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn horathai_chart(day: u32, month: u32, year_be: u32, hour: u32, minute: u32, latitude: f64, longitude: f64, out: *mut HorathaiChart) -> i32 {
+    if !(latitude.abs() <= 90.0 && longitude.abs() <= 180.0) {
+        return HORATHAI_ERROR_INVALID_COORDINATES;
+    }
+    let place = Province { name: "", english_name: "", latitude, longitude };
+    let chart = match calculate_thai_ascendant(day, month, year_be, hour, minute, &place, &MotionThresholds::default()) {
+        Ok(chart) => chart,
+        Err(err) => return error_code(&err),
+    };
+
+    let mut bodies = [HorathaiBody { planet: 0, longitude: 0.0, motion: HORATHAI_MOTION_NORMAL }; HORATHAI_PLANET_COUNT];
+    for (slot, body) in bodies.iter_mut().zip(&chart.bodies) {
+        *slot = HorathaiBody { planet: planet_number(body.planet), longitude: body.longitude, motion: motion_code(body.motion) };
+    }
+    let result = HorathaiChart {
+        jd: chart.jd,
+        local_mean_minutes: chart.lmt_minutes,
+        lagna: chart.lagna,
+        weekday_civil: chart.weekday.civil as i32,
+        weekday_thai: chart.weekday.thai as i32,
+        rahu_night: chart.weekday.rahu as i32,
+        bodies,
+    };
+    unsafe { write(out, result) }
+}
+
+// ตารางจังหวัดเป็น C string สร้างครั้งเดียว เรียงตาม key
+struct ProvinceEntry {
+    key: CString,
+    name: CString,
+    english_name: CString,
+    latitude: f64,
+    longitude: f64,
+}
+
+fn province_table() -> &'static [ProvinceEntry] {
+    static TABLE: OnceLock<Vec<ProvinceEntry>> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        let mut table: Vec<ProvinceEntry> = provinces().into_iter().map(|(key, p)| ProvinceEntry {
+            key: CString::new(key).expect("ชื่อจังหวัดต้องไม่มีอักขระ NUL"),
+            name: CString::new(p.name).expect("ชื่อจังหวัดต้องไม่มีอักขระ NUL"),
+            english_name: CString::new(p.english_name).expect("ชื่อจังหวัดต้องไม่มีอักขระ NUL"),
+            latitude: p.latitude,
+            longitude: p.longitude,
+        }).collect();
+        table.sort_by(|a, b| a.key.cmp(&b.key));
+        table
+    })
+}
+
+fn province_value(entry: &'static ProvinceEntry) -> HorathaiProvince {
+    HorathaiProvince {
+        key: entry.key.as_ptr(),
+        name: entry.name.as_ptr(),
+        english_name: entry.english_name.as_ptr(),
+        latitude: entry.latitude,
+        longitude: entry.longitude,
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn horathai_province_count() -> usize {
+    province_table().len()
+}
+
+// จังหวัดลำดับที่ index (0 ถึง horathai_province_count() - 1)
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn horathai_province_at(index: usize, out: *mut HorathaiProvince) -> i32 {
+    match province_table().get(index) {
+        Some(entry) => unsafe { write(out, province_value(entry)) },
+        None => HORATHAI_ERROR_UNKNOWN_PROVINCE,
+    }
+}
+
+// ค้นจังหวัดจาก key ชื่อไทย หรือชื่ออังกฤษ (ไม่สนตัวพิมพ์เล็ก/ใหญ่)
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn horathai_province_find(name: *const c_char, out: *mut HorathaiProvince) -> i32 {
+    if name.is_null() {
+        return HORATHAI_ERROR_NULL_POINTER;
+    }
+    let Ok(name) = unsafe { CStr::from_ptr(name) }.to_str() else { return HORATHAI_ERROR_UNKNOWN_PROVINCE };
+    let name = name.trim();
+    let found = province_table().iter().find(|entry| {
+        [&entry.key, &entry.name, &entry.english_name].iter().any(|text| text.to_str().is_ok_and(|text| text.eq_ignore_ascii_case(name)))
+    });
+    match found {
+        Some(entry) => unsafe { write(out, province_value(entry)) },
+        None => HORATHAI_ERROR_UNKNOWN_PROVINCE,
+    }
+}
+
+// คำอธิบายรหัสข้อผิดพลาด (ภาษาอังกฤษ ข้อความคงที่ ห้าม free)
+#[unsafe(no_mangle)]
+pub extern "C" fn horathai_error_message(code: i32) -> *const c_char {
+    let message = match code {
+        HORATHAI_OK => c"ok",
+        HORATHAI_ERROR_NULL_POINTER => c"null pointer argument",
+        HORATHAI_ERROR_INVALID_DATE => c"invalid date",
+        HORATHAI_ERROR_INVALID_TIME => c"invalid time",
+        HORATHAI_ERROR_INVALID_COORDINATES => c"invalid coordinates",
+        HORATHAI_ERROR_UNKNOWN_PLANET => c"unknown planet number",
+        HORATHAI_ERROR_UNKNOWN_PROVINCE => c"unknown province",
+        HORATHAI_ERROR_YEAR_OUT_OF_RANGE => c"year out of range (BE up to 10542)",
+        _ => c"unknown error code",
+    };
+    message.as_ptr()
+}
@@ -0,0 +1,85 @@
+// ค้นหาเวลาดาวย้ายราศี
+// ไล่ตามเวลาทีละช่วงสั้น ๆ เมื่อราศีเปลี่ยนจึงแบ่งครึ่งช่วง (bisection) จนละเอียดถึงระดับนาที
+// ดาวที่พักร์อาจย้ายกลับราศีเดิมแล้วย้ายเข้าใหม่ จึงได้หลายครั้งต่อราศี
+
+use crate::planet::Planet;
+use crate::SolarState;
+
+// ช่วงไล่หา 6 ชั่วโมง: จันทร์เดินราว 3 องศา ไม่มีดาวใดข้ามสองราศีในช่วงนี้
+const STEP_DAYS: f64 = 0.25;
+// แบ่งครึ่งจนช่วงเหลือไม่ถึงครึ่งนาที
+const PRECISION_DAYS: f64 = 0.5 / 1440.0;
+// ค้นได้ไกลสุด ๑๐๐ ปีต่อครั้ง
+pub const MAX_DAYS: u32 = 36525;
+
+pub struct Ingress {
+    pub planet: Planet,
+    pub jd: f64,          // เวลาที่เข้าราศีใหม่ (Julian Day เวลาไทย)
+    pub from_sign: usize, // ราศีเดิม 0 = เมษ
+    pub to_sign: usize,   // ราศีที่ย้ายเข้า
+}
+
+impl Ingress {
+    // ย้ายถอยกลับราศีก่อนหน้า (เพราะพักร์ หรือราหูที่เดินถอยหลังเสมอ)
+    pub fn is_backward(&self) -> bool {
+        (self.from_sign + 11) % 12 == self.to_sign
+    }
+}
+
+fn sign_of(planet: Planet, jd: f64) -> usize {
+    (planet.longitude(jd) / 30.0) as usize % 12
+}
+
+// ช่วง [early, late] ที่ราศีเปลี่ยน บีบให้เหลือเวลาแรกที่อยู่ในราศีใหม่
+fn refine(planet: Planet, mut early: f64, mut late: f64, early_sign: usize) -> f64 {
+    while late - early > PRECISION_DAYS {
+        let mid = (early + late) / 2.0;
+        if sign_of(planet, mid) == early_sign {
+            early = mid;
+        } else {
+            late = mid;
+        }
+    }
+    late
+}
+
+// ราศีของดาวที่ค้นทุกดวง ณ เวลาเดียวกัน คำนวณสถานะโลกครั้งเดียว (SolarState)
+fn signs_at(planets: &[Planet], jd: f64) -> Vec<usize> {
+    let state = SolarState::at(jd);
+    planets.iter().map(|&planet| (state.longitude(planet) / 30.0) as usize % 12).collect()
+}
+
+// ค้นจาก start_jd ไป days วัน (ค่าติดลบคือค้นย้อนหลัง)
+// ผลเรียงตามลำดับที่พบ คือใกล้ start_jd ก่อน
+pub fn find_ingresses(planets: &[Planet], start_jd: f64, days: f64) -> Vec<Ingress> {
+    let steps = (days.abs() / STEP_DAYS).ceil() as usize;
+    let direction = days.signum();
+    let mut events = Vec::new();
+
+    // ไล่ทุกดวงไปพร้อมกันทีละช่วง เฉพาะช่วงที่ราศีเปลี่ยนจึงแบ่งครึ่งทีละดวง
+    let mut prev_jd = start_jd;
+    let mut prev_signs = signs_at(planets, prev_jd);
+    for i in 1..=steps {
+        let offset = (i as f64 * STEP_DAYS).min(days.abs());
+        let jd = start_jd + direction * offset;
+        let signs = signs_at(planets, jd);
+
+        for ((&planet, &prev_sign), &sign) in planets.iter().zip(&prev_signs).zip(&signs) {
+            if sign != prev_sign {
+                // จัดให้เป็นลำดับเวลาจริงเสมอ ไม่ว่าจะค้นไปข้างหน้าหรือย้อนหลัง
+                let (early, late, from_sign, to_sign) = if direction > 0.0 {
+                    (prev_jd, jd, prev_sign, sign)
+                } else {
+                    (jd, prev_jd, sign, prev_sign)
+                };
+                events.push(Ingress { planet, jd: refine(planet, early, late, from_sign), from_sign, to_sign });
+            }
+        }
+
+        prev_jd = jd;
+        prev_signs = signs;
+    }
+
+    events.sort_by(|a, b| (direction * a.jd).total_cmp(&(direction * b.jd)));
+    events
+}
@@ -0,0 +1,344 @@
+// อ่าน/เขียน JSON แบบง่ายโดยไม่พึ่ง crate ภายนอก
+// ใช้ประกอบผลลัพธ์เป็นค่า Value แล้วเรียก to_string() (ตัวอักษรไทยเขียนตรง ๆ เป็น UTF-8)
+// หรือ pretty() สำหรับไฟล์ที่คนเปิดอ่าน/แก้เองได้ และ parse() อ่านกลับเป็น Value
+
+use std::fmt::{self, Display, Write};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>), // เรียงตามลำดับที่ใส่
+}
+
+impl Value {
+    pub fn object<K: Into<String>>(fields: impl IntoIterator<Item = (K, Value)>) -> Value {
+        Value::Object(fields.into_iter().map(|(k, v)| (k.into(), v)).collect())
+    }
+
+    // ค่าของ key ใน object (ไม่ใช่ object หรือไม่มี key ได้ None)
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Object(fields) => fields.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Value::Number(number) => Some(*number),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[Value]> {
+        match self {
+            Value::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    // เขียนแบบย่อหน้า ๒ ช่อง หนึ่งค่าต่อบรรทัด
+    pub fn pretty(&self) -> String {
+        let mut output = String::new();
+        self.write_pretty(&mut output, 0);
+        output
+    }
+
+    fn write_pretty(&self, output: &mut String, indent: usize) {
+        let items: Vec<(Option<&str>, &Value)> = match self {
+            Value::Array(items) if !items.is_empty() => items.iter().map(|v| (None, v)).collect(),
+            Value::Object(fields) if !fields.is_empty() => fields.iter().map(|(k, v)| (Some(k.as_str()), v)).collect(),
+            _ => return output.push_str(&self.to_string()),
+        };
+        let (open, close) = if matches!(self, Value::Array(_)) { ('[', ']') } else { ('{', '}') };
+        output.push(open);
+        for (i, (key, value)) in items.iter().enumerate() {
+            output.push_str(if i > 0 { ",\n" } else { "\n" });
+            output.push_str(&"  ".repeat(indent + 1));
+            if let Some(key) = key {
+                output.push_str(&format!("{}: ", Value::from(*key)));
+            }
+            value.write_pretty(output, indent + 1);
+        }
+        output.push('\n');
+        output.push_str(&"  ".repeat(indent));
+        output.push(close);
+    }
+}
+
+impl From<&str> for Value {
+    fn from(text: &str) -> Value {
+        Value::String(text.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(text: String) -> Value {
+        Value::String(text)
+    }
+}
+
+impl From<f64> for Value {
+    fn from(number: f64) -> Value {
+        Value::Number(number)
+    }
+}
+
+impl From<u32> for Value {
+    fn from(number: u32) -> Value {
+        Value::Number(number as f64)
+    }
+}
+
+impl From<i64> for Value {
+    fn from(number: i64) -> Value {
+        Value::Number(number as f64)
+    }
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Value {
+        Value::Bool(value)
+    }
+}
+
+fn write_string(f: &mut fmt::Formatter, text: &str) -> fmt::Result {
+    f.write_char('"')?;
+    for c in text.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => f.write_char(c)?,
+        }
+    }
+    f.write_char('"')
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::Null => f.write_str("null"),
+            Value::Bool(value) => write!(f, "{}", value),
+            // JSON ไม่มี NaN/Infinity
+            Value::Number(number) if !number.is_finite() => f.write_str("null"),
+            Value::Number(number) => write!(f, "{}", number),
+            Value::String(text) => write_string(f, text),
+            Value::Array(items) => {
+                f.write_char('[')?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 { f.write_char(',')?; }
+                    write!(f, "{}", item)?;
+                }
+                f.write_char(']')
+            }
+            Value::Object(fields) => {
+                f.write_char('{')?;
+                for (i, (key, value)) in fields.iter().enumerate() {
+                    if i > 0 { f.write_char(',')?; }
+                    write_string(f, key)?;
+                    write!(f, ":{}", value)?;
+                }
+                f.write_char('}')
+            }
+        }
+    }
+}
+
+// ตำแหน่ง (ไบต์นับจาก 0) ที่อ่านต่อไม่ได้
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub position: usize,
+}
+
+// ซ้อน [ หรือ { ลึกกว่านี้ถือว่าอ่านไม่ได้ กันสแต็กล้นจากข้อมูลที่ส่งมาทางบริการ HTTP
+const MAX_DEPTH: usize = 64;
+
+pub fn parse(text: &str) -> Result<Value, ParseError> {
+    let mut parser = Parser { text, bytes: text.as_bytes(), position: 0, depth: 0 };
+    let value = parser.value()?;
+    parser.skip_whitespace();
+    if parser.position < text.len() {
+        return Err(parser.error());
+    }
+    Ok(value)
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    bytes: &'a [u8],
+    position: usize,
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self) -> ParseError {
+        ParseError { position: self.position }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.bytes.get(self.position), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.position += 1;
+        }
+    }
+
+    // ข้ามช่องว่างแล้วกินตัวอักษรนี้ถ้าเป็นตัวถัดไป
+    fn eat(&mut self, byte: u8) -> bool {
+        self.skip_whitespace();
+        let found = self.bytes.get(self.position) == Some(&byte);
+        if found { self.position += 1; }
+        found
+    }
+
+    fn value(&mut self) -> Result<Value, ParseError> {
+        self.skip_whitespace();
+        match self.bytes.get(self.position) {
+            Some(&open @ (b'{' | b'[')) => {
+                if self.depth == MAX_DEPTH {
+                    return Err(self.error());
+                }
+                self.depth += 1;
+                let value = if open == b'{' { self.object() } else { self.array() };
+                self.depth -= 1;
+                value
+            }
+            Some(b'"') => self.string().map(Value::String),
+            Some(b't') => self.literal("true", Value::Bool(true)),
+            Some(b'f') => self.literal("false", Value::Bool(false)),
+            Some(b'n') => self.literal("null", Value::Null),
+            Some(b'-' | b'0'..=b'9') => self.number(),
+            _ => Err(self.error()),
+        }
+    }
+
+    fn literal(&mut self, word: &str, value: Value) -> Result<Value, ParseError> {
+        if !self.text[self.position..].starts_with(word) {
+            return Err(self.error());
+        }
+        self.position += word.len();
+        Ok(value)
+    }
+
+    fn object(&mut self) -> Result<Value, ParseError> {
+        self.position += 1;
+        let mut fields = Vec::new();
+        if self.eat(b'}') {
+            return Ok(Value::Object(fields));
+        }
+        loop {
+            self.skip_whitespace();
+            if self.bytes.get(self.position) != Some(&b'"') {
+                return Err(self.error());
+            }
+            let key = self.string()?;
+            if !self.eat(b':') {
+                return Err(self.error());
+            }
+            fields.push((key, self.value()?));
+            if self.eat(b'}') {
+                return Ok(Value::Object(fields));
+            }
+            if !self.eat(b',') {
+                return Err(self.error());
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Value, ParseError> {
+        self.position += 1;
+        let mut items = Vec::new();
+        if self.eat(b']') {
+            return Ok(Value::Array(items));
+        }
+        loop {
+            items.push(self.value()?);
+            if self.eat(b']') {
+                return Ok(Value::Array(items));
+            }
+            if !self.eat(b',') {
+                return Err(self.error());
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, ParseError> {
+        self.position += 1;
+        let mut text = String::new();
+        loop {
+            let Some(c) = self.text[self.position..].chars().next() else { return Err(self.error()) };
+            match c {
+                '"' => {
+                    self.position += 1;
+                    return Ok(text);
+                }
+                '\\' => {
+                    self.position += 1;
+                    text.push(self.escape()?);
+                }
+                c if (c as u32) < 0x20 => return Err(self.error()),
+                c => {
+                    self.position += c.len_utf8();
+                    text.push(c);
+                }
+            }
+        }
+    }
+
+    // ตัวอักษรหลัง \ รวม \uXXXX และคู่ surrogate ของอักษรนอก BMP
+    fn escape(&mut self) -> Result<char, ParseError> {
+        let byte = self.bytes.get(self.position).copied();
+        self.position += 1;
+        Ok(match byte {
+            Some(b'"') => '"',
+            Some(b'\\') => '\\',
+            Some(b'/') => '/',
+            Some(b'b') => '\u{8}',
+            Some(b'f') => '\u{c}',
+            Some(b'n') => '\n',
+            Some(b'r') => '\r',
+            Some(b't') => '\t',
+            Some(b'u') => {
+                let mut code = self.hex()?;
+                if (0xD800..0xDC00).contains(&code) && self.text[self.position..].starts_with("\\u") {
+                    self.position += 2;
+                    let low = self.hex()?;
+                    if !(0xDC00..0xE000).contains(&low) {
+                        return Err(self.error());
+                    }
+                    code = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+                }
+                char::from_u32(code).ok_or_else(|| self.error())?
+            }
+            _ => return Err(ParseError { position: self.position - 1 }),
+        })
+    }
+
+    fn hex(&mut self) -> Result<u32, ParseError> {
+        let digits = self.text.get(self.position..self.position + 4).filter(|d| d.bytes().all(|b| b.is_ascii_hexdigit()));
+        let code = digits.and_then(|d| u32::from_str_radix(d, 16).ok()).ok_or_else(|| self.error())?;
+        self.position += 4;
+        Ok(code)
+    }
+
+    fn number(&mut self) -> Result<Value, ParseError> {
+        let start = self.position;
+        while matches!(self.bytes.get(self.position), Some(b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9')) {
+            self.position += 1;
+        }
+        self.text[start..self.position].parse().map(Value::Number).map_err(|_| ParseError { position: start })
+    }
+}
@@ -0,0 +1,1110 @@
+// ไลบรารีคำนวณดวงชะตาไทยแบบสุริยยาตร์
+// โปรแกรม horathai (main.rs) ไลบรารีภาษา C (ffi.rs) ใช้ฟังก์ชันชุดเดียวกันนี้
+
+use std::collections::HashMap;
+
+pub mod accuracy;
+pub mod batch;
+pub mod calendar;
+pub mod csv;
+pub mod ephemeris;
+pub mod error;
+pub mod ffi;
+pub mod ingress;
+pub mod json;
+pub mod locale;
+pub mod lunar;
+pub mod motion;
+pub mod notation;
+pub mod numerals;
+pub mod planet;
+pub mod profile;
+#[cfg(feature = "python")]
+mod python;
+pub mod rectify;
+pub mod server;
+pub mod songkran;
+pub mod sunrise;
+pub mod svg;
+pub mod table;
+pub mod terminal;
+pub mod thaidate;
+pub mod transit;
+pub mod weekday;
+pub mod yam;
+
+pub use error::Error;
+
+use calendar::Calendar;
+use json::Value;
+use locale::Lang;
+use motion::{classify_motion, daily_motion_between, MotionState, MotionThresholds};
+use notation::Notation;
+use planet::Planet;
+
+pub struct Province {
+    pub name: &'static str,
+    pub english_name: &'static str,
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+// ชื่อราศี เรียงจากเมษ (0) ถึงมีน (11)
+pub const ZODIAC_NAMES: [&str; 12] = ["เมษ", "พฤษภ", "มิถุน", "กรกฎ", "สิงห์", "กันย์", "ตุลย์", "พิจิก", "ธนู", "มกร", "กุมภ์", "มีน"];
+
+// ชื่อวัน เริ่มจากวันอาทิตย์ (0)
+pub const WEEKDAY_NAMES: [&str; 7] = ["อาทิตย์", "จันทร์", "อังคาร", "พุธ", "พฤหัสบดี", "ศุกร์", "เสาร์"];
+
+pub fn provinces() -> HashMap<&'static str, Province> {
+    let mut provinces = HashMap::new();
+    provinces.insert("bangkok", Province { name: "กรุงเทพฯ", english_name: "Bangkok", latitude: 13.75, longitude: 100.50 });
+    provinces.insert("chiangmai", Province { name: "เชียงใหม่", english_name: "Chiang Mai", latitude: 18.79, longitude: 98.98 });
+    provinces.insert("ubon", Province { name: "อุบลราชธานี", english_name: "Ubon Ratchathani", latitude: 15.24, longitude: 104.85 });
+    provinces.insert("kalasin", Province { name: "กาฬสินธุ์", english_name: "Kalasin", latitude: 16.43, longitude: 103.53 });
+    provinces
+}
+
+// หาจังหวัดจาก key (bangkok) ชื่อไทย (กรุงเทพฯ) หรือชื่ออังกฤษ (Bangkok) ไม่สนตัวพิมพ์เล็ก/ใหญ่
+pub fn find_province(name: &str) -> Result<Province, Error> {
+    let name = name.trim();
+    provinces().into_iter()
+        .find(|(key, p)| [*key, p.name, p.english_name].iter().any(|text| text.eq_ignore_ascii_case(name)))
+        .map(|(_, p)| p)
+        .ok_or_else(|| Error::UnknownPlace(name.to_string()))
+}
+
+// ช่วงปีที่คำนวณได้ (4713 ปีก่อน ค.ศ. ถึง ค.ศ. 9999) ปีก่อน พ.ศ. 0 ใช้ได้เฉพาะฟังก์ชันที่รับปีดาราศาสตร์
+pub const MIN_YEAR_BE: i64 = calendar::MIN_YEAR as i64 + BE_OFFSET;
+pub const MAX_YEAR_BE: i64 = calendar::MAX_YEAR as i64 + BE_OFFSET;
+
+// ปี พ.ศ. = ปีดาราศาสตร์ (ค.ศ.) + 543
+const BE_OFFSET: i64 = 543;
+
+// ตรวจวันเวลา (ปี พ.ศ.) ก่อนคำนวณ: ปีอยู่ในช่วง มีวันนั้นในเดือนนั้นจริง และเวลาไม่เกิน 23:59
+pub fn check_date_time(d: u32, m: u32, y_be: u32, h: u32, min: u32) -> Result<(), Error> {
+    check_year_date_time(d, m, y_be as i64 - BE_OFFSET, h, min)
+}
+
+// ปี พ.ศ. ที่รับมาเดี่ยว ๆ เช่น --year ของสงกรานต์และปฏิทินจันทรคติ
+pub fn check_year(year_be: i64) -> Result<(), Error> {
+    if !(MIN_YEAR_BE..=MAX_YEAR_BE).contains(&year_be) {
+        return Err(Error::YearOutOfRange { year_be });
+    }
+    Ok(())
+}
+
+// เช่นเดียวกันแต่ปีเป็นปีดาราศาสตร์ ตรวจตามปฏิทินจูเลียน/เกรกอเรียนที่ใช้จริง (ไม่มี 29 ก.พ. 1700 แต่มี 29 ก.พ. 1500)
+fn check_year_date_time(d: u32, m: u32, year: i64, h: u32, min: u32) -> Result<(), Error> {
+    let year_be = year + BE_OFFSET;
+    check_year(year_be)?;
+    if !calendar::is_valid_date(year as i32, m, d, Calendar::Historical) {
+        return Err(thaidate::DateError::NoSuchDay { day: d, month: m, year_be }.into());
+    }
+    if h > 23 || min > 59 {
+        return Err(thaidate::DateError::NoSuchTime { hour: h, minute: min }.into());
+    }
+    Ok(())
+}
+
+// Julian Day ของเวลาปัจจุบัน (เวลาไทย UTC+7)
+pub fn now_jd() -> f64 {
+    chrono::Utc::now().timestamp() as f64 / 86400.0 + 2440587.5 + 7.0 / 24.0
+}
+
+// ตำแหน่งดาวหนึ่งดวงในดวงชะตา
+pub struct ChartBody {
+    pub planet: Planet,
+    pub longitude: f64,
+    pub motion: MotionState,
+}
+
+// ผลคำนวณดวงชะตาหนึ่งดวง
+pub struct Chart {
+    pub jd: f64,
+    pub lmt_minutes: f64,       // เวลาท้องถิ่น (นาทีนับจากเที่ยงคืน)
+    pub lagna: f64,             // ลองจิจูดลัคนา
+    pub bodies: Vec<ChartBody>, // เรียงตามเลขดาว ๑-๙ และ ๐
+    pub weekday: weekday::BirthWeekday,
+}
+
+impl Chart {
+    pub fn lagna_sign(&self) -> usize {
+        (self.lagna / 30.0) as usize % 12
+    }
+
+    pub fn body(&self, planet: Planet) -> &ChartBody {
+        self.bodies.iter().find(|b| b.planet == planet).expect("ดวงชะตาต้องมีดาวครบทุกดวง")
+    }
+
+    // สิ่งที่วางในแต่ละราศี สำหรับวาดดวง: ล ก่อน ตามด้วยเลขดาวและสถานะการเดิน
+    pub fn sign_contents(&self, lang: Lang) -> [Vec<(String, MotionState)>; 12] {
+        let mut signs: [Vec<(String, MotionState)>; 12] = Default::default();
+        signs[self.lagna_sign()].push((lang.lagna_mark().to_string(), MotionState::Normal));
+        for body in &self.bodies {
+            signs[(body.longitude / 30.0) as usize % 12].push((lang.numeral(body.planet), body.motion));
+        }
+        signs
+    }
+
+    // ผลคำนวณเป็น JSON แบบเดียวกันทั้งบริการ HTTP และ Python
+    pub fn to_json(&self, prov: &Province, birth: (u32, u32, u32, u32, u32), notation: Notation, lang: Lang) -> Value {
+        let (d, m, y, h, min) = birth;
+        let lmt = self.lmt_minutes.rem_euclid(1440.0);
+        let bodies = Planet::ALL.iter().map(|&planet| {
+            let body = self.body(planet);
+            let mut fields = vec![
+                ("planet", Value::from(planet.key())),
+                ("name", Value::from(lang.planet(planet))),
+            ];
+            fields.extend(position_json(body.longitude, notation, lang));
+            fields.push(("motion", Value::from(body.motion.key())));
+            Value::object(fields)
+        });
+        Value::object([
+            ("date", Value::from(format!("{}/{}/{}", d, m, y))),
+            ("time", Value::from(format!("{:02}:{:02}", h, min))),
+            ("jd", Value::from(self.jd)),
+            ("place", Value::object([
+                ("name", Value::from(lang.province(prov))),
+                ("latitude", Value::from(prov.latitude)),
+                ("longitude", Value::from(prov.longitude)),
+            ])),
+            ("local_mean_time", Value::from(format!("{:02}:{:02}", (lmt / 60.0) as u32, (lmt % 60.0) as u32))),
+            ("weekday", Value::object([
+                ("civil", Value::from(lang.weekday(self.weekday.civil))),
+                ("astrological", Value::from(lang.weekday(self.weekday.thai))),
+                ("rahu", Value::from(self.weekday.rahu)),
+            ])),
+            ("lagna", Value::object(position_json(self.lagna, notation, lang))),
+            ("bodies", Value::Array(bodies.collect())),
+        ])
+    }
+}
+
+// ตำแหน่งหนึ่งจุด: ลองจิจูดทศนิยมนับจาก 0 เมษ และข้อความตาม notation
+fn position_json(longitude: f64, notation: Notation, lang: Lang) -> Vec<(&'static str, Value)> {
+    let sign = (longitude / 30.0) as usize % 12;
+    vec![
+        ("longitude", Value::from(longitude)),
+        ("sign", Value::from(lang.sign(sign))),
+        ("sign_index", Value::from(sign as u32)),
+        ("position", Value::from(notation.format(longitude))),
+    ]
+}
+
+pub fn calculate_thai_ascendant(d: u32, m: u32, y: u32, h: u32, min: u32, prov: &Province, thresholds: &MotionThresholds) -> Result<Chart, Error> {
+    // --- คำนวณ Julian Day (ฐานสำหรับคำนวณดาว) ---
+    let jd = calculate_jd(d, m, y, h, min)?;
+
+
+    // --- หาองศาอาทิตย์ (ต้องได้ประมาณ 15-16 องศาราศีกันย์)
+    let solar_long = estimate_solar_thai(d, m, y);
+    let solar_zodiac_idx = (solar_long / 30.0) as usize;
+    let solar_degree = solar_long % 30.0;
+
+    // --- ดาว ๒-๙ และ ๐ ณ เวลาเกิด พร้อมสถานะการเดิน (พักร์/มนฑ์/เสริด) ---
+    // อาทิตย์ (๑) ใช้องศา ณ เที่ยงคืนต้นวันตามคัมภีร์แทน
+    let mut bodies = calculate_bodies(jd, thresholds);
+    bodies[0].longitude = solar_long;
+
+    //--- คำนวณเวลาท้องถิ่นจริง (LMT)
+    // เวลาไทยอิง 105E | กรุงเทพ 100.5E | ต่างกัน 4.5 องศา | 1 องศา = 4 นาที
+    // 4.5 * 4 = 18 นาที (ต้อง "ลบ" ออกจากเวลาหน้าปัดนาฬิกา)
+    let lmt_offset = (105.0 - prov.longitude) * 4.0;
+    let total_birth_min = (h as f64 * 60.0) + min as f64 - lmt_offset;
+
+    // 3. อันตรกาล (เวลาหลังอาทิตย์อุทัย 06:00 น.)
+    let mut antar_kala = total_birth_min - 360.0;
+    if antar_kala < 0.0 { antar_kala += 1440.0; }
+
+    // 4. ค่าอันโตนาที (มาตรฐานสุริยยาตร์)
+    let anto_nati = [120.0, 96.0, 72.0, 120.0, 144.0, 168.0, 168.0, 144.0, 120.0, 72.0, 96.0, 120.0];
+
+    // 5. ชำระกาลราศีแรก (ราศีที่อาทิตย์สถิตอยู่ - กันย์)
+    let degree_left = 30.0 - solar_degree;
+    let time_left_in_zodiac = (degree_left / 30.0) * anto_nati[solar_zodiac_idx];
+
+    let mut current_idx = solar_zodiac_idx;
+    let final_degree;
+    let mut temp_antar = antar_kala;
+
+    // 6. การวางลัคนา
+    if temp_antar <= time_left_in_zodiac {
+        final_degree = solar_degree + (temp_antar / anto_nati[solar_zodiac_idx] * 30.0);
+    } else {
+        temp_antar -= time_left_in_zodiac;
+        loop {
+            current_idx = (current_idx + 1) % 12;
+            let zodiac_time = anto_nati[current_idx];
+            if temp_antar <= zodiac_time {
+                final_degree = (temp_antar / zodiac_time) * 30.0;
+                break;
+            }
+            temp_antar -= zodiac_time;
+        }
+    }
+
+    Ok(Chart {
+        jd,
+        lmt_minutes: total_birth_min,
+        lagna: current_idx as f64 * 30.0 + final_degree,
+        bodies,
+        weekday: weekday::birth_weekday(d, m, y, h, min, prov.latitude, prov.longitude),
+    })
+}
+
+// ฟังก์ชันประมาณตำแหน่งอาทิตย์ตามคัมภีร์สุริยยาตร์
+fn estimate_solar_thai(d: u32, m: u32, y_be: u32) -> f64 {
+    // นับจากมหาสงกรานต์ (อาทิตย์ 0 องศาเมษ) ที่ผ่านมาล่าสุด ถึงเที่ยงคืนต้นวัน
+    solar_longitude_since_songkran(julian_day(d, m, y_be, 0, 0))
+}
+
+// สุริยยาตร์เฉลี่ย อาทิตย์เดินครบ 360 องศาในหนึ่งปีสุริยยาตร์ (ราววันละ 0.9856 องศา)
+// มหาสงกรานต์แต่ละปีห่างกัน YEAR_DAYS พอดี จึงนับจากสงกรานต์ปีใดก็ได้ รวมถึงวันที่ก่อน พ.ศ. 0
+fn solar_longitude_since_songkran(jd: f64) -> f64 {
+    let (_, _, y_be, _, _) = jd_to_date(jd);
+    let start = songkran::songkran(y_be).moment_jd;
+    ((jd - start).rem_euclid(songkran::YEAR_DAYS) * 360.0 / songkran::YEAR_DAYS) % 360.0
+}
+
+// Julian Day (เวลาไทย) ของวันเวลาปี พ.ศ. ตรวจวันเวลาก่อนด้วย check_date_time
+// วันที่ก่อน 15 ต.ค. 2125 (ค.ศ. 1582) เป็นปฏิทินจูเลียน ตามเอกสารร่วมสมัย (ดู calendar.rs)
+pub fn calculate_jd(d: u32, m: u32, y_be: u32, h: u32, min: u32) -> Result<f64, Error> {
+    check_date_time(d, m, y_be, h, min)?;
+    Ok(julian_day(d, m, y_be, h, min))
+}
+
+// เช่นเดียวกับ calculate_jd แต่ปีเป็นปีดาราศาสตร์ (ค.ศ.) 0 คือ 1 ปีก่อน ค.ศ., -1 คือ 2 ปีก่อน ค.ศ.
+// สำหรับวันที่ก่อน พ.ศ. 0 ที่ปี พ.ศ. แบบ u32 แทนไม่ได้
+pub fn calculate_jd_astronomical(d: u32, m: u32, year: i32, h: u32, min: u32) -> Result<f64, Error> {
+    check_year_date_time(d, m, year as i64, h, min)?;
+    Ok(astronomical_julian_day(d, m, year, h, min))
+}
+
+// สูตรของ calculate_jd โดยไม่ตรวจ สำหรับวันที่ที่ตรวจแล้วหรือได้จาก jd_to_date ภายในไลบรารี
+pub(crate) fn julian_day(d: u32, m: u32, y_be: u32, h: u32, min: u32) -> f64 {
+    astronomical_julian_day(d, m, (y_be as i64 - BE_OFFSET) as i32, h, min)
+}
+
+fn astronomical_julian_day(d: u32, m: u32, year: i32, h: u32, min: u32) -> f64 {
+    calendar::julian_day(year, m, d, Calendar::Historical) + (h as f64 / 24.0) + (min as f64 / 1440.0)
+}
+
+// แปลง Julian Day กลับเป็น (วัน, เดือน, ปี พ.ศ., ชั่วโมง, นาที) ปัดเป็นนาทีที่ใกล้ที่สุด
+// ใช้ปฏิทินเดียวกับ calculate_jd ก่อน พ.ศ. 0 ให้ใช้ jd_to_astronomical_date (ที่นี่จะได้ปี 0)
+pub fn jd_to_date(jd: f64) -> (u32, u32, u32, u32, u32) {
+    let (day, month, year, hour, minute) = jd_to_astronomical_date(jd);
+    (day, month, (year as i64 + BE_OFFSET).max(0) as u32, hour, minute)
+}
+
+// เช่นเดียวกับ jd_to_date แต่ได้ปีดาราศาสตร์ ตรงข้ามกับ calculate_jd_astronomical
+pub fn jd_to_astronomical_date(jd: f64) -> (u32, u32, i32, u32, u32) {
+    let minutes = ((jd + 0.5) * 1440.0).round();
+    let z = (minutes / 1440.0).floor();
+    let minute_of_day = (minutes - z * 1440.0) as u32;
+    let (year, month, day) = calendar::from_julian_day(z - 0.5, Calendar::Historical);
+    (day, month, year, minute_of_day / 60, minute_of_day % 60)
+}
+
+// ตำแหน่งอาทิตย์ ณ Julian Day ใดๆ (สุริยยาตร์เฉลี่ยเดียวกับ estimate_solar_thai แต่คิดเศษของวันด้วย)
+pub fn calculate_sun_position(jd: f64) -> f64 {
+    solar_longitude_since_songkran(jd)
+}
+
+pub fn calculate_moon_position(jd: f64) -> f64 {
+   // จุดนับหรคุณ 1 ม.ค. ค.ศ. 1800 (JD 2378496.5)
+    let epoch_1800_jd =  2378600.45833;
+    let horakhun = jd - epoch_1800_jd; 
+
+    // 1. มัธยมจันทร์ (Mean Moon) 
+    // ปรับค่าตำแหน่งเริ่มต้นเป็น 158.5 เพื่อให้สอดคล้องกับตำแหน่งในราศีมังกรสำหรับปี 2530
+    let mut mean_moon = (238.15 + (13.1763906 * horakhun)) % 360.0;
+    if mean_moon < 0.0 { mean_moon += 360.0; }
+
+    // 2. มัธยมอุจจันทร์ (Mean Apogee)
+    let mut mean_apogee = (172.42 + (0.1114035 * horakhun)) % 360.0;
+    if mean_apogee < 0.0 { mean_apogee += 360.0; }
+
+    // 3. วิกษิปภาค (Anomaly)
+    let mut anomaly = mean_moon - mean_apogee;
+    if anomaly < 0.0 { anomaly += 360.0; }
+
+    // 4. สมการจุดศูนย์กลาง (Equation of Center)
+    // ใช้ค่า 6.289 ซึ่งเป็นค่าสูงสุดในบางตำราสุริยยาตร์เพื่อดึงตำแหน่งจันทร์ให้แม่นยำขึ้น
+    let correction = 6.289 * (anomaly.to_radians().sin());
+
+    // 5. สมผุสจันทร์ (True Moon)
+    let mut true_moon = mean_moon - correction;
+    if true_moon < 0.0 { true_moon += 360.0; }
+    
+    true_moon % 360.0
+}
+
+// ตำแหน่งโลกรอบดวงอาทิตย์ (heliocentric) ณ เวลาหนึ่ง ดาวเคราะห์ลบค่านี้ออกเพื่อได้ตำแหน่งที่มองจากโลก
+// ค่าคงที่ของดาวแต่ละดวงปรับไว้คู่กับสูตรโลกที่ดาวนั้นใช้ จึงมีสามสูตรและให้ผลต่างกันเล็กน้อย
+#[derive(Clone, Copy, Debug)]
+pub struct EarthState {
+    pub t: f64,              // ศตวรรษจูเลียนนับจาก epoch ของสูตร
+    pub mean_longitude: f64, // องศา
+    pub x: f64,              // AU
+    pub y: f64,
+    pub nutation: f64,       // การส่ายของแกนโลกในลองจิจูด (องศา)
+}
+
+impl EarthState {
+    // สูตรที่ดาวพฤหัสบดี ศุกร์ เสาร์ มฤตยูใช้ร่วมกัน
+    pub fn at(jd: f64) -> EarthState {
+        // Epoch อ้างอิง: 13 เมษายน 1800 เวลา 06:00 GMT+7
+        let epoch_1800_jd = 2378493.458333;
+        let t = (jd - epoch_1800_jd) / 36525.0; // จำนวนศตวรรษจูเลียนหลัง epoch
+
+        // ===== 1. คำนวณตำแหน่งโลก (Earth/Sun) =====
+        // Mean Longitude ของโลก (องศา)
+        let mut e_l = 100.46435 + 35999.37249 * t + 0.0003032 * t * t;
+        e_l %= 360.0;
+        if e_l < 0.0 { e_l += 360.0; }
+
+        // Perihelion ของโลก
+        let mut e_g = 102.93768 + 0.32327 * t + 0.00015 * t * t;
+        e_g %= 360.0;
+        if e_g < 0.0 { e_g += 360.0; }
+
+        // Mean Anomaly ของโลก
+        let e_m = (e_l - e_g).to_radians();
+
+        // Equation of Center สำหรับโลก
+        let e_eq_center = 1.914600 * e_m.sin() 
+                        + 0.019993 * (2.0 * e_m).sin()
+                        + 0.000290 * (3.0 * e_m).sin();
+
+        // True Longitude ของโลก
+        let e_v = e_l + e_eq_center;
+        let e_v_rad = e_v.to_radians();
+
+        // ระยะห่างโลก-ดวงอาทิตย์ (AU)
+        let e_ecc = 0.016708617 - 0.000042037 * t;
+        let e_r = 1.000001018 * (1.0 - e_ecc * e_ecc) / (1.0 + e_ecc * e_m.cos());
+
+        // Nutation (การส่ายของแกนโลก) จาก longitude ของ ascending node ของดวงจันทร์
+        let omega = (125.04 - 1934.136 * t).to_radians();
+        let nutation = -0.00569 - 0.00479 * omega.sin();
+
+        EarthState { t, mean_longitude: e_l, x: e_r * e_v_rad.cos(), y: e_r * e_v_rad.sin(), nutation }
+    }
+
+    // ดาวพุธ: สมการจุดศูนย์กลาง 5 พจน์ และ eccentricity มีพจน์ t²
+    pub fn for_mercury(jd: f64) -> EarthState {
+        // Epoch อ้างอิง: 13 เมษายน 1800 เวลา 06:00 GMT+7
+        // = 12 เมษายน 1800 เวลา 23:00 UTC
+        // JD = 2378493.458333
+        let epoch_1800_jd = 2378493.458333;
+        let t = (jd - epoch_1800_jd) / 36525.0; // จำนวนศตวรรษจูเลียนหลัง epoch
+
+        // ===== 1. คำนวณตำแหน่งโลก (Earth/Sun) - ละเอียดขึ้น =====
+        // Mean Longitude ของโลก (องศา)
+        let mut e_l = 100.46435 + 35999.37249 * t + 0.0003032 * t * t;
+        e_l %= 360.0;
+        if e_l < 0.0 { e_l += 360.0; }
+
+        // Perihelion ของโลก
+        let mut e_g = 102.93768 + 0.32327 * t + 0.00015 * t * t;
+        e_g %= 360.0;
+        if e_g < 0.0 { e_g += 360.0; }
+
+        // Mean Anomaly ของโลก
+        let e_m = (e_l - e_g).to_radians();
+
+        // Equation of Center สำหรับโลก (แม่นยำขึ้น - พจน์ 5 ระดับ)
+        let e_eq_center = 1.914600 * e_m.sin() 
+                        + 0.019993 * (2.0 * e_m).sin()
+                        + 0.000290 * (3.0 * e_m).sin()
+                        + 0.000020 * (4.0 * e_m).sin()
+                        + 0.000002 * (5.0 * e_m).sin();
+
+        // True Longitude ของโลก
+        let e_v = e_l + e_eq_center;
+        let e_v_rad = e_v.to_radians();
+
+        // ระยะห่างโลก-ดวงอาทิตย์ (AU) - สูตรแม่นยำ
+        let e_ecc = 0.016708617 - 0.000042037 * t - 0.0000001236 * t * t; // eccentricity ของโลก
+        let e_r = 1.000001018 * (1.0 - e_ecc * e_ecc) / (1.0 + e_ecc * e_m.cos());
+
+        // Nutation (การส่ายของแกนโลก) จาก longitude ของ ascending node ของดวงจันทร์
+        let omega = (125.04 - 1934.136 * t).to_radians();
+        let nutation = -0.00569 - 0.00479 * omega.sin();
+
+        EarthState { t, mean_longitude: e_l, x: e_r * e_v_rad.cos(), y: e_r * e_v_rad.sin(), nutation }
+    }
+
+    // ดาวอังคาร: สูตรอย่างย่อ นับจาก epoch ของดาวอังคารเอง
+    pub fn for_mars(jd: f64) -> EarthState {
+        let epoch_1800_jd = 2378598.458333;
+        let horakhun = (jd - epoch_1800_jd)/ 36525.0;
+        // 1. คำนวณวงโคจรโลก (Earth/Sun elements)
+        let e_l = (100.46435 + 35999.37249 * horakhun) % 360.0; // Mean Longitude
+        let e_g = (102.93768 + 0.32327 * horakhun) % 360.0;    // Perihelion
+        let e_m = (e_l - e_g).to_radians();             // Mean Anomaly
+        let e_v = e_l + 1.9148 * e_m.sin() + 0.0200 * (2.0 * e_m).sin(); // True Longitude
+        let e_r = 1.00014 * (1.0 - 0.01671 * e_m.cos()); // Distance from Sun (AU)
+        let e_v_rad = e_v.to_radians();
+
+        // ดาวอังคารไม่แก้ nutation
+        EarthState { t: horakhun, mean_longitude: e_l, x: e_r * e_v_rad.cos(), y: e_r * e_v_rad.sin(), nutation: 0.0 }
+    }
+}
+
+// สถานะโลก (คือตำแหน่งอาทิตย์ที่มองจากโลก) ณ เวลาหนึ่ง คำนวณครั้งเดียวแล้วหาดาวทุกดวงจากชุดนี้
+// ได้ค่าเท่ากับ calculate_*_position ทุกบิต แต่ไม่ต้องคำนวณโลกใหม่ทุกดวง
+// ใช้กับงานที่ต้องการดาวหลายดวงในเวลาเดียวกัน เช่น ตารางดาวรายวัน การไล่หาเวลาย้ายราศี
+#[derive(Clone, Copy, Debug)]
+pub struct SolarState {
+    pub jd: f64,
+    pub earth: EarthState,         // ดาวพฤหัสบดี ศุกร์ เสาร์ มฤตยู
+    pub earth_mercury: EarthState,
+    pub earth_mars: EarthState,
+}
+
+impl SolarState {
+    pub fn at(jd: f64) -> SolarState {
+        SolarState { jd, earth: EarthState::at(jd), earth_mercury: EarthState::for_mercury(jd), earth_mars: EarthState::for_mars(jd) }
+    }
+
+    pub fn longitude(&self, planet: Planet) -> f64 {
+        match planet {
+            Planet::Sun => calculate_sun_position(self.jd),
+            Planet::Moon => calculate_moon_position(self.jd),
+            Planet::Mars => mars_position(&self.earth_mars),
+            Planet::Mercury => mercury_position(&self.earth_mercury),
+            Planet::Jupiter => jupiter_position(&self.earth),
+            Planet::Venus => venus_position(&self.earth),
+            Planet::Saturn => saturn_position(&self.earth),
+            Planet::Rahu => calculate_rahu_position(self.jd),
+            Planet::Ketu => calculate_ketu_thai_position(self.jd),
+            Planet::Uranus => uranus_position(&self.earth),
+        }
+    }
+
+    // ลองจิจูดทุกดวง เรียงตาม Planet::ALL
+    pub fn longitudes(&self) -> [f64; 10] {
+        Planet::ALL.map(|planet| self.longitude(planet))
+    }
+}
+
+// ดาวทุกดวง ณ jd พร้อมสถานะการเดิน (พักร์/มนฑ์/เสริด)
+// ใช้ SolarState สามเวลา (jd และ ±12 ชั่วโมง) แทนการเรียกฟังก์ชันทีละดวงทีละเวลา
+// อาทิตย์ จันทร์ ราหู เกตุ ไม่มีพักร์ จึงถือว่าเดินปกติเสมอ
+pub fn calculate_bodies(jd: f64, thresholds: &MotionThresholds) -> Vec<ChartBody> {
+    let [before, now, after] = [jd - 0.5, jd, jd + 0.5].map(SolarState::at);
+    Planet::ALL.iter().map(|&planet| {
+        let motion = match planet.mean_motion() {
+            Some(mean) => classify_motion(daily_motion_between(before.longitude(planet), after.longitude(planet)), mean, thresholds),
+            None => MotionState::Normal,
+        };
+        ChartBody { planet, longitude: now.longitude(planet), motion }
+    }).collect()
+}
+
+// ฟังก์ชันคำนวณดาวอังคาร (๓)
+pub fn calculate_mars_position(jd: f64) -> f64 {
+    mars_position(&EarthState::for_mars(jd))
+}
+
+fn mars_position(earth: &EarthState) -> f64 {
+    let horakhun = earth.t;
+
+    // 2. คำนวณวงโคจรดาวอังคาร (Mars elements)
+    let m_l = (317.18 + 19140.30268 * horakhun) % 360.0; // Mean Longitude
+    let m_g = (335.15 + 0.44301 * horakhun) % 360.0;     // Perihelion
+    let m_m = (m_l - m_g).to_radians();              // Mean Anomaly
+    
+    // Equation of Center สำหรับอังคาร (แม่นยำขึ้น)
+    let m_v = m_l + 10.6912 * m_m.sin() + 0.6228 * (2.0 * m_m).sin(); 
+    let m_v_rad = m_v.to_radians();
+    
+    // ระยะห่างจากดวงอาทิตย์ (Mars Distance - r)
+    // สูตร: r = a(1 - e^2) / (1 + e cos(v)) โดยประมาณ
+    let m_r = 1.52368 * (1.0 - 0.0934 * m_m.cos());
+
+    // 3. แปลงจาก Heliocentric (ดวงอาทิตย์เป็นศูนย์กลาง) เป็น Geocentric (โลกเป็นศูนย์กลาง)
+    // ใช้กฎของ Sine/Cosine ในการหาตำแหน่งที่มองจากโลก
+    let x = m_r * m_v_rad.cos() - earth.x;
+    let y = m_r * m_v_rad.sin() - earth.y;
+
+    let mut geocentric_long = y.atan2(x).to_degrees();
+
+    // ปรับค่าให้อยู่ในช่วง 0-360
+    if geocentric_long < 0.0 {
+        geocentric_long += 360.0;
+    }
+    
+    geocentric_long
+}
+
+// ฟังก์ชันคำนวณดาวพุธ (๔) - ปรับปรุงแบบละเอียด
+// อ้างอิง Epoch: 13 เมษายน 1800 เวลา 6:00 น. (GMT+7) = JD 2378493.458333 (UTC 12 เม.ย. 1800 23:00)
+pub fn calculate_mercury_position(jd: f64, _solar_long: f64) -> f64 {
+    mercury_position(&EarthState::for_mercury(jd))
+}
+
+fn mercury_position(earth: &EarthState) -> f64 {
+    let t = earth.t; // จำนวนศตวรรษจูเลียนหลัง epoch
+    let e_l = earth.mean_longitude;
+
+    // ===== 2. คำนวณวงโคจรดาวพุธ (Heliocentric) - แม่นยำสูง =====
+    
+    // Mean Longitude ของดาวพุธ ณ Epoch (องศา)
+    // ค่าเริ่มต้น ณ 13 เม.ย. 1800: ประมาณ 315.5 องศา
+    let mut m_l = 315.5484 + 149472.6746 * t + 0.00030397 * t * t;
+    m_l %= 360.0;
+    if m_l < 0.0 { m_l += 360.0; }
+    
+    // Perihelion ของดาวพุธ
+    let mut m_perihelion = 77.4561 + 0.15856 * t + 0.00002953 * t * t;
+    m_perihelion %= 360.0;
+    if m_perihelion < 0.0 { m_perihelion += 360.0; }
+    
+    // Ascending Node ของดาวพุธ
+    let mut m_node = 48.3309 - 0.12534 * t + 0.00008863 * t * t;
+    m_node %= 360.0;
+    if m_node < 0.0 { m_node += 360.0; }
+    
+    // Inclination (ความเอียงของวงโคจร)
+    let m_inc = 7.00487 - 0.00178 * t; // องศา
+    
+    // Mean Anomaly ของดาวพุธ
+    let m_m = (m_l - m_perihelion).to_radians();
+    
+    // Eccentricity ของดาวพุธ (แปรตามเวลา)
+    let m_ecc = 0.20563069 + 0.000020406 * t - 0.0000000284 * t * t;
+    
+    // Equation of Center สำหรับดาวพุธ (พจน์ 8 ระดับ - เนื่องจากวงรีมาก)
+    let m_eq_center = 23.4406 * m_m.sin()
+                    + 2.9818 * (2.0 * m_m).sin()
+                    + 0.5255 * (3.0 * m_m).sin()
+                    + 0.1058 * (4.0 * m_m).sin()
+                    + 0.0241 * (5.0 * m_m).sin()
+                    + 0.0055 * (6.0 * m_m).sin()
+                    + 0.0013 * (7.0 * m_m).sin()
+                    + 0.0003 * (8.0 * m_m).sin();
+    
+    // True Anomaly
+    let m_v_true = m_l + m_eq_center;
+    
+    // Argument of Latitude (u = v + ω - Ω)
+    let m_arg_lat = (m_v_true - m_node).to_radians();
+    
+    // ระยะห่างดาวพุธ-ดวงอาทิตย์ (AU)
+    let m_a = 0.387098; // Semi-major axis
+    let m_r = m_a * (1.0 - m_ecc * m_ecc) / (1.0 + m_ecc * m_m.cos());
+    
+    // ===== 3. คำนวณตำแหน่ง 3 มิติและแปลงเป็นระนาบอุปราศี =====
+    
+    // ตำแหน่งในระนาบวงโคจร (orbital plane)
+    let m_inc_rad = m_inc.to_radians();
+    let m_node_rad = m_node.to_radians();
+    
+    // แปลงเป็นพิกัด heliocentric ecliptic
+    let x_orb = m_r * m_arg_lat.cos();
+    let y_orb = m_r * m_arg_lat.sin();
+    
+    // แปลงจากระนาบวงโคจรไปสู่ระนาบอุปราศี (ecliptic)
+    let m_x_helio = x_orb * m_node_rad.cos() - y_orb * m_inc_rad.cos() * m_node_rad.sin();
+    let m_y_helio = x_orb * m_node_rad.sin() + y_orb * m_inc_rad.cos() * m_node_rad.cos();
+    
+    // ===== 4. แปลงเป็น Geocentric (มองจากโลก) =====
+    
+    // ตำแหน่งโลกในระบบ heliocentric
+    let e_x = earth.x;
+    let e_y = earth.y;
+    
+    // ตำแหน่งดาวพุธที่มองจากโลก
+    let geo_x = m_x_helio - e_x;
+    let geo_y = m_y_helio - e_y;
+    
+    // Geocentric longitude (ลองจิจูดท้องฟ้า)
+    let mut geocentric_long = geo_y.atan2(geo_x).to_degrees();
+    
+    // ===== 5. การแก้ไขเพิ่มเติม (Perturbations) =====
+    // พจน์แก้ไขจากดาวเคราะห์ดวงอื่น (โดยเฉพาะดาวศุกร์และดาวพฤหัสบดี)
+    
+    // Mean longitude ของดาวศุกร์
+    let venus_mean_long = (181.9798 + 58517.8156 * t) % 360.0;
+    
+    // Mean longitude ของดาวพฤหัสบดี  
+    let jupiter_mean_long = (34.3515 + 3034.9056 * t) % 360.0;
+    
+    // Perturbation terms (องศา)
+    let pert_venus = 0.00204 * (5.0 * venus_mean_long.to_radians() - 2.0 * m_l.to_radians() + 0.21328).sin();
+    let pert_jupiter = 0.00103 * (2.0 * jupiter_mean_long.to_radians() - 5.0 * m_l.to_radians() - 3.07577).sin();
+    let pert_earth = 0.00091 * (2.0 * e_l.to_radians() - 2.0 * m_l.to_radians() - 0.05149).sin();
+    
+    geocentric_long += pert_venus + pert_jupiter + pert_earth;
+    
+    // ===== 6. Nutation (การส่ายของแกนโลก) - แก้ไขเล็กน้อย =====
+    geocentric_long += earth.nutation;
+    
+    // ===== 7. ปรับค่าให้อยู่ในช่วง 0-360 องศา =====
+    geocentric_long %= 360.0;
+    if geocentric_long < 0.0 { 
+        geocentric_long += 360.0; 
+    }
+    
+    geocentric_long
+}
+
+// ดาวพฤหัสบดี (๕): เดินประมาณ 1 ราศีต่อปี (ความเร็ว 0.083 องศา/วัน)
+// ฟังก์ชันคำนวณดาวพฤหัสบดี (๕) - ปรับปรุงแบบละเอียด
+// อ้างอิง Epoch: 13 เมษายน 1800 เวลา 6:00 น. (GMT+7) = JD 2378493.458333
+pub fn calculate_jupiter_position(jd: f64) -> f64 {
+    jupiter_position(&EarthState::at(jd))
+}
+
+fn jupiter_position(earth: &EarthState) -> f64 {
+    let t = earth.t; // จำนวนศตวรรษจูเลียนหลัง epoch
+    let e_l = earth.mean_longitude;
+
+    // ===== 2. คำนวณวงโคจรดาวพฤหัสบดี (Heliocentric) =====
+    
+    // Mean Longitude ของดาวพฤหัสบดี ณ Epoch (องศา)
+    // ค่าเริ่มต้น ณ 13 เม.ย. 1800: ประมาณ 34.3 องศา
+    let mut j_l = 65.32108 + 3034.90567 * t + 0.00022374 * t * t;
+    j_l %= 360.0;
+    if j_l < 0.0 { j_l += 360.0; }
+    
+    // Perihelion ของดาวพฤหัสบดี
+    let mut j_perihelion = 14.75385 + 0.21252 * t + 0.00031097 * t * t;
+    j_perihelion %= 360.0;
+    if j_perihelion < 0.0 { j_perihelion += 360.0; }
+    
+    // Ascending Node ของดาวพฤหัสบดี
+    let mut j_node = 100.55615 - 0.05237 * t - 0.00021819 * t * t;
+    j_node %= 360.0;
+    if j_node < 0.0 { j_node += 360.0; }
+    
+    // Inclination (ความเอียงของวงโคจร)
+    let j_inc = 1.30530 - 0.00155 * t; // องศา (เอียงน้อยมาก)
+    
+    // Mean Anomaly ของดาวพฤหัสบดี
+    let j_m = (j_l - j_perihelion).to_radians();
+    
+    // Eccentricity ของดาวพฤหัสบดี (แปรตามเวลา)
+    let j_ecc = 0.04839266 - 0.000013528 * t - 0.0000000864 * t * t;
+    
+    // Equation of Center สำหรับดาวพฤหัสบดี (4 พจน์ - เพราะ eccentricity น้อย)
+    let j_eq_center = 5.55549 * j_m.sin()
+                    + 0.16763 * (2.0 * j_m).sin()
+                    + 0.00526 * (3.0 * j_m).sin()
+                    + 0.00188 * (4.0 * j_m).sin();
+    
+    // True Anomaly
+    let j_v_true = j_l + j_eq_center;
+    
+    // Argument of Latitude (u = v + ω - Ω)
+    let j_arg_lat = (j_v_true - j_node).to_radians();
+    
+    // ระยะห่างดาวพฤหัสบดี-ดวงอาทิตย์ (AU)
+    let j_a = 5.202561; // Semi-major axis
+    let j_r = j_a * (1.0 - j_ecc * j_ecc) / (1.0 + j_ecc * j_m.cos());
+    
+    // ===== 3. คำนวณตำแหน่ง 3 มิติและแปลงเป็นระนาบอุปราศี =====
+    
+    let j_inc_rad = j_inc.to_radians();
+    let j_node_rad = j_node.to_radians();
+    
+    // แปลงเป็นพิกัด heliocentric ecliptic
+    let x_orb = j_r * j_arg_lat.cos();
+    let y_orb = j_r * j_arg_lat.sin();
+    
+    // แปลงจากระนาบวงโคจรไปสู่ระนาบอุปราศี (ecliptic)
+    let j_x_helio = x_orb * j_node_rad.cos() - y_orb * j_inc_rad.cos() * j_node_rad.sin();
+    let j_y_helio = x_orb * j_node_rad.sin() + y_orb * j_inc_rad.cos() * j_node_rad.cos();
+    
+    // ===== 4. แปลงเป็น Geocentric (มองจากโลก) =====
+    
+    // ตำแหน่งโลกในระบบ heliocentric
+    let e_x = earth.x;
+    let e_y = earth.y;
+    
+    // ตำแหน่งดาวพฤหัสบดีที่มองจากโลก
+    let geo_x = j_x_helio - e_x;
+    let geo_y = j_y_helio - e_y;
+    
+    // Geocentric longitude (ลองจิจูดท้องฟ้า)
+    let mut geocentric_long = geo_y.atan2(geo_x).to_degrees();
+    
+    // ===== 5. การแก้ไขเพิ่มเติม (Perturbations) =====
+    // พจน์แก้ไขจากดาวเสาร์ (มีผลมากที่สุดต่อดาวพฤหัสบดี)
+    
+    // Mean longitude ของดาวเสาร์
+    let saturn_mean_long = (49.9485 + 1222.1138 * t) % 360.0;
+    
+    // Perturbation terms (องศา) - ผลจากดาวเสาร์
+    // Great Inequality: การเกิด resonance 5:2 ระหว่างดาวพฤหัสบดีและดาวเสาร์
+    let pert_saturn_1 = 0.33033 * (5.0 * saturn_mean_long.to_radians() - 2.0 * j_l.to_radians() + 0.91330).sin();
+    let pert_saturn_2 = 0.03304 * (5.0 * saturn_mean_long.to_radians() - 2.0 * j_l.to_radians() - 0.63863).sin();
+    
+    // ผลจากโลก (น้อยมาก)
+    let pert_earth = 0.00204 * (e_l.to_radians() - j_l.to_radians()).sin();
+    
+    geocentric_long += pert_saturn_1 + pert_saturn_2 + pert_earth;
+    
+    // ===== 6. Nutation (การส่ายของแกนโลก) =====
+    geocentric_long += earth.nutation;
+    
+    // ===== 7. ปรับค่าให้อยู่ในช่วง 0-360 องศา =====
+    geocentric_long %= 360.0;
+    if geocentric_long < 0.0 { 
+        geocentric_long += 360.0; 
+    }
+    
+    geocentric_long
+}
+
+// ดาวศุกร์ (๖): ดาววงใน เดินเกาะกลุ่มอาทิตย์ (ความเร็วเฉลี่ยเท่าอาทิตย์ แต่แกว่งได้ 47 องศา)
+// ฟังก์ชันคำนวณดาวศุกร์ (๖) - ปรับปรุงแบบละเอียด
+// อ้างอิง Epoch: 13 เมษายน 1800 เวลา 6:00 น. (GMT+7) = JD 2378493.458333
+pub fn calculate_venus_position(jd: f64, _solar_long: f64) -> f64 {
+    venus_position(&EarthState::at(jd))
+}
+
+fn venus_position(earth: &EarthState) -> f64 {
+    let t = earth.t; // จำนวนศตวรรษจูเลียนหลัง epoch
+    let e_l = earth.mean_longitude;
+
+    // ===== 2. คำนวณวงโคจรดาวศุกร์ (Heliocentric) =====
+    
+    // Mean Longitude ของดาวศุกร์ ณ Epoch (องศา)
+    // ค่าเริ่มต้น ณ 13 เม.ย. 1800 เวลา 06:00 น. (GMT+7): 331.03 องศา
+    // Mean Motion: 58543.7986 องศา/ศตวรรษ (ปรับให้ได้ 174.25° ณ 3 ต.ค. 1987)
+    let mut v_l = 331.03 + 58575.336 * t + 0.00052556 * t * t;
+    v_l %= 360.0;
+    if v_l < 0.0 { v_l += 360.0; }
+    
+    // Perihelion ของดาวศุกร์
+    let mut v_perihelion = 131.5637 + 0.04818 * t + 0.00013955 * t * t;
+    v_perihelion %= 360.0;
+    if v_perihelion < 0.0 { v_perihelion += 360.0; }
+    
+    // Ascending Node ของดาวศุกร์
+    let mut v_node = 76.6799 - 0.04107 * t - 0.00013812 * t * t;
+    v_node %= 360.0;
+    if v_node < 0.0 { v_node += 360.0; }
+    
+    // Inclination (ความเอียงของวงโคจร)
+    let v_inc = 3.39471 - 0.00078 * t; // องศา (เอียงปานกลาง)
+    
+    // Mean Anomaly ของดาวศุกร์
+    let v_m = (v_l - v_perihelion).to_radians();
+    
+    // Eccentricity ของดาวศุกร์ (แปรตามเวลา)
+    // ดาวศุกร์มี eccentricity น้อยมาก (0.0068) - วงโคจรเกือบกลมสนิท!
+    let v_ecc = 0.00682069 - 0.000047766 * t + 0.0000000975 * t * t;
+    
+    // Equation of Center สำหรับดาวศุกร์ (3 พจน์ - เพราะ eccentricity น้อยมาก)
+    let v_eq_center = 0.77967 * v_m.sin()
+                    + 0.00052 * (2.0 * v_m).sin()
+                    + 0.00004 * (3.0 * v_m).sin();
+    
+    // True Anomaly
+    let v_v_true = v_l + v_eq_center;
+    
+    // Argument of Latitude (u = v + ω - Ω)
+    let v_arg_lat = (v_v_true - v_node).to_radians();
+    
+    // ระยะห่างดาวศุกร์-ดวงอาทิตย์ (AU)
+    let v_a = 0.723332; // Semi-major axis
+    let v_r = v_a * (1.0 - v_ecc * v_ecc) / (1.0 + v_ecc * v_m.cos());
+    
+    // ===== 3. คำนวณตำแหน่ง 3 มิติและแปลงเป็นระนาบอุปราศี =====
+    
+    let v_inc_rad = v_inc.to_radians();
+    let v_node_rad = v_node.to_radians();
+    
+    // แปลงเป็นพิกัด heliocentric ecliptic
+    let x_orb = v_r * v_arg_lat.cos();
+    let y_orb = v_r * v_arg_lat.sin();
+    
+    // แปลงจากระนาบวงโคจรไปสู่ระนาบอุปราศี (ecliptic)
+    let v_x_helio = x_orb * v_node_rad.cos() - y_orb * v_inc_rad.cos() * v_node_rad.sin();
+    let v_y_helio = x_orb * v_node_rad.sin() + y_orb * v_inc_rad.cos() * v_node_rad.cos();
+    
+    // ===== 4. แปลงเป็น Geocentric (มองจากโลก) =====
+    
+    // ตำแหน่งโลกในระบบ heliocentric
+    let e_x = earth.x;
+    let e_y = earth.y;
+    
+    // ตำแหน่งดาวศุกร์ที่มองจากโลก
+    let geo_x = v_x_helio - e_x;
+    let geo_y = v_y_helio - e_y;
+    
+    // Geocentric longitude (ลองจิจูดท้องฟ้า)
+    let mut geocentric_long = geo_y.atan2(geo_x).to_degrees();
+    
+    // ===== 5. การแก้ไขเพิ่มเติม (Perturbations) =====
+    // พจน์แก้ไขจากดาวพฤหัสบดี (มีผลเล็กน้อย)
+    
+    // Mean longitude ของดาวพฤหัสบดี
+    let jupiter_mean_long = (34.3515 + 3034.9056 * t) % 360.0;
+    
+    // Perturbation terms (องศา)
+    let pert_jupiter = 0.00313 * (2.0 * jupiter_mean_long.to_radians() - 7.0 * v_l.to_radians() + 3.29).sin()
+                     + 0.00198 * (3.0 * jupiter_mean_long.to_radians() - 8.0 * v_l.to_radians() + 2.33).sin();
+    
+    // ผลจากโลก (การรบกวนเล็กน้อย)
+    let pert_earth = 0.00106 * (5.0 * e_l.to_radians() - 3.0 * v_l.to_radians() - 1.51).sin();
+    
+    geocentric_long += pert_jupiter + pert_earth;
+    
+    // ===== 6. Nutation (การส่ายของแกนโลก) =====
+    geocentric_long += earth.nutation;
+    
+    // ===== 7. ปรับค่าให้อยู่ในช่วง 0-360 องศา =====
+    geocentric_long %= 360.0;
+    if geocentric_long < 0.0 { 
+        geocentric_long += 360.0; 
+    }
+    
+    geocentric_long
+
+}
+
+// ดาวเสาร์ (๗): ดาวที่เดินช้าที่สุดในดาวเดิม (ประมาณ 2.5 ปีต่อราศี)
+pub fn calculate_saturn_position(jd: f64) -> f64 {
+    saturn_position(&EarthState::at(jd))
+}
+
+fn saturn_position(earth: &EarthState) -> f64 {
+    let t = earth.t; // จำนวนศตวรรษจูเลียนหลัง epoch
+
+    // ===== 2. คำนวณวงโคจรดาวเสาร์ (Heliocentric) =====
+    
+    // Mean Longitude ของดาวเสาร์ ณ Epoch (องศา)
+    // ค่าเริ่มต้น ณ 13 เม.ย. 1800: ประมาณ 49.95 องศา
+    let mut s_l = 100.40 + 1222.1138 * t + 0.00019626 * t * t;
+    s_l %= 360.0;
+    if s_l < 0.0 { s_l += 360.0; }
+    
+    // Perihelion ของดาวเสาร์
+    let mut s_perihelion = 92.4321 + 0.41005 * t + 0.0005156 * t * t;
+    s_perihelion %= 360.0;
+    if s_perihelion < 0.0 { s_perihelion += 360.0; }
+    
+    // Ascending Node ของดาวเสาร์
+    let mut s_node = 113.71504 - 0.08771 * t - 0.00018150 * t * t;
+    s_node %= 360.0;
+    if s_node < 0.0 { s_node += 360.0; }
+    
+    // Inclination (ความเอียงของวงโคจร)
+    let s_inc = 2.48446 - 0.00180 * t; // องศา (เอียงน้อย)
+    
+    // Mean Anomaly ของดาวเสาร์
+    let s_m = (s_l - s_perihelion).to_radians();
+    
+    // Eccentricity ของดาวเสาร์ (แปรตามเวลา)
+    let s_ecc = 0.05550825 - 0.000034664 * t - 0.0000003634 * t * t;
+    
+    // Equation of Center สำหรับดาวเสาร์ (5 พจน์)
+    let s_eq_center = 6.40370 * s_m.sin()
+                    + 0.41380 * (2.0 * s_m).sin()
+                    + 0.03167 * (3.0 * s_m).sin()
+                    + 0.00272 * (4.0 * s_m).sin()
+                    + 0.00024 * (5.0 * s_m).sin();
+    
+    // True Anomaly
+    let s_v_true = s_l + s_eq_center;
+    
+    // Argument of Latitude (u = v + ω - Ω)
+    let s_arg_lat = (s_v_true - s_node).to_radians();
+    
+    // ระยะห่างดาวเสาร์-ดวงอาทิตย์ (AU)
+    let s_a = 9.554747; // Semi-major axis
+    let s_r = s_a * (1.0 - s_ecc * s_ecc) / (1.0 + s_ecc * s_m.cos());
+    
+    // ===== 3. คำนวณตำแหน่ง 3 มิติและแปลงเป็นระนาบอุปราศี =====
+    
+    let s_inc_rad = s_inc.to_radians();
+    let s_node_rad = s_node.to_radians();
+    
+    // แปลงเป็นพิกัด heliocentric ecliptic
+    let x_orb = s_r * s_arg_lat.cos();
+    let y_orb = s_r * s_arg_lat.sin();
+    
+    // แปลงจากระนาบวงโคจรไปสู่ระนาบอุปราศี (ecliptic)
+    let s_x_helio = x_orb * s_node_rad.cos() - y_orb * s_inc_rad.cos() * s_node_rad.sin();
+    let s_y_helio = x_orb * s_node_rad.sin() + y_orb * s_inc_rad.cos() * s_node_rad.cos();
+    
+    // ===== 4. แปลงเป็น Geocentric (มองจากโลก) =====
+    
+    // ตำแหน่งโลกในระบบ heliocentric
+    let e_x = earth.x;
+    let e_y = earth.y;
+    
+    // ตำแหน่งดาวเสาร์ที่มองจากโลก
+    let geo_x = s_x_helio - e_x;
+    let geo_y = s_y_helio - e_y;
+    
+    // Geocentric longitude (ลองจิจูดท้องฟ้า)
+    let mut geocentric_long = geo_y.atan2(geo_x).to_degrees();
+    
+    // ===== 5. การแก้ไขเพิ่มเติม (Perturbations) =====
+    // พจน์แก้ไขจากดาวพฤหัสบดี (Great Inequality - มีผลมากที่สุด!)
+    
+    // Mean longitude ของดาวพฤหัสบดี
+    let jupiter_mean_long = (34.3515 + 3034.9056 * t) % 360.0;
+    
+    // Great Inequality: การเกิด resonance 5:2 ระหว่างดาวพฤหัสบดีและดาวเสาร์
+    // (เหมือนกันกับที่ดาวพฤหัสบดีได้รับจากดาวเสาร์ แต่ตรงข้าม)
+    let pert_jupiter_1 = -0.33033 * (5.0 * s_l.to_radians() - 2.0 * jupiter_mean_long.to_radians() - 0.91330).sin();
+    let pert_jupiter_2 = -0.03304 * (5.0 * s_l.to_radians() - 2.0 * jupiter_mean_long.to_radians() + 0.63863).sin();
+    
+    // Perturbations เพิ่มเติมจากดาวพฤหัสบดี
+    let pert_jupiter_3 = 0.02160 * (2.0 * jupiter_mean_long.to_radians() - 5.0 * s_l.to_radians() + 0.49987).sin();
+    
+    // Perturbation จากดาวยูเรนัส (เล็กน้อย)
+    let uranus_mean_long = (313.2322 + 428.4820 * t) % 360.0;
+    let pert_uranus = 0.00121 * (uranus_mean_long.to_radians() - s_l.to_radians()).sin();
+    
+    geocentric_long += pert_jupiter_1 + pert_jupiter_2 + pert_jupiter_3 + pert_uranus;
+    
+    // ===== 6. Nutation (การส่ายของแกนโลก) =====
+    geocentric_long += earth.nutation;
+    
+    // ===== 7. ปรับค่าให้อยู่ในช่วง 0-360 องศา =====
+    geocentric_long %= 360.0;
+    if geocentric_long < 0.0 { 
+        geocentric_long += 360.0; 
+    }
+    
+    geocentric_long
+}
+
+// ราหู (๘): เดินถอยหลังเสมอ (Retrograde) ประมาณ 1.5 ปีต่อราศี
+pub fn calculate_rahu_position(jd: f64) -> f64 {
+    let horakhun = jd - 2378600.45833;
+    // สังเกตว่าความเร็วติดลบ และค่าเริ่มต้นอยู่ที่ประมาณ 110 องศา
+    let mut rahu = (6.78 - (0.052955 * horakhun)) % 360.0;
+    if rahu < 0.0 { rahu += 360.0; }
+    rahu
+}
+
+// เกตุไทย (๙): เดินหน้าคงที่ (ความเร็ว 1 ราศี ประมาณ 2 เดือน)
+pub fn calculate_ketu_thai_position(jd: f64) -> f64 {
+    let horakhun = jd - 2378600.45833;
+    // เกตุไทยเดินหน้าเร็วประมาณ 0.15 องศา/วัน
+    let mut ketu = (152.57 + (0.145 * horakhun)) % 360.0;
+    if ketu < 0.0 { ketu += 360.0; }
+    ketu
+}
+
+// ดาวมฤตยู (๐): เดินช้ามาก (ความเร็ว 0.0117 องศา/วัน) ประมาณ 7 ปีต่อราศี
+pub fn calculate_uranus_position(jd: f64) -> f64 {
+    uranus_position(&EarthState::at(jd))
+}
+
+fn uranus_position(earth: &EarthState) -> f64 {
+    let t = earth.t; // จำนวนศตวรรษจูเลียนหลัง epoch
+
+    // ===== 2. คำนวณวงโคจรดาวยูเรนัส (Heliocentric) =====
+    
+    // Mean Longitude ของดาวยูเรนัส ณ Epoch (องศา)
+    // ค่าเริ่มต้น ณ 13 เม.ย. 1800: ประมาณ 313.23 องศา
+    let mut u_l = 143.222 + 428.4820 * t + 0.00004315 * t * t;
+    u_l %= 360.0;
+    if u_l < 0.0 { u_l += 360.0; }
+    
+    // Perihelion ของดาวยูเรนัส
+    let mut u_perihelion = 171.5487 + 0.09266 * t + 0.00019132 * t * t;
+    u_perihelion %= 360.0;
+    if u_perihelion < 0.0 { u_perihelion += 360.0; }
+    
+    // Ascending Node ของดาวยูเรนัส
+    let mut u_node = 73.9893 - 0.06043 * t - 0.00016896 * t * t;
+    u_node %= 360.0;
+    if u_node < 0.0 { u_node += 360.0; }
+    
+    // Inclination (ความเอียงของวงโคจร)
+    let u_inc = 0.77298 + 0.00055 * t; // องศา (เอียงน้อยมาก - เกือบอยู่ในระนาบเดียวกับโลก!)
+    
+    // Mean Anomaly ของดาวยูเรนัส
+    let u_m = (u_l - u_perihelion).to_radians();
+    
+    // Eccentricity ของดาวยูเรนัส (แปรตามเวลา)
+    let u_ecc = 0.04629590 - 0.000027337 * t + 0.0000000790 * t * t;
+    
+    // Equation of Center สำหรับดาวยูเรนัส (4 พจน์ - เพราะ eccentricity น้อย)
+    let u_eq_center = 5.32174 * u_m.sin()
+                    + 0.22160 * (2.0 * u_m).sin()
+                    + 0.01380 * (3.0 * u_m).sin()
+                    + 0.00100 * (4.0 * u_m).sin();
+    
+    // True Anomaly
+    let u_v_true = u_l + u_eq_center;
+    
+    // Argument of Latitude (u = v + ω - Ω)
+    let u_arg_lat = (u_v_true - u_node).to_radians();
+    
+    // ระยะห่างดาวยูเรนัส-ดวงอาทิตย์ (AU)
+    let u_a = 19.19126; // Semi-major axis
+    let u_r = u_a * (1.0 - u_ecc * u_ecc) / (1.0 + u_ecc * u_m.cos());
+    
+    // ===== 3. คำนวณตำแหน่ง 3 มิติและแปลงเป็นระนาบอุปราศี =====
+    
+    let u_inc_rad = u_inc.to_radians();
+    let u_node_rad = u_node.to_radians();
+    
+    // แปลงเป็นพิกัด heliocentric ecliptic
+    let x_orb = u_r * u_arg_lat.cos();
+    let y_orb = u_r * u_arg_lat.sin();
+    
+    // แปลงจากระนาบวงโคจรไปสู่ระนาบอุปราศี (ecliptic)
+    let u_x_helio = x_orb * u_node_rad.cos() - y_orb * u_inc_rad.cos() * u_node_rad.sin();
+    let u_y_helio = x_orb * u_node_rad.sin() + y_orb * u_inc_rad.cos() * u_node_rad.cos();
+    
+    // ===== 4. แปลงเป็น Geocentric (มองจากโลก) =====
+    
+    // ตำแหน่งโลกในระบบ heliocentric
+    let e_x = earth.x;
+    let e_y = earth.y;
+    
+    // ตำแหน่งดาวยูเรนัสที่มองจากโลก
+    let geo_x = u_x_helio - e_x;
+    let geo_y = u_y_helio - e_y;
+    
+    // Geocentric longitude (ลองจิจูดท้องฟ้า)
+    let mut geocentric_long = geo_y.atan2(geo_x).to_degrees();
+    
+    // ===== 5. การแก้ไขเพิ่มเติม (Perturbations) =====
+    // พจน์แก้ไขจากดาวพฤหัสบดีและดาวเสาร์ (มีผลเล็กน้อย)
+    
+    // Mean longitude ของดาวพฤหัสบดี
+    let jupiter_mean_long = (34.3515 + 3034.9056 * t) % 360.0;
+    
+    // Mean longitude ของดาวเสาร์
+    let saturn_mean_long = (49.9485 + 1222.1138 * t) % 360.0;
+    
+    // Perturbation จากดาวพฤหัสบดี
+    let pert_jupiter = 0.01181 * (jupiter_mean_long.to_radians() - u_l.to_radians() + 0.3215).sin()
+                     + 0.00352 * (2.0 * jupiter_mean_long.to_radians() - 2.0 * u_l.to_radians() - 0.4587).sin();
+    
+    // Perturbation จากดาวเสาร์
+    let pert_saturn = 0.00202 * (saturn_mean_long.to_radians() - u_l.to_radians() - 0.1423).sin()
+                    + 0.00133 * (2.0 * saturn_mean_long.to_radians() - 2.0 * u_l.to_radians() + 0.5222).sin();
+    
+    // Perturbation จากดาวเนปจูน (น้อยมาก แต่มีผล)
+    let neptune_mean_long = (304.8800 + 218.4862 * t) % 360.0;
+    let pert_neptune = 0.00034 * (neptune_mean_long.to_radians() - u_l.to_radians()).sin();
+    
+    geocentric_long += pert_jupiter + pert_saturn + pert_neptune;
+    
+    // ===== 6. Nutation (การส่ายของแกนโลก) =====
+    geocentric_long += earth.nutation;
+    
+    // ===== 7. ปรับค่าให้อยู่ในช่วง 0-360 องศา =====
+    geocentric_long %= 360.0;
+    if geocentric_long < 0.0 { 
+        geocentric_long += 360.0; 
+    }
+    
+    geocentric_long
+}
\ No newline at end of file
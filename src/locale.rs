@@ -0,0 +1,636 @@
+// ภาษาของผลลัพธ์: ไทย อังกฤษ หรือคำไทยเขียนด้วยอักษรโรมัน (ถอดตามหลักราชบัณฑิตยสถาน)
+// เลือกด้วย --lang th|en|rtgs ค่าตั้งต้นเป็นไทย
+// ข้อความที่มีค่าแทรกใช้ {} เป็นตำแหน่งแทรก เรียงตามลำดับค่าที่ส่งให้ Lang::format
+// คำสั่ง calendar ยังแสดงวันจันทรคติเป็นภาษาไทยเสมอ (ขึ้น/แรม ค่ำ เดือน ไม่มีคำแปลที่ใช้กันทั่วไป)
+
+use std::fmt::Display;
+
+use crate::motion::MotionState;
+use crate::numerals::to_arabic_digits;
+use crate::planet::Planet;
+use crate::rectify::EventKind;
+use crate::transit::{NatalPoint, Relation, HOUSE_NAMES};
+use crate::{Province, WEEKDAY_NAMES, ZODIAC_NAMES};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Lang {
+    Thai,
+    English,
+    Romanized,
+}
+
+const SIGNS_EN: [&str; 12] = [
+    "Aries", "Taurus", "Gemini", "Cancer", "Leo", "Virgo",
+    "Libra", "Scorpio", "Sagittarius", "Capricorn", "Aquarius", "Pisces",
+];
+const SIGNS_RTGS: [&str; 12] = [
+    "Met", "Phruetsap", "Mithun", "Korakot", "Sing", "Kan",
+    "Tun", "Phichik", "Thanu", "Mangkon", "Kum", "Min",
+];
+
+const HOUSES_EN: [&str; 12] = [
+    "Self", "Wealth", "Siblings", "Home", "Children", "Enemies",
+    "Partner", "Death", "Fortune", "Career", "Gains", "Loss",
+];
+const HOUSES_RTGS: [&str; 12] = [
+    "Tanu", "Kadumpha", "Sahatcha", "Phanthu", "Putta", "Ari",
+    "Patni", "Marana", "Supha", "Kamma", "Lapha", "Winat",
+];
+
+const WEEKDAYS_EN: [&str; 7] = ["Sunday", "Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday"];
+const WEEKDAYS_RTGS: [&str; 7] = ["Athit", "Chan", "Angkhan", "Phut", "Phruehatsabodi", "Suk", "Sao"];
+
+// ข้อความคงที่ในผลลัพธ์และข้อความแจ้งข้อผิดพลาด
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Message {
+    UnknownCommand,
+    UnknownProvince,
+    UnknownPlanet,
+    UnknownLayout,
+    UnknownNotation,
+    InvalidDate,
+    InvalidTime,
+    UnknownDateWord,
+    TwoDigitYear,
+    AmbiguousYear,
+    NoSuchDay,
+    NoSuchTime,
+    WeekdayMismatch,
+    InvalidDays,
+    InvalidCoordinates,
+    ReadFailed,
+    BatchRowError,
+    BatchSummary,
+    InvalidYear,
+    WriteFailed,
+    LagnaRequired,
+    InvalidPosition,
+    ChartHeader,
+    LocalTime,
+    BirthWeekday,
+    RahuNight,
+    Degree,
+    TransitHeader,
+    NatalLagna,
+    TransitLabel,
+    TransitAspect,
+    IngressHeader,
+    Backward,
+    AtTime,
+    ClockTime,
+    YamHeader,
+    Daytime,
+    Nighttime,
+    Yam,
+    Favourable,
+    UnknownFormat,
+    EphemerisHeader,
+    EphemerisLegend,
+    SongkranHeader,
+    SongkranMoment,
+    WanNao,
+    Thaloengsok,
+    UnknownAction,
+    NameRequired,
+    DateRequired,
+    ProfileIdRequired,
+    ProfileNotFound,
+    QueryRequired,
+    InvalidRatio,
+    InvalidJson,
+    NotProfileFile,
+    ProfileVersion,
+    ProfileField,
+    ProfileSaved,
+    ProfileRemoved,
+    NoProfiles,
+    UnknownLanguage,
+    MissingParameter,
+    InvalidParameter,
+    InvalidQuery,
+    InvalidBody,
+    InvalidRequest,
+    HeaderTooLarge,
+    BodyTooLarge,
+    NotFound,
+    MethodNotAllowed,
+    TooManyDays,
+    InvalidLunarDate,
+    NoSuchLunarDay,
+    LunarYearRequired,
+    LunarWeekdayWarning,
+    ZodiacYearWarning,
+    LunarYear,
+    Adhikamasa,
+    Adhikavara,
+    NormalLunarYear,
+    InvalidPort,
+    ServeFailed,
+    Serving,
+    NotReferenceTable,
+    ReferenceColumn,
+    ReferenceField,
+    AccuracyHeader,
+    AccuracyColumns,
+    AccuracyPeriod,
+    AccuracyUnchecked,
+    AccuracyPartial,
+    YearOutOfRange,
+    UnsupportedEra,
+    InvalidEvent,
+    UnknownEvent,
+    EventRequired,
+    InvalidStep,
+    InvalidTop,
+    EmptyWindow,
+    EventBeforeBirth,
+    RectifyHeader,
+    RectifyColumns,
+    RectifyBest,
+    RectifyEvent,
+}
+
+impl Message {
+    // [ไทย, อังกฤษ, โรมัน]
+    fn texts(self) -> [&'static str; 3] {
+        match self {
+            Message::UnknownCommand => ["ไม่รู้จักคำสั่ง: {}", "Unknown command: {}", "Mai ru chak kham sang: {}"],
+            Message::UnknownProvince => ["ไม่รู้จักจังหวัด: {}", "Unknown province: {}", "Mai ru chak changwat: {}"],
+            Message::UnknownPlanet => ["ไม่รู้จักดาว: {}", "Unknown planet: {}", "Mai ru chak dao: {}"],
+            Message::UnknownLayout => [
+                "ไม่รู้จักรูปแบบดวง: {} (wheel หรือ square)",
+                "Unknown layout: {} (wheel or square)",
+                "Mai ru chak rup baep duang: {} (wheel rue square)",
+            ],
+            Message::UnknownNotation => [
+                "ไม่รู้จักรูปแบบตำแหน่ง: {} (decimal, rasi หรือ dms)",
+                "Unknown notation: {} (decimal, rasi or dms)",
+                "Mai ru chak rup baep tamnaeng: {} (decimal, rasi rue dms)",
+            ],
+            Message::InvalidDate => [
+                "รูปแบบวันที่ไม่ถูกต้อง (วัน/เดือน/ปี พ.ศ.): {}",
+                "Invalid date (day/month/Buddhist year): {}",
+                "Rup baep wan thi mai thuk tong (wan/duean/pi Pho So): {}",
+            ],
+            Message::InvalidTime => [
+                "รูปแบบเวลาไม่ถูกต้อง (ชั่วโมง:นาที): {}",
+                "Invalid time (hour:minute): {}",
+                "Rup baep wela mai thuk tong (chua mong:nathi): {}",
+            ],
+            Message::UnknownDateWord => [
+                "ไม่รู้จักคำว่า \"{}\" ในวันที่",
+                "Unknown word \"{}\" in date",
+                "Mai ru chak kham \"{}\" nai wan thi",
+            ],
+            Message::TwoDigitYear => [
+                "ปี {} มีเพียง ๒ หลัก ไม่รู้ว่าเป็นปีใด กรุณาเขียนปีเต็ม เช่น 2530",
+                "Year {} has only two digits and is ambiguous; write the full year, e.g. 2530",
+                "Pi {} mi phiang 2 lak; khian pi tem chen 2530",
+            ],
+            Message::AmbiguousYear => [
+                "ปี {} อาจเป็น พ.ศ. หรือ ค.ศ. ก็ได้ กรุณากำกับ เช่น \"ค.ศ. {}\" หรือ \"พ.ศ. {}\"",
+                "Year {} could be Buddhist or Gregorian; mark it, e.g. \"CE {}\" or \"BE {}\"",
+                "Pi {} pen dai thang Pho So lae Kho So; rabu chen \"Kho So {}\" rue \"Pho So {}\"",
+            ],
+            Message::NoSuchDay => [
+                "ไม่มีวันที่ {} เดือน {} ปี พ.ศ. {}",
+                "There is no day {} in month {} of BE {}",
+                "Mai mi wan thi {} duean {} pi Pho So {}",
+            ],
+            Message::NoSuchTime => ["ไม่มีเวลา {}", "There is no time {}", "Mai mi wela {}"],
+            Message::WeekdayMismatch => [
+                "วันที่นี้ตรงกับ{} ไม่ใช่{}",
+                "That date is a {}, not a {}",
+                "Wan thi ni trong kap {} mai chai {}",
+            ],
+            Message::InvalidCoordinates => ["พิกัดไม่ถูกต้อง: {}", "Invalid coordinates: {}", "Phikat mai thuk tong: {}"],
+            Message::ReadFailed => ["อ่านไฟล์ {} ไม่ได้: {}", "Cannot read {}: {}", "An fai {} mai dai: {}"],
+            Message::BatchRowError => ["บรรทัด {}: {}", "Line {}: {}", "Banthat {}: {}"],
+            Message::BatchSummary => [
+                "คำนวณ {} รายการ ผิดพลาด {} รายการ",
+                "Computed {} records, {} with errors",
+                "Khamnuan {} rai kan, phit phlat {} rai kan",
+            ],
+            Message::InvalidDays => ["จำนวนวันไม่ถูกต้อง", "Invalid number of days", "Chamnuan wan mai thuk tong"],
+            Message::InvalidYear => ["ปี พ.ศ. ไม่ถูกต้อง", "Invalid Buddhist year", "Pi Pho So mai thuk tong"],
+            Message::WriteFailed => ["เขียนไฟล์ {} ไม่ได้: {}", "Cannot write {}: {}", "Khian fai {} mai dai: {}"],
+            Message::LagnaRequired => [
+                "ต้องระบุ --lagna (ราศี.องศา.ลิปดา เช่น 5.11.08)",
+                "--lagna is required (sign.degree.minute, e.g. 5.11.08)",
+                "Tong rabu --lagna (rasi.ongsa.lipda chen 5.11.08)",
+            ],
+            Message::InvalidPosition => [
+                "อ่านตำแหน่ง{}ไม่ได้: {} (ราศี.องศา.ลิปดา หรือ องศา°ลิปดา'ฟิลิปดา\")",
+                "Cannot read position of {}: {} (sign.degree.minute or degree°minute'second\")",
+                "An tamnaeng {} mai dai: {} (rasi.ongsa.lipda rue ongsa°lipda'filipda\")",
+            ],
+            Message::ChartHeader => [
+                "--- ผลคำนวณดวงชะตาสุริยยาตร์ ---",
+                "--- Suriyayatra birth chart ---",
+                "--- Phon khamnuan duang chata Suriyayat ---",
+            ],
+            Message::LocalTime => [
+                "จังหวัด: {} | เวลาท้องถิ่น (LMT): {} น.",
+                "Province: {} | Local mean time (LMT): {}",
+                "Changwat: {} | Wela thongthin (LMT): {}",
+            ],
+            Message::BirthWeekday => [
+                "วันเกิด: {} | ทางโหราศาสตร์: {}{}",
+                "Born on: {} | Astrological day: {}{}",
+                "Wan koet: {} | Thang horasat: {}{}",
+            ],
+            Message::RahuNight => ["กลางคืน (ราหู)", " night (Rahu)", " klang khuen (Rahu)"],
+            Message::Degree => ["องศา", "deg", "ongsa"],
+            Message::TransitHeader => ["--- ดวงจร {} เวลา {} ---", "--- Transits {} at {} ---", "--- Duang chon {} wela {} ---"],
+            Message::NatalLagna => ["ลัคนากำเนิด: {}", "Natal ascendant: {}", "Lakhana kamnoet: {}"],
+            Message::TransitLabel => ["{}จร ({}):", "Transit {} ({}):", "{} chon ({}):"],
+            Message::TransitAspect => ["{}จร{}{}", "transit {} {} natal {}", "{} chon {} {}"],
+            Message::IngressHeader => ["--- ดาวย้ายราศี ---", "--- Sign ingresses ---", "--- Dao yai rasi ---"],
+            Message::Backward => ["(ถอยหลัง)", "(retrograde)", "(thoi lang)"],
+            Message::AtTime => ["เวลา {}", "at {}", "wela {}"],
+            Message::ClockTime => ["{} น.", "{}", "{} n."],
+            Message::YamHeader => ["--- ยามอัฐกาล {} {} {} ---", "--- Yam Atthakan {} {} {} ---", "--- Yam Atthakan {} {} {} ---"],
+            Message::Daytime => ["กลางวัน", "Day", "Klang wan"],
+            Message::Nighttime => ["กลางคืน", "Night", "Klang khuen"],
+            Message::Yam => ["ยาม {}", "Yam {}", "Yam {}"],
+            Message::Favourable => ["ยามดี", "favourable", "yam di"],
+            Message::UnknownFormat => [
+                "ไม่รู้จักรูปแบบผลลัพธ์: {} (text, csv หรือ json)",
+                "Unknown output format: {} (text, csv or json)",
+                "Mai ru chak rup baep phonlap: {} (text, csv rue json)",
+            ],
+            Message::EphemerisHeader => [
+                "--- ปฏิทินโหราศาสตร์ {} ถึง {} เวลา {} ---",
+                "--- Ephemeris {} to {} at {} ---",
+                "--- Pathithin horasat {} thueng {} wela {} ---",
+            ],
+            Message::EphemerisLegend => [
+                "{} ย้ายราศี  {} พักร์  {} มนฑ์  {} เสริด",
+                "{} sign ingress  {} retrograde  {} slow  {} fast",
+                "{} yai rasi  {} phak  {} mon  {} soet",
+            ],
+            Message::SongkranHeader => [
+                "--- สงกรานต์ พ.ศ. {} (จ.ศ. {}) ---",
+                "--- Songkran BE {} (CS {}) ---",
+                "--- Songkran Pho So {} (Cho So {}) ---",
+            ],
+            Message::SongkranMoment => ["มหาสงกรานต์: {} เวลา {}", "Maha Songkran: {} at {}", "Maha Songkran: {} wela {}"],
+            Message::WanNao => ["วันเนา:      {}", "Wan Nao:      {}", "Wan Nao:      {}"],
+            Message::Thaloengsok => ["วันเถลิงศก:  {} เวลา {}", "Wan Thaloeng Sok: {} at {}", "Wan Thaloeng Sok: {} wela {}"],
+            Message::UnknownAction => [
+                "ไม่รู้จักคำสั่งย่อย: {} ({})",
+                "Unknown action: {} ({})",
+                "Mai ru chak kham sang yoi: {} ({})",
+            ],
+            Message::NameRequired => ["ต้องระบุ --name", "--name is required", "Tong rabu --name"],
+            Message::DateRequired => [
+                "ต้องระบุวันเกิดด้วย --birth หรือ --date",
+                "A birth date is required (--birth or --date)",
+                "Tong rabu wan koet duai --birth rue --date",
+            ],
+            Message::ProfileIdRequired => ["ต้องระบุเลขที่โปรไฟล์ --id", "--id is required", "Tong rabu lek thi --id"],
+            Message::ProfileNotFound => ["ไม่พบโปรไฟล์เลขที่ {}", "No profile with id {}", "Mai phop profile lek thi {}"],
+            Message::QueryRequired => ["ต้องระบุคำค้น", "A search term is required", "Tong rabu kham khon"],
+            Message::InvalidRatio => ["อัตราส่วนไม่ถูกต้อง: {}", "Invalid ratio: {}", "Attra suan mai thuk tong: {}"],
+            Message::InvalidJson => [
+                "ไฟล์ {} ไม่ใช่ JSON ที่ถูกต้อง (ตำแหน่ง {})",
+                "{} is not valid JSON (at byte {})",
+                "Fai {} mai chai JSON thi thuk tong (tamnaeng {})",
+            ],
+            Message::NotProfileFile => ["ไฟล์ {} ไม่ใช่แฟ้มโปรไฟล์ของ horathai", "{} is not a horathai profile file", "Fai {} mai chai faem profile khong horathai"],
+            Message::ProfileVersion => [
+                "ไฟล์ {} เป็นรูปแบบรุ่น {} แต่โปรแกรมนี้อ่านได้ถึงรุ่น {}",
+                "{} uses format version {} but this program reads up to version {}",
+                "Fai {} pen rup baep run {} tae program ni an dai thueng run {}",
+            ],
+            Message::ProfileField => ["ไฟล์ {}: ค่า {} ขาดหายหรือไม่ถูกต้อง", "{}: {} is missing or invalid", "Fai {}: kha {} khat hai rue mai thuk tong"],
+            Message::ProfileSaved => ["บันทึกโปรไฟล์เลขที่ {}: {}", "Saved profile {}: {}", "Bantuek profile lek thi {}: {}"],
+            Message::ProfileRemoved => ["ลบโปรไฟล์เลขที่ {}: {}", "Removed profile {}: {}", "Lop profile lek thi {}: {}"],
+            Message::NoProfiles => ["ไม่พบโปรไฟล์", "No profiles found", "Mai phop profile"],
+            Message::UnknownLanguage => [
+                "ไม่รู้จักภาษา: {} (th, en หรือ rtgs)",
+                "Unknown language: {} (th, en or rtgs)",
+                "Mai ru chak phasa: {} (th, en rue rtgs)",
+            ],
+            Message::MissingParameter => ["ต้องระบุ {}", "{} is required", "Tong rabu {}"],
+            Message::InvalidParameter => ["ค่า {} ต้องเป็นข้อความหรือตัวเลข", "{} must be a string or number", "Kha {} tong pen khwam rue tua lek"],
+            Message::InvalidQuery => ["query ไม่ถูกต้อง: {}", "Invalid query: {}", "Query mai thuk tong: {}"],
+            Message::InvalidBody => [
+                "body ต้องเป็น JSON object ที่ถูกต้อง (ตำแหน่ง {})",
+                "The body must be a valid JSON object (at byte {})",
+                "Body tong pen JSON object thi thuk tong (tamnaeng {})",
+            ],
+            Message::InvalidRequest => ["คำขอ HTTP ไม่ถูกต้อง", "Malformed HTTP request", "Kham kho HTTP mai thuk tong"],
+            Message::HeaderTooLarge => ["ส่วนหัวของคำขอยาวเกินไป", "Request headers are too large", "Suan hua khong kham kho yao koen pai"],
+            Message::BodyTooLarge => ["body ยาวเกิน {} ไบต์", "The body is larger than {} bytes", "Body yao koen {} bai"],
+            Message::NotFound => ["ไม่มีบริการ {}", "No such endpoint: {}", "Mai mi borikan {}"],
+            Message::MethodNotAllowed => ["{} ใช้กับ {} ไม่ได้", "{} is not allowed on {}", "{} chai kap {} mai dai"],
+            Message::TooManyDays => ["ขอได้ครั้งละไม่เกิน {} วัน", "At most {} days per request", "Kho dai khrang la mai koen {} wan"],
+            Message::InvalidLunarDate => ["อ่านวันจันทรคติไม่ได้: {}", "Cannot read lunar date: {}", "An wan chanthrakhati mai dai: {}"],
+            Message::NoSuchLunarDay => ["ไม่มี {} ในปี พ.ศ. {}", "There is no {} in BE {}", "Mai mi {} nai pi Pho So {}"],
+            Message::LunarYearRequired => [
+                "ต้องระบุปี พ.ศ. ในข้อความหรือด้วย --year",
+                "A BE year is required, in the text or with --year",
+                "Tong rabu pi Pho So nai khokhwam rue duai --year",
+            ],
+            Message::LunarWeekdayWarning => [
+                "คำเตือน: วันนี้ตรงกับ{} ไม่ใช่{}",
+                "Warning: this date is a {}, not a {}",
+                "Khamtuean: wan ni trong kap {} mai chai {}",
+            ],
+            Message::ZodiacYearWarning => [
+                "คำเตือน: พ.ศ. {} เป็นปี{} ไม่ใช่ปี{}",
+                "Warning: BE {} is a year of {}, not {}",
+                "Khamtuean: Pho So {} pen pi {} mai chai pi {}",
+            ],
+            Message::LunarYear => [
+                "ปีจันทรคติ พ.ศ. {} (จ.ศ. {}): {} {} วัน",
+                "Lunar year BE {} (CS {}): {}, {} days",
+                "Pi chanthrakhati Pho So {} (Cho So {}): {} {} wan",
+            ],
+            Message::Adhikamasa => ["อธิกมาส (เดือน ๘ สองหน)", "leap month (month 8 twice)", "Athikkamat (duean 8 song hon)"],
+            Message::Adhikavara => ["อธิกวาร (เดือน ๗ มี ๓๐ วัน)", "leap day (month 7 has 30 days)", "Athikkawan (duean 7 mi 30 wan)"],
+            Message::NormalLunarYear => ["ปกติมาส ปกติวาร", "common year", "Pakatimat pakatiwan"],
+            Message::InvalidPort => ["หมายเลขพอร์ตไม่ถูกต้อง: {}", "Invalid port: {}", "Mai lek port mai thuk tong: {}"],
+            Message::ServeFailed => ["เปิดบริการที่ {} ไม่ได้: {}", "Cannot serve on {}: {}", "Poet borikan thi {} mai dai: {}"],
+            Message::Serving => [
+                "เปิดบริการที่ http://{} (กด Ctrl+C เพื่อหยุด)",
+                "Serving on http://{} (press Ctrl+C to stop)",
+                "Poet borikan thi http://{} (kot Ctrl+C phuea yut)",
+            ],
+            Message::NotReferenceTable => [
+                "ไฟล์ {} ไม่ใช่ตารางอ้างอิง (คอลัมน์ date, time แล้วตามด้วยชื่อดาว เช่น sun, moon)",
+                "{} is not a reference table (columns date, time, then planet names such as sun, moon)",
+                "Fai {} mai chai tarang angoing (khonlam date, time laeo tam duai chue dao chen sun, moon)",
+            ],
+            Message::ReferenceColumn => ["ไฟล์ {}: ไม่รู้จักคอลัมน์ {}", "{}: unknown column {}", "Fai {}: mai ru chak khonlam {}"],
+            Message::ReferenceField => [
+                "ไฟล์ {} บรรทัด {}: ค่า {} ขาดหายหรือไม่ถูกต้อง",
+                "{} line {}: {} is missing or invalid",
+                "Fai {} banthat {}: kha {} khat hai rue mai thuk tong",
+            ],
+            Message::AccuracyHeader => [
+                "--- ความคลาดเคลื่อนของตำแหน่งดาว (องศา) เทียบกับ {} {} จุด ตั้งแต่ {} ถึง {} ---",
+                "--- Position error (degrees) against {}, {} samples from {} to {} ---",
+                "--- Khwam khlatkhluean khong tamnaeng dao (ongsa) thiap kap {} {} chut tangtae {} thueng {} ---",
+            ],
+            Message::AccuracyColumns => [
+                "จุด,สูงสุด,เฉลี่ย,RMS,คลาดมากสุดเมื่อ",
+                "Samples,Max,Mean,RMS,Worst date",
+                "Chut,Sungsut,Chalia,RMS,Khlat mak sut muea",
+            ],
+            Message::AccuracyPeriod => ["ปี พ.ศ.", "Years (BE)", "Pi Pho So"],
+            Message::YearOutOfRange => [
+                "คำนวณได้เฉพาะปี พ.ศ. {} ถึง {} (ได้ปี {})",
+                "Only BE {} to {} can be calculated (got {})",
+                "Khamnuan dai chapho pi Pho So {} thueng {} (dai pi {})",
+            ],
+            Message::UnsupportedEra => [
+                "ยังแปลงศักราช {} ไม่ได้ กรุณาใช้ พ.ศ. หรือ ค.ศ.",
+                "The {} era is not supported; use BE or CE",
+                "Yang plaeng sakkarat {} mai dai; chai Pho So rue Kho So",
+            ],
+            Message::AccuracyUnchecked => ["ไม่มีค่าอ้างอิงให้เทียบ: {}", "No reference values for: {}", "Mai mi kha angoing hai thiap: {}"],
+            Message::AccuracyPartial => [
+                "มีค่าอ้างอิงเฉพาะ {} ถึง {}: {}",
+                "Reference values only from {} to {}: {}",
+                "Mi kha angoing chapho {} thueng {}: {}",
+            ],
+            Message::InvalidEvent => [
+                "เหตุการณ์ต้องเป็น ชนิด:วันที่ เช่น marriage:12/5/2555 (ได้ {})",
+                "Events must be kind:date such as marriage:12/5/2555 (got {})",
+                "Hetkan tong pen chanit:wanthi chen marriage:12/5/2555 (dai {})",
+            ],
+            Message::UnknownEvent => ["ไม่รู้จักเหตุการณ์: {} ({})", "Unknown event: {} ({})", "Mai ru chak hetkan: {} ({})"],
+            Message::EventRequired => [
+                "ต้องระบุเหตุการณ์อย่างน้อยหนึ่งเหตุการณ์ เช่น --event marriage:12/5/2555",
+                "At least one event is required, such as --event marriage:12/5/2555",
+                "Tong rabu hetkan yang noi nueng hetkan chen --event marriage:12/5/2555",
+            ],
+            Message::InvalidStep => ["ช่วงเวลาต้องเป็นจำนวนนาทีตั้งแต่ 1 ขึ้นไป: {}", "The step must be a whole number of minutes, at least 1: {}", "Chuang wela tong pen chamnuan nathi tangtae 1 khuen pai: {}"],
+            Message::InvalidTop => ["จำนวนอันดับไม่ถูกต้อง: {}", "Invalid number of results: {}", "Chamnuan andap mai thuk tong: {}"],
+            Message::EmptyWindow => [
+                "เวลาเริ่ม (--from) ต้องไม่หลังเวลาสิ้นสุด (--to)",
+                "The start time (--from) must not be after the end time (--to)",
+                "Wela roem (--from) tong mai lang wela sinsut (--to)",
+            ],
+            Message::EventBeforeBirth => ["{} วันที่ {} อยู่ก่อนวันเกิด", "{} on {} is before the birth date", "{} wanthi {} yu kon wan koet"],
+            Message::RectifyHeader => [
+                "--- ตั้งเวลาเกิด {} ระหว่าง {} ถึง {} ทุก {} นาที ({}) จาก {} เหตุการณ์ ---",
+                "--- Rectifying {} between {} and {} every {} minutes ({}) from {} events ---",
+                "--- Tang wela koet {} rawang {} thueng {} thuk {} nathi ({}) chak {} hetkan ---",
+            ],
+            Message::RectifyColumns => ["เวลา,ลัคนา,ดาววันเกิด,คะแนน", "Time,Ascendant,Birth planet,Score", "Wela,Lakhana,Dao wan koet,Khanaen"],
+            Message::RectifyBest => ["เวลาที่เข้ากับเหตุการณ์มากที่สุด {} ลัคนา{}", "Best fitting time {}, ascendant {}", "Wela thi khao kap hetkan mak thi sut {} lakhana {}"],
+            Message::RectifyEvent => [
+                "  {} {}: {} ({}) มหาทักษา {} แทรก {} คะแนนดาวจร {} ทักษา {}",
+                "  {} {}: {} ({}), period {} / {}, transit score {}, period score {}",
+                "  {} {}: {} ({}) maha thaksa {} saek {} khanaen dao chon {} thaksa {}",
+            ],
+        }
+    }
+}
+
+impl Lang {
+    pub fn from_key(key: &str) -> Option<Lang> {
+        match key {
+            "th" => Some(Lang::Thai),
+            "en" => Some(Lang::English),
+            "rtgs" => Some(Lang::Romanized),
+            _ => None,
+        }
+    }
+
+    fn pick<T>(self, thai: T, english: T, romanized: T) -> T {
+        match self {
+            Lang::Thai => thai,
+            Lang::English => english,
+            Lang::Romanized => romanized,
+        }
+    }
+
+    pub fn text(self, message: Message) -> &'static str {
+        let [thai, english, romanized] = message.texts();
+        self.pick(thai, english, romanized)
+    }
+
+    // แทน {} ในข้อความด้วยค่าตามลำดับ
+    pub fn format(self, message: Message, values: &[&dyn Display]) -> String {
+        let mut parts = self.text(message).split("{}");
+        let mut result = parts.next().unwrap_or("").to_string();
+        for (i, part) in parts.enumerate() {
+            if let Some(value) = values.get(i) {
+                result.push_str(&value.to_string());
+            }
+            result.push_str(part);
+        }
+        result
+    }
+
+    pub fn sign(self, sign: usize) -> &'static str {
+        self.pick(ZODIAC_NAMES[sign], SIGNS_EN[sign], SIGNS_RTGS[sign])
+    }
+
+    // ชื่อราศีในตาราง เช่น "ราศีกันย์" / "Virgo"
+    pub fn sign_label(self, sign: usize) -> String {
+        match self {
+            Lang::Thai => format!("ราศี{}", self.sign(sign)),
+            _ => self.sign(sign).to_string(),
+        }
+    }
+
+    pub fn planet(self, planet: Planet) -> &'static str {
+        let english = match planet {
+            Planet::Sun => "Sun",
+            Planet::Moon => "Moon",
+            Planet::Mars => "Mars",
+            Planet::Mercury => "Mercury",
+            Planet::Jupiter => "Jupiter",
+            Planet::Venus => "Venus",
+            Planet::Saturn => "Saturn",
+            Planet::Rahu => "Rahu",
+            Planet::Ketu => "Ketu",
+            Planet::Uranus => "Uranus",
+        };
+        let romanized = match planet {
+            Planet::Sun => "Athit",
+            Planet::Moon => "Chan",
+            Planet::Mars => "Angkhan",
+            Planet::Mercury => "Phut",
+            Planet::Jupiter => "Phruehatsabodi",
+            Planet::Venus => "Suk",
+            Planet::Saturn => "Sao",
+            Planet::Rahu => "Rahu",
+            Planet::Ketu => "Ket",
+            Planet::Uranus => "Maruettayu",
+        };
+        self.pick(planet.thai_name(), english, romanized)
+    }
+
+    // เลขดาว: เลขไทยในภาษาไทย เลขอารบิกในภาษาอื่น
+    pub fn numeral(self, planet: Planet) -> String {
+        match self {
+            Lang::Thai => planet.numeral().to_string(),
+            _ => to_arabic_digits(planet.numeral()),
+        }
+    }
+
+    // ชื่อดาวพร้อมเลขดาว เช่น "อาทิตย์ (๑)" / "Sun (1)"
+    pub fn planet_label(self, planet: Planet) -> String {
+        format!("{} ({})", self.planet(planet), self.numeral(planet))
+    }
+
+    pub fn lagna(self) -> &'static str {
+        self.pick("ลัคนา", "Ascendant", "Lakhana")
+    }
+
+    // อักษรแทนลัคนาในผังดวง
+    pub fn lagna_mark(self) -> &'static str {
+        self.pick("ล", "L", "L")
+    }
+
+    pub fn lagna_label(self) -> String {
+        format!("{} ({})", self.lagna(), self.lagna_mark())
+    }
+
+    pub fn natal_point(self, point: NatalPoint) -> &'static str {
+        match (self, point) {
+            (Lang::Thai, _) => point.thai_name(),
+            (_, NatalPoint::Lagna) => self.lagna(),
+            (_, NatalPoint::Planet(planet)) => self.planet(planet),
+        }
+    }
+
+    pub fn house(self, house: usize) -> &'static str {
+        self.pick(HOUSE_NAMES[house], HOUSES_EN[house], HOUSES_RTGS[house])
+    }
+
+    // ภพในตาราง เช่น "ภพตนุ" / "House 1 (Self)" / "Phop Tanu"
+    pub fn house_label(self, house: usize) -> String {
+        match self {
+            Lang::Thai => format!("ภพ{}", self.house(house)),
+            Lang::English => format!("House {} ({})", house + 1, self.house(house)),
+            Lang::Romanized => format!("Phop {}", self.house(house)),
+        }
+    }
+
+    pub fn weekday(self, weekday: usize) -> &'static str {
+        self.pick(WEEKDAY_NAMES[weekday], WEEKDAYS_EN[weekday], WEEKDAYS_RTGS[weekday])
+    }
+
+    // ชื่อวันแบบเต็ม เช่น "วันพุธ" / "Wednesday" / "Wan Phut"
+    pub fn weekday_label(self, weekday: usize) -> String {
+        match self {
+            Lang::Thai => format!("วัน{}", self.weekday(weekday)),
+            Lang::English => self.weekday(weekday).to_string(),
+            Lang::Romanized => format!("Wan {}", self.weekday(weekday)),
+        }
+    }
+
+    pub fn relation(self, relation: Relation) -> &'static str {
+        let english = match relation {
+            Relation::Conjunct => "conjunct",
+            Relation::Opposite => "opposite",
+            Relation::Trine => "trine",
+            Relation::Square => "square",
+            Relation::Sextile => "sextile",
+        };
+        let romanized = match relation {
+            Relation::Conjunct => "thap",
+            Relation::Opposite => "leng",
+            Relation::Trine => "trikon",
+            Relation::Square => "chak",
+            Relation::Sextile => "yok",
+        };
+        self.pick(relation.thai_name(), english, romanized)
+    }
+
+    pub fn event(self, kind: EventKind) -> &'static str {
+        let english = match kind {
+            EventKind::Marriage => "Marriage",
+            EventKind::Child => "Child",
+            EventKind::Career => "Career change",
+        };
+        let romanized = match kind {
+            EventKind::Marriage => "Taeng ngan",
+            EventKind::Child => "Mi but",
+            EventKind::Career => "Plian ngan",
+        };
+        self.pick(kind.thai_name(), english, romanized)
+    }
+
+    pub fn motion_flag(self, motion: MotionState) -> &'static str {
+        let english = match motion {
+            MotionState::Normal => "",
+            MotionState::Retrograde => "retrograde",
+            MotionState::Slow => "slow",
+            MotionState::Fast => "fast",
+        };
+        let romanized = match motion {
+            MotionState::Normal => "",
+            MotionState::Retrograde => "phak",
+            MotionState::Slow => "mon",
+            MotionState::Fast => "soet",
+        };
+        self.pick(motion.thai_flag(), english, romanized)
+    }
+
+    // อักษรย่อสถานะการเดินที่กำกับเลขดาวในผังดวง
+    pub fn motion_mark(self, motion: MotionState) -> &'static str {
+        let latin = match motion {
+            MotionState::Normal => "",
+            MotionState::Retrograde => "R",
+            MotionState::Slow => "S",
+            MotionState::Fast => "F",
+        };
+        self.pick(motion.thai_mark(), latin, latin)
+    }
+
+    // สถานที่ที่ระบุเป็นพิกัด (ไม่มีชื่อจังหวัด) แสดงเป็นละติจูด, ลองจิจูด
+    pub fn province(self, province: &Province) -> String {
+        if province.name.is_empty() {
+            return format!("{:.2}, {:.2}", province.latitude, province.longitude);
+        }
+        self.pick(province.name, province.english_name, province.english_name).to_string()
+    }
+}
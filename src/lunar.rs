@@ -0,0 +1,247 @@
+// ปฏิทินจันทรคติไทย ตามคัมภีร์สุริยยาตร์
+// ปีจันทรคติในที่นี้เริ่มที่ ขึ้น ๑ ค่ำ เดือน ๕ และนับตามจุลศักราช (จ.ศ.) ของสงกรานต์ในปีนั้น
+// เดือนคี่ ๒๙ วัน (เดือนขาด) เดือนคู่ ๓๐ วัน (เดือนถ้วน) ปกติปีละ ๓๕๔ วัน
+//   อธิกวาร: เดือน ๗ เพิ่มเป็น ๓๐ วัน (๓๕๕ วัน)
+//   อธิกมาส: มีเดือน ๘ สองหน (๘๘) (๓๘๔ วัน)
+// ชนิดปีหาจาก หรคุณ อวมาน ดิถี และกัมมัชพล ณ วันเถลิงศก
+
+use crate::numerals::{to_arabic_digits, to_thai_digits};
+use crate::{jd_to_date, julian_day, WEEKDAY_NAMES};
+
+// ผลต่าง พ.ศ. กับ จ.ศ.
+pub const BE_CS_OFFSET: i64 = 1181;
+
+// ขึ้น ๑ ค่ำ เดือน ๕ ของ จ.ศ. 1386 (พ.ศ. 2567) ตรงกับ 9 เม.ย. 2567 (JDN 2460410)
+// ใช้เป็นหมุดแล้วนับความยาวปีต่อไปข้างหน้าหรือย้อนหลัง
+const ANCHOR_CS: i64 = 1386;
+const ANCHOR_JDN: i64 = 2460410;
+
+// ชื่อปีนักษัตร เริ่มจากชวด
+pub const ZODIAC_YEAR_NAMES: [&str; 12] = [
+    "ชวด", "ฉลู", "ขาล", "เถาะ", "มะโรง", "มะเส็ง", "มะเมีย", "มะแม", "วอก", "ระกา", "จอ", "กุน",
+];
+
+// หรคุณ ณ วันเถลิงศก: จำนวนวันนับจากต้นจุลศักราช
+pub fn horakhun(cs: i64) -> i64 {
+    (292207 * cs + 373).div_euclid(800) + 1
+}
+
+// กัมมัชพล: เศษของวันเถลิงศก (หน่วย 1/800 วัน)
+pub fn kammachapon(cs: i64) -> i64 {
+    800 - (292207 * cs + 373).rem_euclid(800)
+}
+
+// อวมาน: เศษที่สะสมจากดิถีเดินเร็วกว่าวัน (หน่วย 1/692)
+fn avoman(cs: i64) -> i64 {
+    (11 * horakhun(cs) + 650).rem_euclid(692)
+}
+
+// ดิถี ณ วันเถลิงศก (0-29)
+fn tithi(cs: i64) -> i64 {
+    let h = horakhun(cs);
+    (h + (11 * h + 650).div_euclid(692)).rem_euclid(30)
+}
+
+// ดิถีตั้งแต่ 24 ขึ้นไป หรือ 5 ลงมา เข้าเกณฑ์อธิกมาส
+fn adhikamasa_candidate(cs: i64) -> bool {
+    let t = tithi(cs);
+    t >= 24 || t <= 5
+}
+
+pub fn is_adhikamasa(cs: i64) -> bool {
+    // ดิถี 24 แล้วปีหน้าเข้าเกณฑ์ด้วย ให้ยกอธิกมาสไปปีหน้า
+    adhikamasa_candidate(cs) && !(tithi(cs) >= 24 && adhikamasa_candidate(cs + 1))
+}
+
+// เกณฑ์อวมาน: ปีสุรทินอธิกสุรทิน (กัมมัชพลไม่เกิน 207) ใช้ 126 ปีปกติใช้ 137
+fn adhikavara_condition(cs: i64) -> bool {
+    let a = avoman(cs);
+    if kammachapon(cs) <= 207 {
+        a <= 126
+    } else {
+        a < 137 || (a == 137 && avoman(cs + 1) != 0)
+    }
+}
+
+pub fn is_adhikavara(cs: i64) -> bool {
+    // ปีอธิกมาสมีอธิกวารซ้อนไม่ได้ ให้เลื่อนไปปีถัดไป
+    if is_adhikamasa(cs) {
+        return false;
+    }
+    adhikavara_condition(cs) || (is_adhikamasa(cs - 1) && adhikavara_condition(cs - 1))
+}
+
+pub fn year_length(cs: i64) -> i64 {
+    if is_adhikamasa(cs) {
+        384
+    } else if is_adhikavara(cs) {
+        355
+    } else {
+        354
+    }
+}
+
+// JDN ของ ขึ้น ๑ ค่ำ เดือน ๕ ปี จ.ศ. cs
+fn year_start(cs: i64) -> i64 {
+    let mut jdn = ANCHOR_JDN;
+    if cs >= ANCHOR_CS {
+        for y in ANCHOR_CS..cs { jdn += year_length(y); }
+    } else {
+        for y in cs..ANCHOR_CS { jdn -= year_length(y); }
+    }
+    jdn
+}
+
+// เดือนในปีตามลำดับ (เลขเดือน, เป็นเดือน ๘ หลังหรือไม่, จำนวนวัน)
+fn months(cs: i64) -> Vec<(u32, bool, i64)> {
+    let mut months = vec![
+        (5, false, 29),
+        (6, false, 30),
+        (7, false, if is_adhikavara(cs) { 30 } else { 29 }),
+        (8, false, 30),
+    ];
+    if is_adhikamasa(cs) {
+        months.push((8, true, 30));
+    }
+    for month in [9, 10, 11, 12, 1, 2, 3, 4] {
+        months.push((month, false, if month % 2 == 0 { 30 } else { 29 }));
+    }
+    months
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LunarDate {
+    pub weekday: usize,      // 0 = อาทิตย์
+    pub waxing: bool,        // ข้างขึ้น (true) / ข้างแรม (false)
+    pub day: u32,            // ค่ำ
+    pub month: u32,          // เดือน ๑-๑๒
+    pub second_eighth: bool, // เดือน ๘ หลัง ในปีอธิกมาส
+    pub year_be: i64,        // ปีจันทรคติ (พ.ศ. ของปีที่เริ่มเดือน ๕) ต้นปี พ.ศ. 0 เป็นปีจันทรคติ พ.ศ. -1
+}
+
+impl LunarDate {
+    pub fn zodiac_year(&self) -> &'static str {
+        ZODIAC_YEAR_NAMES[(self.year_be - BE_CS_OFFSET + 10).rem_euclid(12) as usize]
+    }
+
+    // รูปแบบที่ใช้ในสูติบัตร เช่น "วันอังคาร ขึ้น ๕ ค่ำ เดือน ๑๑ ปีเถาะ"
+    pub fn to_thai_string(self) -> String {
+        let month = if self.second_eighth { "๘๘".to_string() } else { to_thai_digits(&self.month.to_string()) };
+        format!(
+            "วัน{} {} {} ค่ำ เดือน {} ปี{}",
+            WEEKDAY_NAMES[self.weekday],
+            if self.waxing { "ขึ้น" } else { "แรม" },
+            to_thai_digits(&self.day.to_string()),
+            month,
+            self.zodiac_year(),
+        )
+    }
+}
+
+// วันที่สุริยคติ (วัน, เดือน, ปี พ.ศ.) -> จันทรคติ
+pub fn to_lunar(d: u32, m: u32, y_be: u32) -> LunarDate {
+    let jdn = julian_day(d, m, y_be, 12, 0) as i64;
+
+    // ประมาณปีจาก พ.ศ. แล้วขยับให้วันนั้นอยู่ในช่วงของปี
+    let mut cs = y_be as i64 - BE_CS_OFFSET;
+    while year_start(cs) > jdn { cs -= 1; }
+    while year_start(cs + 1) <= jdn { cs += 1; }
+
+    let mut offset = jdn - year_start(cs);
+    let mut result = None;
+    for (month, second_eighth, length) in months(cs) {
+        if offset < length {
+            result = Some((month, second_eighth, offset));
+            break;
+        }
+        offset -= length;
+    }
+    let (month, second_eighth, offset) = result.expect("วันต้องอยู่ในปีที่หาได้");
+
+    LunarDate {
+        weekday: (jdn + 1).rem_euclid(7) as usize,
+        waxing: offset < 15,
+        day: if offset < 15 { offset + 1 } else { offset - 14 } as u32,
+        month,
+        second_eighth,
+        year_be: cs + BE_CS_OFFSET,
+    }
+}
+
+// จันทรคติ -> สุริยคติ (วัน, เดือน, ปี พ.ศ.)
+// ไม่พบเมื่อค่ำเกินจำนวนวันของเดือน หรือขอเดือน ๘๘ ในปีที่ไม่ใช่อธิกมาส
+pub fn from_lunar(waxing: bool, day: u32, month: u32, second_eighth: bool, year_be: u32) -> Option<(u32, u32, u32)> {
+    let cs = year_be as i64 - BE_CS_OFFSET;
+    let mut jdn = year_start(cs);
+    for (m, second, length) in months(cs) {
+        if m == month && second == second_eighth {
+            let offset = if waxing { day as i64 - 1 } else { day as i64 + 14 };
+            if day == 0 || (waxing && day > 15) || offset >= length {
+                return None;
+            }
+            let (d, m, y, _, _) = jd_to_date((jdn + offset) as f64);
+            return Some((d, m, y));
+        }
+        jdn += length;
+    }
+    None
+}
+
+// ข้อความจันทรคติที่อ่านได้ ค่าที่ไม่ได้ระบุเป็น None
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct LunarText {
+    pub weekday: Option<usize>,
+    pub waxing: bool,
+    pub day: u32,
+    pub month: u32,
+    pub second_eighth: bool,
+    pub zodiac_year: Option<usize>,
+    pub year_be: Option<u32>,
+}
+
+// อ่านข้อความเช่น "วันอังคาร ขึ้น ๕ ค่ำ เดือน ๑๑ ปีเถาะ" หรือ "แรม 3 ค่ำ เดือน 8 หลัง พ.ศ. 2566"
+pub fn parse_lunar(text: &str) -> Option<LunarText> {
+    let text = to_arabic_digits(text);
+    let tokens: Vec<&str> = text.split_whitespace().collect();
+    let mut result = LunarText::default();
+    let mut has_day = false;
+    let mut has_month = false;
+
+    let mut i = 0;
+    while i < tokens.len() {
+        let token = tokens[i];
+        let next = tokens.get(i + 1).copied();
+        if token == "ขึ้น" || token == "แรม" {
+            result.waxing = token == "ขึ้น";
+            result.day = next?.parse().ok()?;
+            has_day = true;
+            i += 1;
+        } else if token == "เดือน" {
+            match next? {
+                "อ้าย" => result.month = 1,
+                "ยี่" => result.month = 2,
+                "88" => { result.month = 8; result.second_eighth = true; }
+                number => result.month = number.parse().ok()?,
+            }
+            if result.month == 8 && tokens.get(i + 2) == Some(&"หลัง") {
+                result.second_eighth = true;
+                i += 1;
+            }
+            has_month = true;
+            i += 1;
+        } else if token == "พ.ศ." {
+            result.year_be = Some(next?.parse().ok()?);
+            i += 1;
+        } else if let Some(name) = token.strip_prefix("วัน") {
+            let name = if name == "พฤหัส" { "พฤหัสบดี" } else { name };
+            result.weekday = Some(WEEKDAY_NAMES.iter().position(|&w| w == name)?);
+        } else if let Some(name) = token.strip_prefix("ปี") {
+            result.zodiac_year = Some(ZODIAC_YEAR_NAMES.iter().position(|&z| z == name)?);
+        } else if token != "ค่ำ" {
+            return None;
+        }
+        i += 1;
+    }
+
+    if has_day && has_month && (1..=12).contains(&result.month) { Some(result) } else { None }
+}
@@ -1,963 +1,872 @@
-use std::collections::HashMap;
+// บรรทัดคำสั่งของ horathai ตัวคำนวณอยู่ในไลบรารี (lib.rs)
 
-struct Province {
-    name: &'static str,
-    longitude: f64,
-}
+use std::path::PathBuf;
+
+mod cli;
+
+use horathai::{accuracy, batch, ephemeris, ingress, locale, lunar, motion, notation, numerals, planet, profile, rectify, server, songkran, svg, table, terminal, thaidate, transit, yam};
+use horathai::{calculate_jd, calculate_thai_ascendant, check_year, find_province, jd_to_date, now_jd, Chart, Province};
+
+use locale::{Lang, Message};
+use motion::MotionThresholds;
+use notation::Notation;
+use planet::Planet;
+use table::{Align, Table};
 
 fn main() {
-    let mut provinces = HashMap::new();
-    provinces.insert("bangkok", Province { name: "กรุงเทพฯ", longitude: 100.50 });
-    provinces.insert("chiangmai", Province { name: "เชียงใหม่", longitude: 98.98 });
-    provinces.insert("ubon", Province { name: "อุบลราชธานี", longitude: 104.85 });
-    provinces.insert("kalasin", Province { name: "กาฬสินธุ์", longitude: 103.53 });
+    let args = cli::Args::parse(std::env::args().skip(1));
+    // ภาษาของผลลัพธ์ --lang th|en|rtgs (ไม่ระบุใช้ภาษาไทย)
+    let lang = match args.get("lang") {
+        None => Lang::Thai,
+        Some(key) => match Lang::from_key(key) {
+            Some(lang) => lang,
+            None => return eprintln!("ไม่รู้จักภาษา / Unknown language: {} (th, en, rtgs)", key),
+        },
+    };
+    match args.command.as_deref() {
+        None | Some("chart") => run_chart(&args, lang),
+        Some("ingress") => run_ingress(&args, lang),
+        Some("transit") => run_transit(&args, lang),
+        Some("calendar") => run_calendar(&args, lang),
+        Some("songkran") => run_songkran(&args, lang),
+        Some("yam") => run_yam(&args, lang),
+        Some("svg") => run_svg(&args, lang),
+        Some("manual") => run_manual(&args, lang),
+        Some("batch") => run_batch(&args, lang),
+        Some("ephemeris") => run_ephemeris(&args, lang),
+        Some("profile") => run_profile(&args, lang),
+        Some("serve") => run_serve(&args, lang),
+        Some("accuracy") => run_accuracy(&args, lang),
+        Some("rectify") => run_rectify(&args, lang),
+        Some(other) => eprintln!("{}", lang.format(Message::UnknownCommand, &[&other])),
+    }
+}
+
+// horathai chart --date 3/10/2530 --time 15:30 --province bangkok [--draw] [--notation rasi|dms|decimal]
+//     [--slow-ratio 0.5 --fast-ratio 1.5]
+fn run_chart(args: &cli::Args, lang: Lang) {
+    let province_key = args.get("province").unwrap_or("bangkok");
+
+    let Some((day, month, year_be, hour, minute)) = birth_input(args, lang) else { return };
+    // เกณฑ์พักร์/มนฑ์/เสริด (ปรับได้ตามตำราที่ใช้)
+    let Some(thresholds) = thresholds_input(args, lang) else { return };
+    let Some(notation) = notation_input(args, lang) else { return };
+    let prov = match find_province(province_key) {
+        Ok(prov) => prov,
+        Err(err) => return eprintln!("{}", err.message(lang)),
+    };
+
+    let chart = match calculate_thai_ascendant(day, month, year_be, hour, minute, &prov, &thresholds) {
+        Ok(chart) => chart,
+        Err(err) => return eprintln!("{}", err.message(lang)),
+    };
+    show_chart(&chart, &prov, [day, month, year_be, hour, minute], None, notation, args.has("draw"), lang);
+}
+
+// แสดงดวงเป็นรายการ หรือวาดเป็นผัง ๑๒ ช่องเมื่อ draw (ชื่อเจ้าชะตาใส่ไว้บรรทัดแรกถ้ามี)
+fn show_chart(chart: &Chart, prov: &Province, birth: [u32; 5], name: Option<&str>, notation: Notation, draw: bool, lang: Lang) {
+    let [day, month, year_be, hour, minute] = birth;
+    if draw {
+        let clock = format!("{:02}:{:02}", hour, minute);
+        let mut title: Vec<String> = name.map(str::to_string).into_iter().collect();
+        title.extend([
+            format!("{}/{}/{} {}", day, month, year_be, lang.format(Message::ClockTime, &[&clock])),
+            lang.province(prov),
+            format!("{}: {}", lang.lagna(), lang.sign_label(chart.lagna_sign())),
+            lang.weekday_label(chart.weekday.thai),
+        ]);
+        print!("{}", terminal::draw_chart(chart, &title, lang));
+    } else {
+        if let Some(name) = name {
+            println!("{} ({}/{}/{} {:02}:{:02})", name, day, month, year_be, hour, minute);
+        }
+        print_chart(chart, prov, notation, lang);
+    }
+}
+
+// horathai svg --date 3/10/2530 --time 15:30 --layout wheel|square --output chart.svg
+fn run_svg(args: &cli::Args, lang: Lang) {
+    let province_key = args.get("province").unwrap_or("bangkok");
+
+    let Some((day, month, year_be, hour, minute)) = birth_input(args, lang) else { return };
+    let Some(thresholds) = thresholds_input(args, lang) else { return };
+    let prov = match find_province(province_key) {
+        Ok(prov) => prov,
+        Err(err) => return eprintln!("{}", err.message(lang)),
+    };
+
+    let chart = match calculate_thai_ascendant(day, month, year_be, hour, minute, &prov, &thresholds) {
+        Ok(chart) => chart,
+        Err(err) => return eprintln!("{}", err.message(lang)),
+    };
+    let clock = format!("{:02}:{:02}", hour, minute);
+    let title = format!("{}/{}/{} {} {}", day, month, year_be, lang.format(Message::ClockTime, &[&clock]), lang.province(&prov));
+    let svg = match args.get("layout").unwrap_or("wheel") {
+        "wheel" => svg::render_wheel(&chart, &title, lang),
+        "square" => svg::render_square(&chart, &title, lang),
+        other => return eprintln!("{}", lang.format(Message::UnknownLayout, &[&other])),
+    };
+
+    match args.get("output") {
+        Some(path) => {
+            if let Err(err) = std::fs::write(path, svg) {
+                eprintln!("{}", lang.format(Message::WriteFailed, &[&path, &err]));
+            }
+        }
+        None => print!("{}", svg),
+    }
+}
+
+// อ่านวันเวลาเกิดจาก --birth หรือ --date และ --time (ไม่ระบุใช้ข้อมูลตัวอย่าง)
+// รับแบบที่เขียนกันจริง เช่น --birth "3 ต.ค. 2530 เวลา 15:30 น." หรือ --date "๓ ตุลาคม พ.ศ. ๒๕๓๐"
+fn birth_input(args: &cli::Args, lang: Lang) -> Option<(u32, u32, u32, u32, u32)> {
+    if let Some(text) = args.get("birth") {
+        return thaidate::parse_date_time(text).map_err(|err| eprintln!("{}", err.message(lang))).ok();
+    }
+
     // ข้อมูลเกิด: 3 ต.ค. 2530 เวลา 15:30 น.
-    let day = 22;
-    let month = 4;
-    let year_be = 2524;
-    let hour = 16;
-    let minute = 0;
-    let province_key = "bangkok";
+    let mut day = 22;
+    let mut month = 4;
+    let mut year_be = 2524;
+    let mut hour = 16;
+    let mut minute = 0;
+
+    if let Some(text) = args.get("date") {
+        match thaidate::parse_date(text) {
+            Ok((d, m, y)) => (day, month, year_be) = (d, m, y),
+            Err(err) => { eprintln!("{}", err.message(lang)); return None; }
+        }
+    }
+    if let Some(text) = args.get("time") {
+        match thaidate::parse_time(text) {
+            Ok((h, min)) => (hour, minute) = (h, min),
+            Err(err) => { eprintln!("{}", err.message(lang)); return None; }
+        }
+    }
+    Some((day, month, year_be, hour, minute))
+}
 
-    if let Some(prov) = provinces.get(province_key) {
-        calculate_thai_ascendant(day, month, year_be, hour, minute, prov);
+// รูปแบบตัวเลขตำแหน่งดาวจาก --notation (ไม่ระบุใช้องศาทศนิยม)
+fn notation_input(args: &cli::Args, lang: Lang) -> Option<Notation> {
+    match args.get("notation") {
+        None => Some(Notation::Decimal),
+        Some(key) => Notation::from_key(key).or_else(|| {
+            eprintln!("{}", lang.format(Message::UnknownNotation, &[&key]));
+            None
+        }),
     }
 }
 
-fn calculate_thai_ascendant(d: u32, m: u32, y: u32, h: u32, min: u32, prov: &Province) {
-    // --- คำนวณ Julian Day (ฐานสำหรับคำนวณดาว) ---
-    let jd = calculate_jd(d, m, y, h, min);
-
-
-    // --- หาองศาอาทิตย์ (ต้องได้ประมาณ 15-16 องศาราศีกันย์)
-    let solar_long = estimate_solar_thai(d, m, y);
-    let solar_zodiac_idx = (solar_long / 30.0) as usize;
-    let solar_degree = solar_long % 30.0;
-
-    // --- หาองศาจันทร์ (๒) ---
-    let moon_long = calculate_moon_position(jd);
-    let moon_zodiac_idx = (moon_long / 30.0) as usize % 12;
-    let moon_degree = moon_long % 30.0;
-
-    // -- หาตำแหน่งดาวอังคาร (๓)
-    let mars_long = calculate_mars_position(jd);
-    let mars_zodiac_idx = (mars_long / 30.0) as usize % 12;
-    let mars_degree = mars_long % 30.0;
-
-    // -- หาตำแหน่งดาวพุธ (๔) - ต้องส่งค่า solar_long เข้าไปด้วย
-    let mercury_long = calculate_mercury_position(jd, solar_long);
-    let mercury_zodiac_idx = (mercury_long / 30.0) as usize % 12;
-    let mercury_degree = mercury_long % 30.0;
-
-    // ดาวพฤหัสบดี (๕)
-    let jupiter_long = calculate_jupiter_position(jd);
-    // ดาวศุกร์ (๖) - ใช้ solar_long เป็นฐานเหมือนดาวพุธ
-    let venus_long = calculate_venus_position(jd, solar_long);
-    // ดาวเสาร์ (๗)
-    let saturn_long = calculate_saturn_position(jd);
-    // ราหู (๘)
-    let rahu_long = calculate_rahu_position(jd);
-    // เกตุไทย (๙)
-    let ketu_long = calculate_ketu_thai_position(jd);
-
-    let uranus_long = calculate_uranus_position(jd);
-
-    // วนลูปแสดงผล (ตัวอย่าง)
-    let planets = [
-        ("พฤหัสบดี (๕)", jupiter_long),
-        ("ศุกร์ (๖)", venus_long),
-        ("เสาร์ (๗)", saturn_long),
-        ("ราหู (๘)", rahu_long),
-        ("เกตุ (๙)", ketu_long),
-        ("มฤตยู (๐)", uranus_long),
-    ];
-
-    //--- คำนวณเวลาท้องถิ่นจริง (LMT)
-    // เวลาไทยอิง 105E | กรุงเทพ 100.5E | ต่างกัน 4.5 องศา | 1 องศา = 4 นาที
-    // 4.5 * 4 = 18 นาที (ต้อง "ลบ" ออกจากเวลาหน้าปัดนาฬิกา)
-    let lmt_offset = (105.0 - prov.longitude) * 4.0;
-    let total_birth_min = (h as f64 * 60.0) + min as f64 - lmt_offset;
-
-    // 3. อันตรกาล (เวลาหลังอาทิตย์อุทัย 06:00 น.)
-    let mut antar_kala = total_birth_min - 360.0;
-    if antar_kala < 0.0 { antar_kala += 1440.0; }
-
-    // 4. ค่าอันโตนาที (มาตรฐานสุริยยาตร์)
-    let anto_nati = [120.0, 96.0, 72.0, 120.0, 144.0, 168.0, 168.0, 144.0, 120.0, 72.0, 96.0, 120.0];
-    let zodiac_names = ["เมษ", "พฤษภ", "มิถุน", "กรกฎ", "สิงห์", "กันย์", "ตุลย์", "พิจิก", "ธนู", "มกร", "กุมภ์", "มีน"];
-
-    // 5. ชำระกาลราศีแรก (ราศีที่อาทิตย์สถิตอยู่ - กันย์)
-    let degree_left = 30.0 - solar_degree;
-    let time_left_in_zodiac = (degree_left / 30.0) * anto_nati[solar_zodiac_idx];
-
-    let mut current_idx = solar_zodiac_idx;
-    let mut final_degree = 0.0;
-    let mut temp_antar = antar_kala;
-
-    // 6. การวางลัคนา
-    if temp_antar <= time_left_in_zodiac {
-        final_degree = solar_degree + (temp_antar / anto_nati[solar_zodiac_idx] * 30.0);
-    } else {
-        temp_antar -= time_left_in_zodiac;
-        loop {
-            current_idx = (current_idx + 1) % 12;
-            let zodiac_time = anto_nati[current_idx];
-            if temp_antar <= zodiac_time {
-                final_degree = (temp_antar / zodiac_time) * 30.0;
-                break;
+// เกณฑ์มนฑ์/เสริดจาก --slow-ratio และ --fast-ratio (ไม่ระบุใช้ค่าตั้งต้น 0.5 และ 1.5 เท่าของความเร็วเฉลี่ย)
+fn thresholds_input(args: &cli::Args, lang: Lang) -> Option<MotionThresholds> {
+    MotionThresholds::default().with_options(|name| args.get(name), lang).map_err(|message| eprintln!("{}", message)).ok()
+}
+
+// ป้อนตำแหน่งจากปฏิทินโหรเองเพื่อดูภพ
+// horathai manual --lagna 5.11.08 --sun 0.08.05 --moon 7.13.09 ... [--notation dms]
+fn run_manual(args: &cli::Args, lang: Lang) {
+    let notation = match args.get("notation") {
+        None => Notation::Rasi,
+        Some(_) => match notation_input(args, lang) {
+            Some(notation) => notation,
+            None => return,
+        },
+    };
+    let Some(text) = args.get("lagna") else {
+        return eprintln!("{}", lang.text(Message::LagnaRequired));
+    };
+    let Some(lagna) = notation::parse_position(text) else {
+        return eprintln!("{}", lang.format(Message::InvalidPosition, &[&lang.lagna(), &text]));
+    };
+    let lagna_sign = (lagna / 30.0) as usize % 12;
+
+    let mut table = Table::new(&[Align::Left, Align::Left, Align::Left, Align::Right]);
+    let mut add = |name: String, longitude: f64| {
+        let sign = (longitude / 30.0) as usize % 12;
+        table.row([
+            format!("{}:", name),
+            lang.sign_label(sign),
+            notation.label(lang).to_string(),
+            notation.format(longitude),
+            lang.house_label((sign + 12 - lagna_sign) % 12),
+        ]);
+    };
+    add(lang.lagna_label(), lagna);
+    for planet in Planet::ALL {
+        let Some(text) = args.get(planet.key()) else { continue };
+        match notation::parse_position(text) {
+            Some(longitude) => add(lang.planet_label(planet), longitude),
+            None => return eprintln!("{}", lang.format(Message::InvalidPosition, &[&lang.planet(planet), &text])),
+        }
+    }
+    print!("{}", table.render());
+}
+
+// horathai batch --input births.csv --output charts.csv [--notation rasi] [--slow-ratio 0.5 --fast-ratio 1.5]
+// ไม่ระบุ --input อ่านจาก stdin ไม่ระบุ --output เขียนออก stdout
+fn run_batch(args: &cli::Args, lang: Lang) {
+    let Some(notation) = notation_input(args, lang) else { return };
+    let Some(thresholds) = thresholds_input(args, lang) else { return };
+    let input = match args.get("input") {
+        Some(path) => std::fs::read_to_string(path).map_err(|err| (path, err)),
+        None => std::io::read_to_string(std::io::stdin()).map_err(|err| ("stdin", err)),
+    };
+    let input = match input {
+        Ok(input) => input,
+        Err((path, err)) => return eprintln!("{}", lang.format(Message::ReadFailed, &[&path, &err])),
+    };
+
+    let result = batch::run(&input, lang, notation, &thresholds);
+    for error in &result.errors {
+        eprintln!("{}", error);
+    }
+    eprintln!("{}", lang.format(Message::BatchSummary, &[&result.rows, &result.errors.len()]));
+
+    match args.get("output") {
+        Some(path) => {
+            if let Err(err) = std::fs::write(path, result.csv) {
+                eprintln!("{}", lang.format(Message::WriteFailed, &[&path, &err]));
             }
-            temp_antar -= zodiac_time;
         }
+        None => print!("{}", result.csv),
     }
+}
 
-    // --- แสดงผลลัพธ์ ---
-    println!("--- ผลคำนวณดวงชะตาสุริยยาตร์ ---");
-    println!("จังหวัด: {} | เวลาท้องถิ่น (LMT): {:02}:{:02} น.", prov.name, (total_birth_min/60.0) as u32, (total_birth_min%60.0) as u32);
-    println!("--------------------------------");
-    println!("ลัคนา (ล):  ราศี{: <5} องศา {:.2}", zodiac_names[current_idx], final_degree);
-    println!("อาทิตย์ (๑): ราศี{: <5} องศา {:.2}", zodiac_names[solar_zodiac_idx], solar_degree);
-    println!("จันทร์ (๒):  ราศี{: <5} องศา {:.2}", zodiac_names[moon_zodiac_idx], moon_degree);
-    println!("อังคาร (๓): ราศี{: <5} องศา {:.2}", zodiac_names[mars_zodiac_idx], mars_degree);
-    println!("พุธ (๔):    ราศี{: <5} องศา {:.2}", zodiac_names[mercury_zodiac_idx], mercury_degree);
-    for (name, pos) in planets {
-        let z_idx = (pos / 30.0) as usize % 12;
-        let deg = pos % 30.0;
-        println!("{: <12}: ราศี{: <5} องศา {:.2}", name, zodiac_names[z_idx], deg);
+// แฟ้มลูกดวง (ไม่ระบุ --store ใช้ ~/.horathai/profiles.json)
+// horathai profile add --name "สมชาย ใจดี" --birth "3 ต.ค. 2530 เวลา 15:30 น." --province bangkok [--notation rasi] [--note ...]
+// horathai profile list | search สมชาย | show --id 1 [--draw] | edit --id 1 --time 15:45 | remove --id 1
+// add/edit รับ --latitude --longitude แทนจังหวัด และ --slow-ratio --fast-ratio สำหรับเกณฑ์มนฑ์/เสริด
+fn run_profile(args: &cli::Args, lang: Lang) {
+    let path = args.get("store").map_or_else(profile::default_path, PathBuf::from);
+    let mut store = match profile::Store::load(&path) {
+        Ok(store) => store,
+        Err(err) => return eprintln!("{}", err.message(lang, &path)),
+    };
+    // เลขที่โปรไฟล์จาก --id หรือคำถัดจากคำสั่งย่อย เช่น profile show 3
+    let profile_id = |store: &profile::Store| -> Option<u32> {
+        let Some(text) = args.get("id").or(args.argument(1)) else {
+            eprintln!("{}", lang.text(Message::ProfileIdRequired));
+            return None;
+        };
+        let id = text.parse().ok().filter(|&id| store.get(id).is_some());
+        if id.is_none() {
+            eprintln!("{}", lang.format(Message::ProfileNotFound, &[&text]));
+        }
+        id
+    };
+    let save = |store: &profile::Store| -> bool {
+        let result = store.save(&path);
+        if let Err(err) = &result {
+            eprintln!("{}", lang.format(Message::WriteFailed, &[&path.display(), err]));
+        }
+        result.is_ok()
+    };
+
+    match args.argument(0) {
+        Some("add") => {
+            let Some(name) = args.get("name") else { return eprintln!("{}", lang.text(Message::NameRequired)) };
+            if args.get("birth").is_none() && args.get("date").is_none() {
+                return eprintln!("{}", lang.text(Message::DateRequired));
+            }
+            let mut new = profile::Profile {
+                id: 0,
+                name: name.to_string(),
+                date: (0, 0, 0),
+                time: (6, 0), // ไม่ทราบเวลาเกิดใช้ 06:00 น. เหมือนโหมด batch
+                place: profile::Place::Province("bangkok".to_string()),
+                notation: Notation::Decimal,
+                thresholds: MotionThresholds::default(),
+                note: String::new(),
+            };
+            if apply_profile_options(args, &mut new, lang).is_none() { return; }
+            let id = store.add(new);
+            if save(&store) {
+                println!("{}", lang.format(Message::ProfileSaved, &[&id, &name]));
+            }
+        }
+        Some("edit") => {
+            let Some(id) = profile_id(&store) else { return };
+            let Some(edited) = store.get_mut(id) else { return };
+            if apply_profile_options(args, edited, lang).is_none() { return; }
+            let name = edited.name.clone();
+            if save(&store) {
+                println!("{}", lang.format(Message::ProfileSaved, &[&id, &name]));
+            }
+        }
+        Some("remove") => {
+            let Some(id) = profile_id(&store) else { return };
+            let Some(removed) = store.remove(id) else { return };
+            if save(&store) {
+                println!("{}", lang.format(Message::ProfileRemoved, &[&id, &removed.name]));
+            }
+        }
+        Some("list") => print_profiles(store.profiles.iter(), lang),
+        Some("search") => match args.get("query").or(args.argument(1)) {
+            Some(query) => print_profiles(store.search(query), lang),
+            None => eprintln!("{}", lang.text(Message::QueryRequired)),
+        },
+        // คำนวณดวงใหม่จากข้อมูลที่บันทึกไว้ --notation ใช้แทนค่าที่บันทึกได้ชั่วคราว
+        Some("show") => {
+            let Some(id) = profile_id(&store) else { return };
+            let Some(saved) = store.get(id) else { return };
+            let prov = match saved.place.resolve() {
+                Ok(prov) => prov,
+                Err(err) => return eprintln!("{}", err.message(lang)),
+            };
+            let notation = match args.get("notation") {
+                None => saved.notation,
+                Some(_) => match notation_input(args, lang) { Some(notation) => notation, None => return },
+            };
+            let ((d, m, y), (h, min)) = (saved.date, saved.time);
+            let chart = match calculate_thai_ascendant(d, m, y, h, min, &prov, &saved.thresholds) {
+                Ok(chart) => chart,
+                Err(err) => return eprintln!("{}", err.message(lang)),
+            };
+            show_chart(&chart, &prov, [d, m, y, h, min], Some(&saved.name), notation, args.has("draw"), lang);
+        }
+        other => eprintln!("{}", lang.format(Message::UnknownAction, &[
+            &other.unwrap_or(""),
+            &"add, list, search, show, edit, remove",
+        ])),
     }
-    println!("--------------------------------");
 }
 
-// ฟังก์ชันประมาณตำแหน่งอาทิตย์ตามคัมภีร์สุริยยาตร์
-fn estimate_solar_thai(d: u32, m: u32, y_be: u32) -> f64 {
-    let y_ad = y_be - 543;
-    let is_leap = (y_ad % 4 == 0 && y_ad % 100 != 0) || (y_ad % 400 == 0);
-    let month_days = if is_leap {
-        [0, 31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31]
-    } else {
-        [0, 31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31]
+// ใส่ค่าที่ระบุในบรรทัดคำสั่งลงในโปรไฟล์ ค่าที่ไม่ระบุคงเดิม
+fn apply_profile_options(args: &cli::Args, profile: &mut profile::Profile, lang: Lang) -> Option<()> {
+    let report = |message: String| -> Option<()> {
+        eprintln!("{}", message);
+        None
     };
+    if let Some(name) = args.get("name") {
+        profile.name = name.to_string();
+    }
+    if let Some(text) = args.get("birth") {
+        let (d, m, y, h, min) = thaidate::parse_date_time(text).map_err(|err| report(err.message(lang))).ok()?;
+        (profile.date, profile.time) = ((d, m, y), (h, min));
+    }
+    if let Some(text) = args.get("date") {
+        profile.date = thaidate::parse_date(text).map_err(|err| report(err.message(lang))).ok()?;
+    }
+    if let Some(text) = args.get("time") {
+        profile.time = thaidate::parse_time(text).map_err(|err| report(err.message(lang))).ok()?;
+    }
+    if let Some(key) = args.get("province") {
+        if let Err(err) = find_province(key) {
+            return report(err.message(lang));
+        }
+        profile.place = profile::Place::Province(key.to_string());
+    }
+    if args.get("latitude").is_some() || args.get("longitude").is_some() {
+        let (latitude, longitude) = (args.get("latitude").unwrap_or(""), args.get("longitude").unwrap_or(""));
+        let coordinate = |text: &str, limit: f64| text.parse::<f64>().ok().filter(|v| v.abs() <= limit);
+        match (coordinate(latitude, 90.0), coordinate(longitude, 180.0)) {
+            (Some(latitude), Some(longitude)) => profile.place = profile::Place::Coordinates { latitude, longitude },
+            _ => return report(lang.format(Message::InvalidCoordinates, &[&format!("{}, {}", latitude, longitude)])),
+        }
+    }
+    if args.get("notation").is_some() {
+        profile.notation = notation_input(args, lang)?;
+    }
+    profile.thresholds = profile.thresholds.with_options(|name| args.get(name), lang).map_err(report).ok()?;
+    if let Some(note) = args.get("note") {
+        profile.note = note.to_string();
+    }
+    Some(())
+}
 
-    let mut day_of_year = d;
-    for i in 1..m as usize {
-        day_of_year += month_days[i];
+fn print_profiles<'a>(profiles: impl Iterator<Item = &'a profile::Profile>, lang: Lang) {
+    let mut table = Table::new(&[Align::Right, Align::Left, Align::Left, Align::Left, Align::Left, Align::Left]);
+    let mut count = 0;
+    for saved in profiles {
+        count += 1;
+        let place = saved.place.resolve().map_or_else(|_| saved.place.describe(), |prov| lang.province(&prov));
+        table.row([saved.id.to_string(), saved.name.clone(), saved.date_text(), saved.time_text(), place, saved.note.clone()]);
+    }
+    if count == 0 {
+        return println!("{}", lang.text(Message::NoProfiles));
     }
+    print!("{}", table.render());
+}
+
+// horathai transit --date 3/10/2530 --time 15:30 --transit-date 18/10/2569 --transit-time 12:00
+fn run_transit(args: &cli::Args, lang: Lang) {
+    let province_key = args.get("province").unwrap_or("bangkok");
 
-    // วันสงกรานต์ (อาทิตย์ 0 องศาเมษ) โดยปกติคือ 13 เม.ย.
-    let apr13 = if is_leap { 104 } else { 103 };
-    let mut diff = day_of_year as i32 - apr13;
-    if diff < 0 { diff += if is_leap { 366 } else { 365 }; }
+    let Some((day, month, year_be, hour, minute)) = birth_input(args, lang) else { return };
+    let Some(thresholds) = thresholds_input(args, lang) else { return };
+    let Some(notation) = notation_input(args, lang) else { return };
 
-    // สุริยยาตร์เฉลี่ย อาทิตย์เดินวันละ 0.9856 องศา (โดยประมาณ)
-    (diff as f64) * 0.9856262833675565 
+    // ไม่ระบุวันจร ใช้เวลาปัจจุบัน
+    let (mut t_day, mut t_month, mut t_year, mut t_hour, mut t_minute) = jd_to_date(now_jd());
+    if let Some(text) = args.get("transit-date") {
+        match thaidate::parse_date(text) {
+            Ok((d, m, y)) => (t_day, t_month, t_year) = (d, m, y),
+            Err(err) => return eprintln!("{}", err.message(lang)),
+        }
+    }
+    if let Some(text) = args.get("transit-time") {
+        match thaidate::parse_time(text) {
+            Ok((h, min)) => (t_hour, t_minute) = (h, min),
+            Err(err) => return eprintln!("{}", err.message(lang)),
+        }
+    }
+
+    let prov = match find_province(province_key) {
+        Ok(prov) => prov,
+        Err(err) => return eprintln!("{}", err.message(lang)),
+    };
+    let charts = calculate_thai_ascendant(day, month, year_be, hour, minute, &prov, &thresholds)
+        .and_then(|natal| Ok((natal, calculate_thai_ascendant(t_day, t_month, t_year, t_hour, t_minute, &prov, &thresholds)?)));
+    let (natal, transit) = match charts {
+        Ok(charts) => charts,
+        Err(err) => return eprintln!("{}", err.message(lang)),
+    };
+    print_chart(&natal, &prov, notation, lang);
+    print_transit(&natal, &transit, notation, lang);
 }
-fn calculate_jd(d: u32, m: u32, y_be: u32, h: u32, min: u32) -> f64 {
-    let mut year = (y_be - 543) as i32;
-    let mut month = m as i32;
-    if month <= 2 {
-        year -= 1;
-        month += 12;
-    }
-    let a = year / 100;
-    let b = 2 - a + (a / 4);
-    let jd = (365.25 * (year + 4716) as f64).floor() + 
-             (30.6001 * (month + 1) as f64).floor() + 
-             d as f64 + (h as f64 / 24.0) + (min as f64 / 1440.0) + 
-             b as f64 - 1524.5;
-    jd
+
+fn print_transit(natal: &Chart, transit: &Chart, notation: Notation, lang: Lang) {
+    let (d, m, y, h, min) = jd_to_date(transit.jd);
+    let date = format!("{}/{}/{}", d, m, y);
+    let clock = lang.format(Message::ClockTime, &[&format!("{:02}:{:02}", h, min)]);
+    println!("{}", lang.format(Message::TransitHeader, &[&date, &clock]));
+    println!("{}", lang.format(Message::NatalLagna, &[&lang.sign_label(natal.lagna_sign())]));
+    println!("--------------------------------");
+    let mut table = Table::new(&[Align::Left, Align::Left, Align::Left, Align::Right]);
+    for placement in transit::overlay(natal, transit) {
+        table.row([
+            lang.format(Message::TransitLabel, &[&lang.planet(placement.planet), &lang.numeral(placement.planet)]),
+            lang.sign_label((placement.longitude / 30.0) as usize % 12),
+            notation.label(lang).to_string(),
+            notation.format(placement.longitude),
+            lang.house_label(placement.house),
+            lang.motion_flag(placement.motion).to_string(),
+        ]);
+        let aspects: Vec<String> = placement.aspects.iter()
+            .map(|a| lang.format(Message::TransitAspect, &[
+                &lang.planet(placement.planet),
+                &lang.relation(a.relation),
+                &lang.natal_point(a.natal),
+            ]))
+            .collect();
+        if !aspects.is_empty() {
+            table.line(format!("    {}", aspects.join(", ")));
+        }
+    }
+    print!("{}", table.render());
+    println!("--------------------------------");
 }
 
-fn calculate_moon_position(jd: f64) -> f64 {
-   // จุดนับหรคุณ 1 ม.ค. ค.ศ. 1800 (JD 2378496.5)
-    let epoch_1800_jd =  2378600.45833;
-    let horakhun = jd - epoch_1800_jd; 
-
-    // 1. มัธยมจันทร์ (Mean Moon) 
-    // ปรับค่าตำแหน่งเริ่มต้นเป็น 158.5 เพื่อให้สอดคล้องกับตำแหน่งในราศีมังกรสำหรับปี 2530
-    let mut mean_moon = (238.15 + (13.1763906 * horakhun)) % 360.0;
-    if mean_moon < 0.0 { mean_moon += 360.0; }
-
-    // 2. มัธยมอุจจันทร์ (Mean Apogee)
-    let mut mean_apogee = (172.42 + (0.1114035 * horakhun)) % 360.0;
-    if mean_apogee < 0.0 { mean_apogee += 360.0; }
-
-    // 3. วิกษิปภาค (Anomaly)
-    let mut anomaly = mean_moon - mean_apogee;
-    if anomaly < 0.0 { anomaly += 360.0; }
-
-    // 4. สมการจุดศูนย์กลาง (Equation of Center)
-    // ใช้ค่า 6.289 ซึ่งเป็นค่าสูงสุดในบางตำราสุริยยาตร์เพื่อดึงตำแหน่งจันทร์ให้แม่นยำขึ้น
-    let correction = 6.289 * (anomaly.to_radians().sin());
-
-    // 5. สมผุสจันทร์ (True Moon)
-    let mut true_moon = mean_moon - correction;
-    if true_moon < 0.0 { true_moon += 360.0; }
-    
-    true_moon % 360.0
+// horathai ingress --from 1/1/2568 --days 365 --planets saturn,jupiter [--backward]
+// --days เป็นจำนวนเต็มไม่เกิน ingress::MAX_DAYS (๑๐๐ ปี)
+fn run_ingress(args: &cli::Args, lang: Lang) {
+    let start_jd = match args.get("from") {
+        Some(text) => match thaidate::parse_date(text).and_then(|(d, m, y)| calculate_jd(d, m, y, 0, 0)) {
+            Ok(jd) => jd,
+            Err(err) => return eprintln!("{}", err.message(lang)),
+        },
+        None => now_jd(),
+    };
+    let days = match args.get("days").map(|text| text.trim().parse::<u32>()) {
+        Some(Ok(days)) if days <= ingress::MAX_DAYS => days as f64,
+        Some(_) => return eprintln!("{}", lang.text(Message::InvalidDays)),
+        None => 365.0,
+    };
+    let days = if args.has("backward") { -days } else { days };
+
+    // ไม่ระบุดาว = ทุกดวงยกเว้นจันทร์ (จันทร์ย้ายราศีทุก 2-3 วัน)
+    let planets: Vec<Planet> = match args.get("planets") {
+        Some(list) => {
+            let mut planets = Vec::new();
+            for key in list.split(',') {
+                match Planet::from_key(key.trim()) {
+                    Some(p) => planets.push(p),
+                    None => return eprintln!("{}", lang.format(Message::UnknownPlanet, &[&key])),
+                }
+            }
+            planets
+        }
+        None => Planet::ALL.iter().copied().filter(|p| *p != Planet::Moon).collect(),
+    };
+
+    println!("{}", lang.text(Message::IngressHeader));
+    let mut table = Table::new(&[Align::Left, Align::Left, Align::Left, Align::Left, Align::Right]);
+    for event in ingress::find_ingresses(&planets, start_jd, days) {
+        let (d, m, y, h, min) = jd_to_date(event.jd);
+        table.row([
+            format!("{}:", lang.planet_label(event.planet)),
+            lang.sign_label(event.from_sign),
+            "->".to_string(),
+            lang.sign_label(event.to_sign),
+            format!("{}/{}/{}", d, m, y),
+            lang.format(Message::AtTime, &[&lang.format(Message::ClockTime, &[&format!("{:02}:{:02}", h, min)])]),
+            if event.is_backward() { lang.text(Message::Backward) } else { "" }.to_string(),
+        ]);
+    }
+    print!("{}", table.render());
 }
+// horathai ephemeris --month 10/2569 [--time 06:00] [--format text|csv|json] [--notation decimal]
+// horathai ephemeris --year 2569 หรือ --from 1/10/2569 --days 30
+// ทุกคำสั่งที่คำนวณดวงรับ --slow-ratio และ --fast-ratio เป็นเกณฑ์มนฑ์/เสริดเช่นเดียวกับ chart
+fn run_ephemeris(args: &cli::Args, lang: Lang) {
+    let ((d, m, y), days) = match ephemeris::date_range(|name| args.get(name), lang) {
+        Ok(range) => range,
+        Err(message) => return eprintln!("{}", message),
+    };
+    let (hour, minute) = match args.get("time").map(thaidate::parse_time) {
+        Some(Ok(time)) => time,
+        Some(Err(err)) => return eprintln!("{}", err.message(lang)),
+        None => (6, 0),
+    };
+    // ปฏิทินโหรใช้ ราศี.องศา.ลิปดา เป็นหลัก
+    let notation = match args.get("notation") {
+        None => Notation::Rasi,
+        Some(_) => match notation_input(args, lang) {
+            Some(notation) => notation,
+            None => return,
+        },
+    };
+
+    let Some(thresholds) = thresholds_input(args, lang) else { return };
+    let table = ephemeris::ephemeris((d, m, y), days, hour, minute, &thresholds);
+    let output = match args.get("format").unwrap_or("text") {
+        "text" => {
+            let (last_d, last_m, last_y) = table.last().map_or((d, m, y), |day| day.date);
+            let clock = lang.format(Message::ClockTime, &[&format!("{:02}:{:02}", hour, minute)]);
+            let header = lang.format(Message::EphemerisHeader, &[
+                &format!("{}/{}/{}", d, m, y),
+                &format!("{}/{}/{}", last_d, last_m, last_y),
+                &clock,
+            ]);
+            format!("{}\n{}", header, ephemeris::to_text(&table, lang, notation))
+        }
+        "csv" => ephemeris::to_csv(&table, lang, notation),
+        "json" => format!("{}\n", ephemeris::to_json(&table, lang, notation)),
+        other => return eprintln!("{}", lang.format(Message::UnknownFormat, &[&other])),
+    };
 
-// ฟังก์ชันคำนวณดาวอังคาร (๓)
-fn calculate_mars_position(jd: f64) -> f64 {
-    let epoch_1800_jd = 2378598.458333;
-    let horakhun = (jd - epoch_1800_jd)/ 36525.0;
-    // 1. คำนวณวงโคจรโลก (Earth/Sun elements)
-    let e_l = (100.46435 + 35999.37249 * horakhun) % 360.0; // Mean Longitude
-    let e_g = (102.93768 + 0.32327 * horakhun) % 360.0;    // Perihelion
-    let e_m = (e_l - e_g).to_radians();             // Mean Anomaly
-    let e_v = e_l + 1.9148 * e_m.sin() + 0.0200 * (2.0 * e_m).sin(); // True Longitude
-    let e_r = 1.00014 * (1.0 - 0.01671 * e_m.cos()); // Distance from Sun (AU)
-
-    // 2. คำนวณวงโคจรดาวอังคาร (Mars elements)
-    let m_l = (317.18 + 19140.30268 * horakhun) % 360.0; // Mean Longitude
-    let m_g = (335.15 + 0.44301 * horakhun) % 360.0;     // Perihelion
-    let m_m = (m_l - m_g).to_radians();              // Mean Anomaly
-    
-    // Equation of Center สำหรับอังคาร (แม่นยำขึ้น)
-    let m_v = m_l + 10.6912 * m_m.sin() + 0.6228 * (2.0 * m_m).sin(); 
-    let m_v_rad = m_v.to_radians();
-    
-    // ระยะห่างจากดวงอาทิตย์ (Mars Distance - r)
-    // สูตร: r = a(1 - e^2) / (1 + e cos(v)) โดยประมาณ
-    let m_r = 1.52368 * (1.0 - 0.0934 * m_m.cos());
-
-    // 3. แปลงจาก Heliocentric (ดวงอาทิตย์เป็นศูนย์กลาง) เป็น Geocentric (โลกเป็นศูนย์กลาง)
-    // ใช้กฎของ Sine/Cosine ในการหาตำแหน่งที่มองจากโลก
-    let e_v_rad = e_v.to_radians();
-    
-    let x = m_r * m_v_rad.cos() - e_r * e_v_rad.cos();
-    let y = m_r * m_v_rad.sin() - e_r * e_v_rad.sin();
-
-    let mut geocentric_long = y.atan2(x).to_degrees();
-
-    // ปรับค่าให้อยู่ในช่วง 0-360
-    if geocentric_long < 0.0 {
-        geocentric_long += 360.0;
-    }
-    
-    geocentric_long
+    match args.get("output") {
+        Some(path) => {
+            if let Err(err) = std::fs::write(path, output) {
+                eprintln!("{}", lang.format(Message::WriteFailed, &[&path, &err]));
+            }
+        }
+        None => print!("{}", output),
+    }
 }
 
-// ฟังก์ชันคำนวณดาวพุธ (๔) - ปรับปรุงแบบละเอียด
-// อ้างอิง Epoch: 13 เมษายน 1800 เวลา 6:00 น. (GMT+7) = JD 2378493.458333 (UTC 12 เม.ย. 1800 23:00)
-fn calculate_mercury_position(jd: f64, solar_long: f64) -> f64 {
-    // Epoch อ้างอิง: 13 เมษายน 1800 เวลา 06:00 GMT+7
-    // = 12 เมษายน 1800 เวลา 23:00 UTC
-    // JD = 2378493.458333
-    let epoch_1800_jd = 2378493.458333;
-    let t = (jd - epoch_1800_jd) / 36525.0; // จำนวนศตวรรษจูเลียนหลัง epoch
-
-    // ===== 1. คำนวณตำแหน่งโลก (Earth/Sun) - ละเอียดขึ้น =====
-    // Mean Longitude ของโลก (องศา)
-    let mut e_l = 100.46435 + 35999.37249 * t + 0.0003032 * t * t;
-    e_l = e_l % 360.0;
-    if e_l < 0.0 { e_l += 360.0; }
-    
-    // Perihelion ของโลก
-    let mut e_g = 102.93768 + 0.32327 * t + 0.00015 * t * t;
-    e_g = e_g % 360.0;
-    if e_g < 0.0 { e_g += 360.0; }
-    
-    // Mean Anomaly ของโลก
-    let e_m = (e_l - e_g).to_radians();
-    
-    // Equation of Center สำหรับโลก (แม่นยำขึ้น - พจน์ 5 ระดับ)
-    let e_eq_center = 1.914600 * e_m.sin() 
-                    + 0.019993 * (2.0 * e_m).sin()
-                    + 0.000290 * (3.0 * e_m).sin()
-                    + 0.000020 * (4.0 * e_m).sin()
-                    + 0.000002 * (5.0 * e_m).sin();
-    
-    // True Longitude ของโลก
-    let e_v = e_l + e_eq_center;
-    let e_v_rad = e_v.to_radians();
-    
-    // ระยะห่างโลก-ดวงอาทิตย์ (AU) - สูตรแม่นยำ
-    let e_ecc = 0.016708617 - 0.000042037 * t - 0.0000001236 * t * t; // eccentricity ของโลก
-    let e_r = 1.000001018 * (1.0 - e_ecc * e_ecc) / (1.0 + e_ecc * e_m.cos());
-
-    // ===== 2. คำนวณวงโคจรดาวพุธ (Heliocentric) - แม่นยำสูง =====
-    
-    // Mean Longitude ของดาวพุธ ณ Epoch (องศา)
-    // ค่าเริ่มต้น ณ 13 เม.ย. 1800: ประมาณ 315.5 องศา
-    let mut m_l = 315.5484 + 149472.6746 * t + 0.00030397 * t * t;
-    m_l = m_l % 360.0;
-    if m_l < 0.0 { m_l += 360.0; }
-    
-    // Perihelion ของดาวพุธ
-    let mut m_perihelion = 77.4561 + 0.15856 * t + 0.00002953 * t * t;
-    m_perihelion = m_perihelion % 360.0;
-    if m_perihelion < 0.0 { m_perihelion += 360.0; }
-    
-    // Ascending Node ของดาวพุธ
-    let mut m_node = 48.3309 - 0.12534 * t + 0.00008863 * t * t;
-    m_node = m_node % 360.0;
-    if m_node < 0.0 { m_node += 360.0; }
-    
-    // Inclination (ความเอียงของวงโคจร)
-    let m_inc = 7.00487 - 0.00178 * t; // องศา
-    
-    // Mean Anomaly ของดาวพุธ
-    let m_m = (m_l - m_perihelion).to_radians();
-    
-    // Eccentricity ของดาวพุธ (แปรตามเวลา)
-    let m_ecc = 0.20563069 + 0.000020406 * t - 0.0000000284 * t * t;
-    
-    // Equation of Center สำหรับดาวพุธ (พจน์ 8 ระดับ - เนื่องจากวงรีมาก)
-    let m_eq_center = 23.4406 * m_m.sin()
-                    + 2.9818 * (2.0 * m_m).sin()
-                    + 0.5255 * (3.0 * m_m).sin()
-                    + 0.1058 * (4.0 * m_m).sin()
-                    + 0.0241 * (5.0 * m_m).sin()
-                    + 0.0055 * (6.0 * m_m).sin()
-                    + 0.0013 * (7.0 * m_m).sin()
-                    + 0.0003 * (8.0 * m_m).sin();
-    
-    // True Anomaly
-    let m_v_true = m_l + m_eq_center;
-    
-    // Argument of Latitude (u = v + ω - Ω)
-    let m_arg_lat = (m_v_true - m_node).to_radians();
-    
-    // ระยะห่างดาวพุธ-ดวงอาทิตย์ (AU)
-    let m_a = 0.387098; // Semi-major axis
-    let m_r = m_a * (1.0 - m_ecc * m_ecc) / (1.0 + m_ecc * m_m.cos());
-    
-    // ===== 3. คำนวณตำแหน่ง 3 มิติและแปลงเป็นระนาบอุปราศี =====
-    
-    // ตำแหน่งในระนาบวงโคจร (orbital plane)
-    let m_inc_rad = m_inc.to_radians();
-    let m_node_rad = m_node.to_radians();
-    
-    // แปลงเป็นพิกัด heliocentric ecliptic
-    let x_orb = m_r * m_arg_lat.cos();
-    let y_orb = m_r * m_arg_lat.sin();
-    
-    // แปลงจากระนาบวงโคจรไปสู่ระนาบอุปราศี (ecliptic)
-    let m_x_helio = (x_orb * m_node_rad.cos() - y_orb * m_inc_rad.cos() * m_node_rad.sin());
-    let m_y_helio = (x_orb * m_node_rad.sin() + y_orb * m_inc_rad.cos() * m_node_rad.cos());
-    let m_z_helio = (y_orb * m_inc_rad.sin());
-    
-    // ===== 4. แปลงเป็น Geocentric (มองจากโลก) =====
-    
-    // ตำแหน่งโลกในระบบ heliocentric
-    let e_x = e_r * e_v_rad.cos();
-    let e_y = e_r * e_v_rad.sin();
-    let e_z = 0.0; // โลกอยู่ในระนาบอุปราศี
-    
-    // ตำแหน่งดาวพุธที่มองจากโลก
-    let geo_x = m_x_helio - e_x;
-    let geo_y = m_y_helio - e_y;
-    let geo_z = m_z_helio - e_z;
-    
-    // Geocentric longitude (ลองจิจูดท้องฟ้า)
-    let mut geocentric_long = geo_y.atan2(geo_x).to_degrees();
-    
-    // ===== 5. การแก้ไขเพิ่มเติม (Perturbations) =====
-    // พจน์แก้ไขจากดาวเคราะห์ดวงอื่น (โดยเฉพาะดาวศุกร์และดาวพฤหัสบดี)
-    
-    // Mean longitude ของดาวศุกร์
-    let venus_mean_long = (181.9798 + 58517.8156 * t) % 360.0;
-    
-    // Mean longitude ของดาวพฤหัสบดี  
-    let jupiter_mean_long = (34.3515 + 3034.9056 * t) % 360.0;
-    
-    // Perturbation terms (องศา)
-    let pert_venus = 0.00204 * (5.0 * venus_mean_long.to_radians() - 2.0 * m_l.to_radians() + 0.21328).sin();
-    let pert_jupiter = 0.00103 * (2.0 * jupiter_mean_long.to_radians() - 5.0 * m_l.to_radians() - 3.07577).sin();
-    let pert_earth = 0.00091 * (2.0 * e_l.to_radians() - 2.0 * m_l.to_radians() - 0.05149).sin();
-    
-    geocentric_long += pert_venus + pert_jupiter + pert_earth;
-    
-    // ===== 6. Nutation (การส่ายของแกนโลก) - แก้ไขเล็กน้อย =====
-    let omega = (125.04 - 1934.136 * t).to_radians(); // Longitude ของ ascending node ของดวงจันทร์
-    let nutation_long = -0.00569 - 0.00479 * omega.sin(); // องศา
-    
-    geocentric_long += nutation_long;
-    
-    // ===== 7. ปรับค่าให้อยู่ในช่วง 0-360 องศา =====
-    geocentric_long = geocentric_long % 360.0;
-    if geocentric_long < 0.0 { 
-        geocentric_long += 360.0; 
-    }
-    
-    geocentric_long
+// horathai serve [--host 127.0.0.1] [--port 8080] [--slow-ratio 0.5 --fast-ratio 1.5]
+// รายละเอียดบริการแต่ละตัวอยู่ที่ server.rs
+fn run_serve(args: &cli::Args, lang: Lang) {
+    let host = args.get("host").unwrap_or("127.0.0.1");
+    let port = match args.get("port").map(str::parse::<u16>) {
+        None => 8080,
+        Some(Ok(port)) => port,
+        Some(Err(_)) => return eprintln!("{}", lang.format(Message::InvalidPort, &[&args.get("port").unwrap_or("")])),
+    };
+    // เกณฑ์ตั้งต้นของทุกคำขอ แต่ละคำขอยังส่ง slow-ratio/fast-ratio มาทับได้
+    let Some(thresholds) = thresholds_input(args, lang) else { return };
+    if let Err(err) = server::serve(host, port, lang, thresholds) {
+        eprintln!("{}", lang.format(Message::ServeFailed, &[&format!("{}:{}", host, port), &err]));
+    }
 }
 
-// ดาวพฤหัสบดี (๕): เดินประมาณ 1 ราศีต่อปี (ความเร็ว 0.083 องศา/วัน)
-// ฟังก์ชันคำนวณดาวพฤหัสบดี (๕) - ปรับปรุงแบบละเอียด
-// อ้างอิง Epoch: 13 เมษายน 1800 เวลา 6:00 น. (GMT+7) = JD 2378493.458333
-fn calculate_jupiter_position(jd: f64) -> f64 {
-    // Epoch อ้างอิง: 13 เมษายน 1800 เวลา 06:00 GMT+7
-    let epoch_1800_jd = 2378493.458333;
-    let t = (jd - epoch_1800_jd) / 36525.0; // จำนวนศตวรรษจูเลียนหลัง epoch
-
-    // ===== 1. คำนวณตำแหน่งโลก (Earth/Sun) =====
-    // Mean Longitude ของโลก (องศา)
-    let mut e_l = 100.46435 + 35999.37249 * t + 0.0003032 * t * t;
-    e_l = e_l % 360.0;
-    if e_l < 0.0 { e_l += 360.0; }
-    
-    // Perihelion ของโลก
-    let mut e_g = 102.93768 + 0.32327 * t + 0.00015 * t * t;
-    e_g = e_g % 360.0;
-    if e_g < 0.0 { e_g += 360.0; }
-    
-    // Mean Anomaly ของโลก
-    let e_m = (e_l - e_g).to_radians();
-    
-    // Equation of Center สำหรับโลก
-    let e_eq_center = 1.914600 * e_m.sin() 
-                    + 0.019993 * (2.0 * e_m).sin()
-                    + 0.000290 * (3.0 * e_m).sin();
-    
-    // True Longitude ของโลก
-    let e_v = e_l + e_eq_center;
-    let e_v_rad = e_v.to_radians();
-    
-    // ระยะห่างโลก-ดวงอาทิตย์ (AU)
-    let e_ecc = 0.016708617 - 0.000042037 * t;
-    let e_r = 1.000001018 * (1.0 - e_ecc * e_ecc) / (1.0 + e_ecc * e_m.cos());
-
-    // ===== 2. คำนวณวงโคจรดาวพฤหัสบดี (Heliocentric) =====
-    
-    // Mean Longitude ของดาวพฤหัสบดี ณ Epoch (องศา)
-    // ค่าเริ่มต้น ณ 13 เม.ย. 1800: ประมาณ 34.3 องศา
-    let mut j_l = 65.32108 + 3034.90567 * t + 0.00022374 * t * t;
-    j_l = j_l % 360.0;
-    if j_l < 0.0 { j_l += 360.0; }
-    
-    // Perihelion ของดาวพฤหัสบดี
-    let mut j_perihelion = 14.75385 + 0.21252 * t + 0.00031097 * t * t;
-    j_perihelion = j_perihelion % 360.0;
-    if j_perihelion < 0.0 { j_perihelion += 360.0; }
-    
-    // Ascending Node ของดาวพฤหัสบดี
-    let mut j_node = 100.55615 - 0.05237 * t - 0.00021819 * t * t;
-    j_node = j_node % 360.0;
-    if j_node < 0.0 { j_node += 360.0; }
-    
-    // Inclination (ความเอียงของวงโคจร)
-    let j_inc = 1.30530 - 0.00155 * t; // องศา (เอียงน้อยมาก)
-    
-    // Mean Anomaly ของดาวพฤหัสบดี
-    let j_m = (j_l - j_perihelion).to_radians();
-    
-    // Eccentricity ของดาวพฤหัสบดี (แปรตามเวลา)
-    let j_ecc = 0.04839266 - 0.000013528 * t - 0.0000000864 * t * t;
-    
-    // Equation of Center สำหรับดาวพฤหัสบดี (4 พจน์ - เพราะ eccentricity น้อย)
-    let j_eq_center = 5.55549 * j_m.sin()
-                    + 0.16763 * (2.0 * j_m).sin()
-                    + 0.00526 * (3.0 * j_m).sin()
-                    + 0.00188 * (4.0 * j_m).sin();
-    
-    // True Anomaly
-    let j_v_true = j_l + j_eq_center;
-    
-    // Argument of Latitude (u = v + ω - Ω)
-    let j_arg_lat = (j_v_true - j_node).to_radians();
-    
-    // ระยะห่างดาวพฤหัสบดี-ดวงอาทิตย์ (AU)
-    let j_a = 5.202561; // Semi-major axis
-    let j_r = j_a * (1.0 - j_ecc * j_ecc) / (1.0 + j_ecc * j_m.cos());
-    
-    // ===== 3. คำนวณตำแหน่ง 3 มิติและแปลงเป็นระนาบอุปราศี =====
-    
-    let j_inc_rad = j_inc.to_radians();
-    let j_node_rad = j_node.to_radians();
-    
-    // แปลงเป็นพิกัด heliocentric ecliptic
-    let x_orb = j_r * j_arg_lat.cos();
-    let y_orb = j_r * j_arg_lat.sin();
-    
-    // แปลงจากระนาบวงโคจรไปสู่ระนาบอุปราศี (ecliptic)
-    let j_x_helio = (x_orb * j_node_rad.cos() - y_orb * j_inc_rad.cos() * j_node_rad.sin());
-    let j_y_helio = (x_orb * j_node_rad.sin() + y_orb * j_inc_rad.cos() * j_node_rad.cos());
-    let j_z_helio = (y_orb * j_inc_rad.sin());
-    
-    // ===== 4. แปลงเป็น Geocentric (มองจากโลก) =====
-    
-    // ตำแหน่งโลกในระบบ heliocentric
-    let e_x = e_r * e_v_rad.cos();
-    let e_y = e_r * e_v_rad.sin();
-    let e_z = 0.0; // โลกอยู่ในระนาบอุปราศี
-    
-    // ตำแหน่งดาวพฤหัสบดีที่มองจากโลก
-    let geo_x = j_x_helio - e_x;
-    let geo_y = j_y_helio - e_y;
-    let geo_z = j_z_helio - e_z;
-    
-    // Geocentric longitude (ลองจิจูดท้องฟ้า)
-    let mut geocentric_long = geo_y.atan2(geo_x).to_degrees();
-    
-    // ===== 5. การแก้ไขเพิ่มเติม (Perturbations) =====
-    // พจน์แก้ไขจากดาวเสาร์ (มีผลมากที่สุดต่อดาวพฤหัสบดี)
-    
-    // Mean longitude ของดาวเสาร์
-    let saturn_mean_long = (49.9485 + 1222.1138 * t) % 360.0;
-    
-    // Perturbation terms (องศา) - ผลจากดาวเสาร์
-    // Great Inequality: การเกิด resonance 5:2 ระหว่างดาวพฤหัสบดีและดาวเสาร์
-    let pert_saturn_1 = 0.33033 * (5.0 * saturn_mean_long.to_radians() - 2.0 * j_l.to_radians() + 0.91330).sin();
-    let pert_saturn_2 = 0.03304 * (5.0 * saturn_mean_long.to_radians() - 2.0 * j_l.to_radians() - 0.63863).sin();
-    
-    // ผลจากโลก (น้อยมาก)
-    let pert_earth = 0.00204 * (e_l.to_radians() - j_l.to_radians()).sin();
-    
-    geocentric_long += pert_saturn_1 + pert_saturn_2 + pert_earth;
-    
-    // ===== 6. Nutation (การส่ายของแกนโลก) =====
-    let omega = (125.04 - 1934.136 * t).to_radians();
-    let nutation_long = -0.00569 - 0.00479 * omega.sin();
-    
-    geocentric_long += nutation_long;
-    
-    // ===== 7. ปรับค่าให้อยู่ในช่วง 0-360 องศา =====
-    geocentric_long = geocentric_long % 360.0;
-    if geocentric_long < 0.0 { 
-        geocentric_long += 360.0; 
-    }
-    
-    geocentric_long
+// horathai accuracy [--reference table.csv] [--planet mars] [--format text|csv|json] [--output report.txt]
+// ไม่ระบุ --reference เทียบกับตารางที่แนบมากับโปรแกรม (รายละเอียดที่ accuracy.rs)
+fn run_accuracy(args: &cli::Args, lang: Lang) {
+    let (source, text) = match args.get("reference") {
+        Some(path) => match std::fs::read_to_string(path) {
+            Ok(text) => (path, text),
+            Err(err) => return eprintln!("{}", lang.format(Message::ReadFailed, &[&path, &err])),
+        },
+        None => ("data/reference_ephemeris.csv", accuracy::BUNDLED_REFERENCE.to_string()),
+    };
+    let reference = match accuracy::parse_reference(&text) {
+        Ok(reference) => reference,
+        Err(err) => return eprintln!("{}", err.message(lang, source)),
+    };
+    let planets = match args.get("planet") {
+        None => Vec::new(),
+        Some(key) => match Planet::from_key(key) {
+            Some(planet) => vec![planet],
+            None => return eprintln!("{}", lang.format(Message::UnknownPlanet, &[&key])),
+        },
+    };
+
+    let report = accuracy::compare(&reference, &planets);
+    let output = match args.get("format").unwrap_or("text") {
+        "text" => {
+            let first = reference.rows.first().map_or((0, 0, 0), |row| row.date);
+            let last = reference.rows.last().map_or((0, 0, 0), |row| row.date);
+            let mut text = lang.format(Message::AccuracyHeader, &[
+                &source,
+                &reference.rows.len(),
+                &format!("{}/{}/{}", first.0, first.1, first.2),
+                &format!("{}/{}/{}", last.0, last.1, last.2),
+            ]);
+            text.push('\n');
+            let unchecked = accuracy::unchecked(&reference);
+            if planets.is_empty() && !unchecked.is_empty() {
+                let names: Vec<&str> = unchecked.iter().map(|&p| lang.planet(p)).collect();
+                text.push_str(&lang.format(Message::AccuracyUnchecked, &[&names.join(", ")]));
+                text.push('\n');
+            }
+            for coverage in accuracy::partial(&reference, &report) {
+                let names: Vec<&str> = coverage.planets.iter().map(|&p| lang.planet(p)).collect();
+                let ((d1, m1, y1), (d2, m2, y2)) = (coverage.first, coverage.last);
+                text.push_str(&lang.format(Message::AccuracyPartial, &[
+                    &format!("{}/{}/{}", d1, m1, y1),
+                    &format!("{}/{}/{}", d2, m2, y2),
+                    &names.join(", "),
+                ]));
+                text.push('\n');
+            }
+            text + &accuracy::to_text(&report, lang)
+        }
+        "csv" => accuracy::to_csv(&report),
+        "json" => format!("{}\n", accuracy::to_json(&report, lang)),
+        other => return eprintln!("{}", lang.format(Message::UnknownFormat, &[&other])),
+    };
+
+    match args.get("output") {
+        Some(path) => {
+            if let Err(err) = std::fs::write(path, output) {
+                eprintln!("{}", lang.format(Message::WriteFailed, &[&path, &err]));
+            }
+        }
+        None => print!("{}", output),
+    }
 }
 
-// ดาวศุกร์ (๖): ดาววงใน เดินเกาะกลุ่มอาทิตย์ (ความเร็วเฉลี่ยเท่าอาทิตย์ แต่แกว่งได้ 47 องศา)
-// ฟังก์ชันคำนวณดาวศุกร์ (๖) - ปรับปรุงแบบละเอียด
-// อ้างอิง Epoch: 13 เมษายน 1800 เวลา 6:00 น. (GMT+7) = JD 2378493.458333
-fn calculate_venus_position(jd: f64, solar_long: f64) -> f64 {
-   
-    // Epoch อ้างอิง: 13 เมษายน 1800 เวลา 06:00 GMT+7
-    let epoch_1800_jd = 2378493.458333;
-    let t = (jd - epoch_1800_jd) / 36525.0; // จำนวนศตวรรษจูเลียนหลัง epoch
-
-    // ===== 1. คำนวณตำแหน่งโลก (Earth/Sun) =====
-    // Mean Longitude ของโลก (องศา)
-    let mut e_l = 100.46435 + 35999.37249 * t + 0.0003032 * t * t;
-    e_l = e_l % 360.0;
-    if e_l < 0.0 { e_l += 360.0; }
-    
-    // Perihelion ของโลก
-    let mut e_g = 102.93768 + 0.32327 * t + 0.00015 * t * t;
-    e_g = e_g % 360.0;
-    if e_g < 0.0 { e_g += 360.0; }
-    
-    // Mean Anomaly ของโลก
-    let e_m = (e_l - e_g).to_radians();
-    
-    // Equation of Center สำหรับโลก
-    let e_eq_center = 1.914600 * e_m.sin() 
-                    + 0.019993 * (2.0 * e_m).sin()
-                    + 0.000290 * (3.0 * e_m).sin();
-    
-    // True Longitude ของโลก
-    let e_v = e_l + e_eq_center;
-    let e_v_rad = e_v.to_radians();
-    
-    // ระยะห่างโลก-ดวงอาทิตย์ (AU)
-    let e_ecc = 0.016708617 - 0.000042037 * t;
-    let e_r = 1.000001018 * (1.0 - e_ecc * e_ecc) / (1.0 + e_ecc * e_m.cos());
-
-    // ===== 2. คำนวณวงโคจรดาวศุกร์ (Heliocentric) =====
-    
-    // Mean Longitude ของดาวศุกร์ ณ Epoch (องศา)
-    // ค่าเริ่มต้น ณ 13 เม.ย. 1800 เวลา 06:00 น. (GMT+7): 331.03 องศา
-    // Mean Motion: 58543.7986 องศา/ศตวรรษ (ปรับให้ได้ 174.25° ณ 3 ต.ค. 1987)
-    let mut v_l = 331.03 + 58575.336 * t + 0.00052556 * t * t;
-    v_l = v_l % 360.0;
-    if v_l < 0.0 { v_l += 360.0; }
-    
-    // Perihelion ของดาวศุกร์
-    let mut v_perihelion = 131.5637 + 0.04818 * t + 0.00013955 * t * t;
-    v_perihelion = v_perihelion % 360.0;
-    if v_perihelion < 0.0 { v_perihelion += 360.0; }
-    
-    // Ascending Node ของดาวศุกร์
-    let mut v_node = 76.6799 - 0.04107 * t - 0.00013812 * t * t;
-    v_node = v_node % 360.0;
-    if v_node < 0.0 { v_node += 360.0; }
-    
-    // Inclination (ความเอียงของวงโคจร)
-    let v_inc = 3.39471 - 0.00078 * t; // องศา (เอียงปานกลาง)
-    
-    // Mean Anomaly ของดาวศุกร์
-    let v_m = (v_l - v_perihelion).to_radians();
-    
-    // Eccentricity ของดาวศุกร์ (แปรตามเวลา)
-    // ดาวศุกร์มี eccentricity น้อยมาก (0.0068) - วงโคจรเกือบกลมสนิท!
-    let v_ecc = 0.00682069 - 0.000047766 * t + 0.0000000975 * t * t;
-    
-    // Equation of Center สำหรับดาวศุกร์ (3 พจน์ - เพราะ eccentricity น้อยมาก)
-    let v_eq_center = 0.77967 * v_m.sin()
-                    + 0.00052 * (2.0 * v_m).sin()
-                    + 0.00004 * (3.0 * v_m).sin();
-    
-    // True Anomaly
-    let v_v_true = v_l + v_eq_center;
-    
-    // Argument of Latitude (u = v + ω - Ω)
-    let v_arg_lat = (v_v_true - v_node).to_radians();
-    
-    // ระยะห่างดาวศุกร์-ดวงอาทิตย์ (AU)
-    let v_a = 0.723332; // Semi-major axis
-    let v_r = v_a * (1.0 - v_ecc * v_ecc) / (1.0 + v_ecc * v_m.cos());
-    
-    // ===== 3. คำนวณตำแหน่ง 3 มิติและแปลงเป็นระนาบอุปราศี =====
-    
-    let v_inc_rad = v_inc.to_radians();
-    let v_node_rad = v_node.to_radians();
-    
-    // แปลงเป็นพิกัด heliocentric ecliptic
-    let x_orb = v_r * v_arg_lat.cos();
-    let y_orb = v_r * v_arg_lat.sin();
-    
-    // แปลงจากระนาบวงโคจรไปสู่ระนาบอุปราศี (ecliptic)
-    let v_x_helio = (x_orb * v_node_rad.cos() - y_orb * v_inc_rad.cos() * v_node_rad.sin());
-    let v_y_helio = (x_orb * v_node_rad.sin() + y_orb * v_inc_rad.cos() * v_node_rad.cos());
-    let v_z_helio = (y_orb * v_inc_rad.sin());
-    
-    // ===== 4. แปลงเป็น Geocentric (มองจากโลก) =====
-    
-    // ตำแหน่งโลกในระบบ heliocentric
-    let e_x = e_r * e_v_rad.cos();
-    let e_y = e_r * e_v_rad.sin();
-    let e_z = 0.0; // โลกอยู่ในระนาบอุปราศี
-    
-    // ตำแหน่งดาวศุกร์ที่มองจากโลก
-    let geo_x = v_x_helio - e_x;
-    let geo_y = v_y_helio - e_y;
-    let geo_z = v_z_helio - e_z;
-    
-    // Geocentric longitude (ลองจิจูดท้องฟ้า)
-    let mut geocentric_long = geo_y.atan2(geo_x).to_degrees();
-    
-    // ===== 5. การแก้ไขเพิ่มเติม (Perturbations) =====
-    // พจน์แก้ไขจากดาวพฤหัสบดี (มีผลเล็กน้อย)
-    
-    // Mean longitude ของดาวพฤหัสบดี
-    let jupiter_mean_long = (34.3515 + 3034.9056 * t) % 360.0;
-    
-    // Perturbation terms (องศา)
-    let pert_jupiter = 0.00313 * (2.0 * jupiter_mean_long.to_radians() - 7.0 * v_l.to_radians() + 3.29).sin()
-                     + 0.00198 * (3.0 * jupiter_mean_long.to_radians() - 8.0 * v_l.to_radians() + 2.33).sin();
-    
-    // ผลจากโลก (การรบกวนเล็กน้อย)
-    let pert_earth = 0.00106 * (5.0 * e_l.to_radians() - 3.0 * v_l.to_radians() - 1.51).sin();
-    
-    geocentric_long += pert_jupiter + pert_earth;
-    
-    // ===== 6. Nutation (การส่ายของแกนโลก) =====
-    let omega = (125.04 - 1934.136 * t).to_radians();
-    let nutation_long = -0.00569 - 0.00479 * omega.sin();
-    
-    geocentric_long += nutation_long;
-    
-    // ===== 7. ปรับค่าให้อยู่ในช่วง 0-360 องศา =====
-    geocentric_long = geocentric_long % 360.0;
-    if geocentric_long < 0.0 { 
-        geocentric_long += 360.0; 
-    }
-    
-    geocentric_long
+// horathai rectify --date 3/10/2530 --from 09:00 --to 11:00 [--step 4] [--province bangkok]
+//     --event marriage:12/5/2555 --event child:3/2/2558 --event career:1/6/2560 [--top 10] [--format text|csv|json]
+// ไม่ระบุ --from/--to ลองทั้งวัน
+fn run_rectify(args: &cli::Args, lang: Lang) {
+    let Some(text) = args.get("date") else { return eprintln!("{}", lang.text(Message::DateRequired)) };
+    let date = match thaidate::parse_date(text) {
+        Ok(date) => date,
+        Err(err) => return eprintln!("{}", err.message(lang)),
+    };
+    let time = |name: &str, default: (u32, u32)| args.get(name).map_or(Ok(default), thaidate::parse_time);
+    let (from, to) = match time("from", (0, 0)).and_then(|from| Ok((from, time("to", (23, 59))?))) {
+        Ok(window) => window,
+        Err(err) => return eprintln!("{}", err.message(lang)),
+    };
+    // ลัคนาเดินราว 1 องศาทุก 4 นาที
+    let step = match args.get("step") {
+        None => 4,
+        Some(text) => match text.parse::<u32>() {
+            Ok(step) => step,
+            Err(_) => return eprintln!("{}", rectify::RectifyError::InvalidStep(text.to_string()).message(lang)),
+        },
+    };
+    let top = match args.get("top").map(str::parse::<usize>) {
+        None => 10,
+        Some(Ok(top)) => top,
+        Some(Err(_)) => return eprintln!("{}", lang.format(Message::InvalidTop, &[&args.get("top").unwrap_or("")])),
+    };
+    let events = match args.get_all("event").map(rectify::parse_event).collect::<Result<Vec<_>, _>>() {
+        Ok(events) => events,
+        Err(err) => return eprintln!("{}", err.message(lang)),
+    };
+    let prov = match find_province(args.get("province").unwrap_or("bangkok")) {
+        Ok(prov) => prov,
+        Err(err) => return eprintln!("{}", err.message(lang)),
+    };
 
+    let Some(thresholds) = thresholds_input(args, lang) else { return };
+    let candidates = match rectify::rectify(date, from, to, step, &prov, &events, &thresholds) {
+        Ok(candidates) => candidates,
+        Err(err) => return eprintln!("{}", err.message(lang)),
+    };
+    let output = match args.get("format").unwrap_or("text") {
+        "text" => {
+            let header = lang.format(Message::RectifyHeader, &[
+                &format!("{}/{}/{}", date.0, date.1, date.2),
+                &format!("{:02}:{:02}", from.0, from.1),
+                &format!("{:02}:{:02}", to.0, to.1),
+                &step,
+                &lang.province(&prov),
+                &events.len(),
+            ]);
+            format!("{}\n{}", header, rectify::to_text(&candidates, top, lang))
+        }
+        "csv" => rectify::to_csv(&candidates, lang),
+        "json" => format!("{}\n", rectify::to_json(&candidates, lang)),
+        other => return eprintln!("{}", lang.format(Message::UnknownFormat, &[&other])),
+    };
+
+    match args.get("output") {
+        Some(path) => {
+            if let Err(err) = std::fs::write(path, output) {
+                eprintln!("{}", lang.format(Message::WriteFailed, &[&path, &err]));
+            }
+        }
+        None => print!("{}", output),
+    }
 }
 
-// ดาวเสาร์ (๗): ดาวที่เดินช้าที่สุดในดาวเดิม (ประมาณ 2.5 ปีต่อราศี)
-fn calculate_saturn_position(jd: f64) -> f64 {
-    // Epoch อ้างอิง: 13 เมษายน 1800 เวลา 06:00 GMT+7
-    let epoch_1800_jd = 2378493.458333;
-    let t = (jd - epoch_1800_jd) / 36525.0; // จำนวนศตวรรษจูเลียนหลัง epoch
-
-    // ===== 1. คำนวณตำแหน่งโลก (Earth/Sun) =====
-    // Mean Longitude ของโลก (องศา)
-    let mut e_l = 100.46435 + 35999.37249 * t + 0.0003032 * t * t;
-    e_l = e_l % 360.0;
-    if e_l < 0.0 { e_l += 360.0; }
-    
-    // Perihelion ของโลก
-    let mut e_g = 102.93768 + 0.32327 * t + 0.00015 * t * t;
-    e_g = e_g % 360.0;
-    if e_g < 0.0 { e_g += 360.0; }
-    
-    // Mean Anomaly ของโลก
-    let e_m = (e_l - e_g).to_radians();
-    
-    // Equation of Center สำหรับโลก
-    let e_eq_center = 1.914600 * e_m.sin() 
-                    + 0.019993 * (2.0 * e_m).sin()
-                    + 0.000290 * (3.0 * e_m).sin();
-    
-    // True Longitude ของโลก
-    let e_v = e_l + e_eq_center;
-    let e_v_rad = e_v.to_radians();
-    
-    // ระยะห่างโลก-ดวงอาทิตย์ (AU)
-    let e_ecc = 0.016708617 - 0.000042037 * t;
-    let e_r = 1.000001018 * (1.0 - e_ecc * e_ecc) / (1.0 + e_ecc * e_m.cos());
-
-    // ===== 2. คำนวณวงโคจรดาวเสาร์ (Heliocentric) =====
-    
-    // Mean Longitude ของดาวเสาร์ ณ Epoch (องศา)
-    // ค่าเริ่มต้น ณ 13 เม.ย. 1800: ประมาณ 49.95 องศา
-    let mut s_l = 100.40 + 1222.1138 * t + 0.00019626 * t * t;
-    s_l = s_l % 360.0;
-    if s_l < 0.0 { s_l += 360.0; }
-    
-    // Perihelion ของดาวเสาร์
-    let mut s_perihelion = 92.4321 + 0.41005 * t + 0.0005156 * t * t;
-    s_perihelion = s_perihelion % 360.0;
-    if s_perihelion < 0.0 { s_perihelion += 360.0; }
-    
-    // Ascending Node ของดาวเสาร์
-    let mut s_node = 113.71504 - 0.08771 * t - 0.00018150 * t * t;
-    s_node = s_node % 360.0;
-    if s_node < 0.0 { s_node += 360.0; }
-    
-    // Inclination (ความเอียงของวงโคจร)
-    let s_inc = 2.48446 - 0.00180 * t; // องศา (เอียงน้อย)
-    
-    // Mean Anomaly ของดาวเสาร์
-    let s_m = (s_l - s_perihelion).to_radians();
-    
-    // Eccentricity ของดาวเสาร์ (แปรตามเวลา)
-    let s_ecc = 0.05550825 - 0.000034664 * t - 0.0000003634 * t * t;
-    
-    // Equation of Center สำหรับดาวเสาร์ (5 พจน์)
-    let s_eq_center = 6.40370 * s_m.sin()
-                    + 0.41380 * (2.0 * s_m).sin()
-                    + 0.03167 * (3.0 * s_m).sin()
-                    + 0.00272 * (4.0 * s_m).sin()
-                    + 0.00024 * (5.0 * s_m).sin();
-    
-    // True Anomaly
-    let s_v_true = s_l + s_eq_center;
-    
-    // Argument of Latitude (u = v + ω - Ω)
-    let s_arg_lat = (s_v_true - s_node).to_radians();
-    
-    // ระยะห่างดาวเสาร์-ดวงอาทิตย์ (AU)
-    let s_a = 9.554747; // Semi-major axis
-    let s_r = s_a * (1.0 - s_ecc * s_ecc) / (1.0 + s_ecc * s_m.cos());
-    
-    // ===== 3. คำนวณตำแหน่ง 3 มิติและแปลงเป็นระนาบอุปราศี =====
-    
-    let s_inc_rad = s_inc.to_radians();
-    let s_node_rad = s_node.to_radians();
-    
-    // แปลงเป็นพิกัด heliocentric ecliptic
-    let x_orb = s_r * s_arg_lat.cos();
-    let y_orb = s_r * s_arg_lat.sin();
-    
-    // แปลงจากระนาบวงโคจรไปสู่ระนาบอุปราศี (ecliptic)
-    let s_x_helio = (x_orb * s_node_rad.cos() - y_orb * s_inc_rad.cos() * s_node_rad.sin());
-    let s_y_helio = (x_orb * s_node_rad.sin() + y_orb * s_inc_rad.cos() * s_node_rad.cos());
-    let s_z_helio = (y_orb * s_inc_rad.sin());
-    
-    // ===== 4. แปลงเป็น Geocentric (มองจากโลก) =====
-    
-    // ตำแหน่งโลกในระบบ heliocentric
-    let e_x = e_r * e_v_rad.cos();
-    let e_y = e_r * e_v_rad.sin();
-    let e_z = 0.0; // โลกอยู่ในระนาบอุปราศี
-    
-    // ตำแหน่งดาวเสาร์ที่มองจากโลก
-    let geo_x = s_x_helio - e_x;
-    let geo_y = s_y_helio - e_y;
-    let geo_z = s_z_helio - e_z;
-    
-    // Geocentric longitude (ลองจิจูดท้องฟ้า)
-    let mut geocentric_long = geo_y.atan2(geo_x).to_degrees();
-    
-    // ===== 5. การแก้ไขเพิ่มเติม (Perturbations) =====
-    // พจน์แก้ไขจากดาวพฤหัสบดี (Great Inequality - มีผลมากที่สุด!)
-    
-    // Mean longitude ของดาวพฤหัสบดี
-    let jupiter_mean_long = (34.3515 + 3034.9056 * t) % 360.0;
-    
-    // Great Inequality: การเกิด resonance 5:2 ระหว่างดาวพฤหัสบดีและดาวเสาร์
-    // (เหมือนกันกับที่ดาวพฤหัสบดีได้รับจากดาวเสาร์ แต่ตรงข้าม)
-    let pert_jupiter_1 = -0.33033 * (5.0 * s_l.to_radians() - 2.0 * jupiter_mean_long.to_radians() - 0.91330).sin();
-    let pert_jupiter_2 = -0.03304 * (5.0 * s_l.to_radians() - 2.0 * jupiter_mean_long.to_radians() + 0.63863).sin();
-    
-    // Perturbations เพิ่มเติมจากดาวพฤหัสบดี
-    let pert_jupiter_3 = 0.02160 * (2.0 * jupiter_mean_long.to_radians() - 5.0 * s_l.to_radians() + 0.49987).sin();
-    
-    // Perturbation จากดาวยูเรนัส (เล็กน้อย)
-    let uranus_mean_long = (313.2322 + 428.4820 * t) % 360.0;
-    let pert_uranus = 0.00121 * (uranus_mean_long.to_radians() - s_l.to_radians()).sin();
-    
-    geocentric_long += pert_jupiter_1 + pert_jupiter_2 + pert_jupiter_3 + pert_uranus;
-    
-    // ===== 6. Nutation (การส่ายของแกนโลก) =====
-    let omega = (125.04 - 1934.136 * t).to_radians();
-    let nutation_long = -0.00569 - 0.00479 * omega.sin();
-    
-    geocentric_long += nutation_long;
-    
-    // ===== 7. ปรับค่าให้อยู่ในช่วง 0-360 องศา =====
-    geocentric_long = geocentric_long % 360.0;
-    if geocentric_long < 0.0 { 
-        geocentric_long += 360.0; 
-    }
-    
-    geocentric_long
+// horathai calendar --date 3/10/2530
+// horathai calendar --lunar "วันอังคาร ขึ้น ๕ ค่ำ เดือน ๑๑ ปีเถาะ" --year 2530
+fn run_calendar(args: &cli::Args, lang: Lang) {
+    if let Some(text) = args.get("lunar") {
+        let Some(parsed) = lunar::parse_lunar(text) else {
+            return eprintln!("{}", lang.format(Message::InvalidLunarDate, &[&text]));
+        };
+        let year_be = match (parsed.year_be, args.get("year").map(str::parse::<u32>)) {
+            (Some(year), _) | (None, Some(Ok(year))) => year,
+            (None, Some(Err(_))) => return eprintln!("{}", lang.text(Message::InvalidYear)),
+            (None, None) => return eprintln!("{}", lang.text(Message::LunarYearRequired)),
+        };
+        if let Err(err) = check_year(year_be as i64) {
+            return eprintln!("{}", err.message(lang));
+        }
+        let Some((d, m, y)) = lunar::from_lunar(parsed.waxing, parsed.day, parsed.month, parsed.second_eighth, year_be) else {
+            return eprintln!("{}", lang.format(Message::NoSuchLunarDay, &[&text, &year_be]));
+        };
+
+        let found = lunar::to_lunar(d, m, y);
+        println!("{} = {}/{}/{}", found.to_thai_string(), d, m, y);
+        // ตรวจวันและปีนักษัตรที่ระบุมากับข้อความ
+        if let Some(weekday) = parsed.weekday.filter(|&w| w != found.weekday) {
+            eprintln!("{}", lang.format(Message::LunarWeekdayWarning, &[&lang.weekday_label(found.weekday), &lang.weekday_label(weekday)]));
+        }
+        // ชื่อปีนักษัตรแสดงเป็นภาษาไทยเช่นเดียวกับวันจันทรคติ
+        if let Some(z) = parsed.zodiac_year.filter(|z| lunar::ZODIAC_YEAR_NAMES[*z] != found.zodiac_year()) {
+            eprintln!("{}", lang.format(Message::ZodiacYearWarning, &[&year_be, &found.zodiac_year(), &lunar::ZODIAC_YEAR_NAMES[z]]));
+        }
+        return;
+    }
+
+    let (d, m, y) = match args.get("date") {
+        Some(text) => match thaidate::parse_date(text) {
+            Ok(date) => date,
+            Err(err) => return eprintln!("{}", err.message(lang)),
+        },
+        None => { let (d, m, y, _, _) = jd_to_date(now_jd()); (d, m, y) }
+    };
+    let date = lunar::to_lunar(d, m, y);
+    let cs = date.year_be - lunar::BE_CS_OFFSET;
+    let year_kind = if lunar::is_adhikamasa(cs) {
+        Message::Adhikamasa
+    } else if lunar::is_adhikavara(cs) {
+        Message::Adhikavara
+    } else {
+        Message::NormalLunarYear
+    };
+    println!("{}/{}/{} = {}", d, m, y, date.to_thai_string());
+    println!("{}", lang.format(Message::LunarYear, &[&date.year_be, &cs, &lang.text(year_kind), &lunar::year_length(cs)]));
 }
 
-// ราหู (๘): เดินถอยหลังเสมอ (Retrograde) ประมาณ 1.5 ปีต่อราศี
-fn calculate_rahu_position(jd: f64) -> f64 {
-    let horakhun = jd - 2378600.45833;
-    // สังเกตว่าความเร็วติดลบ และค่าเริ่มต้นอยู่ที่ประมาณ 110 องศา
-    let mut rahu = (6.78 - (0.052955 * horakhun)) % 360.0;
-    if rahu < 0.0 { rahu += 360.0; }
-    rahu
+// horathai songkran --year 2567
+fn run_songkran(args: &cli::Args, lang: Lang) {
+    let year_be = match args.get("year").map(str::parse::<u32>) {
+        Some(Ok(year)) => year,
+        Some(Err(_)) => return eprintln!("{}", lang.text(Message::InvalidYear)),
+        None => jd_to_date(now_jd()).2,
+    };
+    if let Err(err) = check_year(year_be as i64) {
+        return eprintln!("{}", err.message(lang));
+    }
+    let info = songkran::songkran(year_be);
+    // วันที่พร้อมชื่อวัน เช่น "วันเสาร์ที่ 13/4/2567"
+    let day_text = |jd: f64| {
+        let (d, m, y, _, _) = jd_to_date(jd);
+        let weekday = lang.weekday_label((jd + 1.5).floor() as usize % 7);
+        match lang {
+            Lang::Thai => format!("{}ที่ {}/{}/{}", weekday, d, m, y),
+            _ => format!("{} {}/{}/{}", weekday, d, m, y),
+        }
+    };
+    let time_text = |jd: f64| {
+        let (_, _, _, h, min) = jd_to_date(jd);
+        lang.format(Message::ClockTime, &[&format!("{:02}:{:02}", h, min)])
+    };
+
+    println!("{}", lang.format(Message::SongkranHeader, &[&year_be, &info.cs]));
+    println!("{}", lang.format(Message::SongkranMoment, &[&day_text(info.moment_jd), &time_text(info.moment_jd)]));
+    let wan_nao: Vec<String> = info.wan_nao.iter().map(|&jd| day_text(jd)).collect();
+    println!("{}", lang.format(Message::WanNao, &[&wan_nao.join(", ")]));
+    println!("{}", lang.format(Message::Thaloengsok, &[&day_text(info.thaloengsok_jd), &time_text(info.thaloengsok_jd)]));
 }
 
-// เกตุไทย (๙): เดินหน้าคงที่ (ความเร็ว 1 ราศี ประมาณ 2 เดือน)
-fn calculate_ketu_thai_position(jd: f64) -> f64 {
-    let horakhun = jd - 2378600.45833;
-    // เกตุไทยเดินหน้าเร็วประมาณ 0.15 องศา/วัน
-    let mut ketu = (152.57 + (0.145 * horakhun)) % 360.0;
-    if ketu < 0.0 { ketu += 360.0; }
-    ketu
+// horathai yam --date 18/10/2569 --province bangkok
+fn run_yam(args: &cli::Args, lang: Lang) {
+    let province_key = args.get("province").unwrap_or("bangkok");
+    let prov = match find_province(province_key) {
+        Ok(prov) => prov,
+        Err(err) => return eprintln!("{}", err.message(lang)),
+    };
+    let (d, m, y) = match args.get("date") {
+        Some(text) => match thaidate::parse_date(text) {
+            Ok(date) => date,
+            Err(err) => return eprintln!("{}", err.message(lang)),
+        },
+        None => { let (d, m, y, _, _) = jd_to_date(now_jd()); (d, m, y) }
+    };
+
+    let noon = match calculate_jd(d, m, y, 12, 0) {
+        Ok(jd) => jd,
+        Err(err) => return eprintln!("{}", err.message(lang)),
+    };
+    let weekday = (noon as i64 + 1).rem_euclid(7) as usize;
+    // นาทีนับจากเที่ยงคืน -> "hh:mm" (กลางคืนข้ามวันได้)
+    let clock = |minutes: f64| {
+        let minutes = minutes.round() as i64 % 1440;
+        format!("{:02}:{:02}", minutes / 60, minutes % 60)
+    };
+
+    let date = format!("{}/{}/{}", d, m, y);
+    println!("{}", lang.format(Message::YamHeader, &[&lang.weekday_label(weekday), &date, &lang.province(&prov)]));
+    let mut table = Table::new(&[]);
+    for yam in yam::yam_atthakan(d, m, y, prov.latitude, prov.longitude) {
+        if yam.number == 1 {
+            table.line(lang.text(if yam.night { Message::Nighttime } else { Message::Daytime }));
+        }
+        table.row([
+            format!("  {}", lang.format(Message::Yam, &[&match lang {
+                Lang::Thai => numerals::to_thai_digits(&yam.number.to_string()),
+                _ => yam.number.to_string(),
+            }])),
+            lang.format(Message::ClockTime, &[&format!("{}-{}", clock(yam.start), clock(yam.end))]),
+            lang.planet_label(yam.ruler),
+            if yam.favourable { lang.text(Message::Favourable) } else { "" }.to_string(),
+        ]);
+    }
+    print!("{}", table.render());
 }
 
-// ดาวมฤตยู (๐): เดินช้ามาก (ความเร็ว 0.0117 องศา/วัน) ประมาณ 7 ปีต่อราศี
-fn calculate_uranus_position(jd: f64) -> f64 {
-    // Epoch อ้างอิง: 13 เมษายน 1800 เวลา 06:00 GMT+7
-    let epoch_1800_jd = 2378493.458333;
-    let t = (jd - epoch_1800_jd) / 36525.0; // จำนวนศตวรรษจูเลียนหลัง epoch
-
-    // ===== 1. คำนวณตำแหน่งโลก (Earth/Sun) =====
-    // Mean Longitude ของโลก (องศา)
-    let mut e_l = 100.46435 + 35999.37249 * t + 0.0003032 * t * t;
-    e_l = e_l % 360.0;
-    if e_l < 0.0 { e_l += 360.0; }
-    
-    // Perihelion ของโลก
-    let mut e_g = 102.93768 + 0.32327 * t + 0.00015 * t * t;
-    e_g = e_g % 360.0;
-    if e_g < 0.0 { e_g += 360.0; }
-    
-    // Mean Anomaly ของโลก
-    let e_m = (e_l - e_g).to_radians();
-    
-    // Equation of Center สำหรับโลก
-    let e_eq_center = 1.914600 * e_m.sin() 
-                    + 0.019993 * (2.0 * e_m).sin()
-                    + 0.000290 * (3.0 * e_m).sin();
-    
-    // True Longitude ของโลก
-    let e_v = e_l + e_eq_center;
-    let e_v_rad = e_v.to_radians();
-    
-    // ระยะห่างโลก-ดวงอาทิตย์ (AU)
-    let e_ecc = 0.016708617 - 0.000042037 * t;
-    let e_r = 1.000001018 * (1.0 - e_ecc * e_ecc) / (1.0 + e_ecc * e_m.cos());
-
-    // ===== 2. คำนวณวงโคจรดาวยูเรนัส (Heliocentric) =====
-    
-    // Mean Longitude ของดาวยูเรนัส ณ Epoch (องศา)
-    // ค่าเริ่มต้น ณ 13 เม.ย. 1800: ประมาณ 313.23 องศา
-    let mut u_l = 143.222 + 428.4820 * t + 0.00004315 * t * t;
-    u_l = u_l % 360.0;
-    if u_l < 0.0 { u_l += 360.0; }
-    
-    // Perihelion ของดาวยูเรนัส
-    let mut u_perihelion = 171.5487 + 0.09266 * t + 0.00019132 * t * t;
-    u_perihelion = u_perihelion % 360.0;
-    if u_perihelion < 0.0 { u_perihelion += 360.0; }
-    
-    // Ascending Node ของดาวยูเรนัส
-    let mut u_node = 73.9893 - 0.06043 * t - 0.00016896 * t * t;
-    u_node = u_node % 360.0;
-    if u_node < 0.0 { u_node += 360.0; }
-    
-    // Inclination (ความเอียงของวงโคจร)
-    let u_inc = 0.77298 + 0.00055 * t; // องศา (เอียงน้อยมาก - เกือบอยู่ในระนาบเดียวกับโลก!)
-    
-    // Mean Anomaly ของดาวยูเรนัส
-    let u_m = (u_l - u_perihelion).to_radians();
-    
-    // Eccentricity ของดาวยูเรนัส (แปรตามเวลา)
-    let u_ecc = 0.04629590 - 0.000027337 * t + 0.0000000790 * t * t;
-    
-    // Equation of Center สำหรับดาวยูเรนัส (4 พจน์ - เพราะ eccentricity น้อย)
-    let u_eq_center = 5.32174 * u_m.sin()
-                    + 0.22160 * (2.0 * u_m).sin()
-                    + 0.01380 * (3.0 * u_m).sin()
-                    + 0.00100 * (4.0 * u_m).sin();
-    
-    // True Anomaly
-    let u_v_true = u_l + u_eq_center;
-    
-    // Argument of Latitude (u = v + ω - Ω)
-    let u_arg_lat = (u_v_true - u_node).to_radians();
-    
-    // ระยะห่างดาวยูเรนัส-ดวงอาทิตย์ (AU)
-    let u_a = 19.19126; // Semi-major axis
-    let u_r = u_a * (1.0 - u_ecc * u_ecc) / (1.0 + u_ecc * u_m.cos());
-    
-    // ===== 3. คำนวณตำแหน่ง 3 มิติและแปลงเป็นระนาบอุปราศี =====
-    
-    let u_inc_rad = u_inc.to_radians();
-    let u_node_rad = u_node.to_radians();
-    
-    // แปลงเป็นพิกัด heliocentric ecliptic
-    let x_orb = u_r * u_arg_lat.cos();
-    let y_orb = u_r * u_arg_lat.sin();
-    
-    // แปลงจากระนาบวงโคจรไปสู่ระนาบอุปราศี (ecliptic)
-    let u_x_helio = (x_orb * u_node_rad.cos() - y_orb * u_inc_rad.cos() * u_node_rad.sin());
-    let u_y_helio = (x_orb * u_node_rad.sin() + y_orb * u_inc_rad.cos() * u_node_rad.cos());
-    let u_z_helio = (y_orb * u_inc_rad.sin());
-    
-    // ===== 4. แปลงเป็น Geocentric (มองจากโลก) =====
-    
-    // ตำแหน่งโลกในระบบ heliocentric
-    let e_x = e_r * e_v_rad.cos();
-    let e_y = e_r * e_v_rad.sin();
-    let e_z = 0.0; // โลกอยู่ในระนาบอุปราศี
-    
-    // ตำแหน่งดาวยูเรนัสที่มองจากโลก
-    let geo_x = u_x_helio - e_x;
-    let geo_y = u_y_helio - e_y;
-    let geo_z = u_z_helio - e_z;
-    
-    // Geocentric longitude (ลองจิจูดท้องฟ้า)
-    let mut geocentric_long = geo_y.atan2(geo_x).to_degrees();
-    
-    // ===== 5. การแก้ไขเพิ่มเติม (Perturbations) =====
-    // พจน์แก้ไขจากดาวพฤหัสบดีและดาวเสาร์ (มีผลเล็กน้อย)
-    
-    // Mean longitude ของดาวพฤหัสบดี
-    let jupiter_mean_long = (34.3515 + 3034.9056 * t) % 360.0;
-    
-    // Mean longitude ของดาวเสาร์
-    let saturn_mean_long = (49.9485 + 1222.1138 * t) % 360.0;
-    
-    // Perturbation จากดาวพฤหัสบดี
-    let pert_jupiter = 0.01181 * (jupiter_mean_long.to_radians() - u_l.to_radians() + 0.3215).sin()
-                     + 0.00352 * (2.0 * jupiter_mean_long.to_radians() - 2.0 * u_l.to_radians() - 0.4587).sin();
-    
-    // Perturbation จากดาวเสาร์
-    let pert_saturn = 0.00202 * (saturn_mean_long.to_radians() - u_l.to_radians() - 0.1423).sin()
-                    + 0.00133 * (2.0 * saturn_mean_long.to_radians() - 2.0 * u_l.to_radians() + 0.5222).sin();
-    
-    // Perturbation จากดาวเนปจูน (น้อยมาก แต่มีผล)
-    let neptune_mean_long = (304.8800 + 218.4862 * t) % 360.0;
-    let pert_neptune = 0.00034 * (neptune_mean_long.to_radians() - u_l.to_radians()).sin();
-    
-    geocentric_long += pert_jupiter + pert_saturn + pert_neptune;
-    
-    // ===== 6. Nutation (การส่ายของแกนโลก) =====
-    let omega = (125.04 - 1934.136 * t).to_radians();
-    let nutation_long = -0.00569 - 0.00479 * omega.sin();
-    
-    geocentric_long += nutation_long;
-    
-    // ===== 7. ปรับค่าให้อยู่ในช่วง 0-360 องศา =====
-    geocentric_long = geocentric_long % 360.0;
-    if geocentric_long < 0.0 { 
-        geocentric_long += 360.0; 
-    }
-    
-    geocentric_long
-}
\ No newline at end of file
+fn print_chart(chart: &Chart, prov: &Province, notation: Notation, lang: Lang) {
+    let total_birth_min = chart.lmt_minutes;
+
+    // --- แสดงผลลัพธ์ ---
+    println!("{}", lang.text(Message::ChartHeader));
+    let lmt = format!("{:02}:{:02}", (total_birth_min/60.0) as u32, (total_birth_min%60.0) as u32);
+    println!("{}", lang.format(Message::LocalTime, &[&lang.province(prov), &lmt]));
+    // วันเกิดทางโหราศาสตร์เปลี่ยนตอนอาทิตย์ขึ้น
+    let weekday = chart.weekday;
+    println!("{}", lang.format(Message::BirthWeekday, &[
+        &lang.weekday_label(weekday.civil),
+        &lang.weekday_label(weekday.thai),
+        &if weekday.rahu { lang.text(Message::RahuNight) } else { "" },
+    ]));
+    println!("--------------------------------");
+    // ตาราง ชื่อ ราศี องศา และเครื่องหมายการเดินของดาว
+    let mut table = Table::new(&[Align::Left, Align::Left, Align::Left, Align::Right]);
+    let mut add = |name: String, longitude: f64, flag: &str| {
+        table.row([
+            format!("{}:", name),
+            lang.sign_label((longitude / 30.0) as usize % 12),
+            notation.label(lang).to_string(),
+            notation.format(longitude),
+            flag.to_string(),
+        ]);
+    };
+    add(lang.lagna_label(), chart.lagna, "");
+    for planet in Planet::ALL {
+        let body = chart.body(planet);
+        add(lang.planet_label(planet), body.longitude, lang.motion_flag(body.motion));
+    }
+    print!("{}", table.render());
+    println!("--------------------------------");
+}
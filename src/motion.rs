@@ -0,0 +1,125 @@
+// การเดินของดาว: พักร์ (ถอยหลัง), มนฑ์ (ช้า/หยุดนิ่ง), เสริด (เร็ว)
+// คำนวณความเร็วรายวันจากฟังก์ชันตำแหน่งดาวโดยตรง (ผลต่างตำแหน่ง ±12 ชั่วโมง)
+
+use crate::locale::{Lang, Message};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MotionState {
+    Normal,     // เดินปกติ
+    Retrograde, // พักร์
+    Slow,       // มนฑ์
+    Fast,       // เสริด
+}
+
+impl MotionState {
+    // เครื่องหมายที่พิมพ์ต่อท้ายชื่อดาว (เดินปกติไม่ต้องแสดง)
+    pub fn thai_flag(self) -> &'static str {
+        match self {
+            MotionState::Normal => "",
+            MotionState::Retrograde => "พักร์",
+            MotionState::Slow => "มนฑ์",
+            MotionState::Fast => "เสริด",
+        }
+    }
+
+    // ชื่อสำหรับผลลัพธ์ที่โปรแกรมอื่นอ่าน (CSV/JSON)
+    pub fn key(self) -> &'static str {
+        match self {
+            MotionState::Normal => "normal",
+            MotionState::Retrograde => "retrograde",
+            MotionState::Slow => "slow",
+            MotionState::Fast => "fast",
+        }
+    }
+
+    // อักษรย่อสำหรับวาดดวง (พ ม ส)
+    pub fn thai_mark(self) -> &'static str {
+        match self {
+            MotionState::Normal => "",
+            MotionState::Retrograde => "พ",
+            MotionState::Slow => "ม",
+            MotionState::Fast => "ส",
+        }
+    }
+}
+
+// เกณฑ์แบ่งสถานะ คิดเป็นอัตราส่วนต่อความเร็วเฉลี่ยของดาวแต่ละดวง
+#[derive(Clone, Copy, Debug)]
+pub struct MotionThresholds {
+    pub slow_ratio: f64, // เดินช้ากว่านี้ถือเป็นมนฑ์
+    pub fast_ratio: f64, // เดินเร็วกว่านี้ถือเป็นเสริด
+}
+
+impl Default for MotionThresholds {
+    fn default() -> Self {
+        MotionThresholds { slow_ratio: 0.5, fast_ratio: 1.5 }
+    }
+}
+
+impl MotionThresholds {
+    // ปรับเกณฑ์จากตัวเลือก slow-ratio และ fast-ratio ตัวที่ไม่ระบุคงค่าเดิม
+    // ใช้ร่วมกันทั้งบรรทัดคำสั่ง แฟ้มลูกดวง และบริการ HTTP
+    pub fn with_options<'a>(self, get: impl Fn(&str) -> Option<&'a str>, lang: Lang) -> Result<MotionThresholds, String> {
+        let ratio = |option: &str, current: f64| match get(option) {
+            None => Ok(current),
+            Some(text) => text.trim().parse::<f64>().ok()
+                .filter(|v| v.is_finite() && *v >= 0.0)
+                .ok_or_else(|| lang.format(Message::InvalidRatio, &[&text])),
+        };
+        let thresholds = MotionThresholds {
+            slow_ratio: ratio("slow-ratio", self.slow_ratio)?,
+            fast_ratio: ratio("fast-ratio", self.fast_ratio)?,
+        };
+        if !thresholds.is_valid() {
+            let pair = format!("slow-ratio {} >= fast-ratio {}", thresholds.slow_ratio, thresholds.fast_ratio);
+            return Err(lang.format(Message::InvalidRatio, &[&pair]));
+        }
+        Ok(thresholds)
+    }
+
+    // อัตราส่วนไม่ติดลบ และเกณฑ์มนฑ์ต้องต่ำกว่าเกณฑ์เสริด มิฉะนั้นสองสถานะจะซ้อนหรือกลับกัน
+    pub fn is_valid(&self) -> bool {
+        let ratio = |value: f64| value.is_finite() && value >= 0.0;
+        ratio(self.slow_ratio) && ratio(self.fast_ratio) && self.slow_ratio < self.fast_ratio
+    }
+}
+
+// ความเร็วเฉลี่ยที่มองจากโลก (องศา/วัน) ของดาวที่พักร์ได้
+// ดาวพุธและดาวศุกร์เกาะกลุ่มอาทิตย์ จึงใช้ความเร็วเฉลี่ยเท่าอาทิตย์
+pub const MEAN_MOTION_MARS: f64 = 0.5240;
+pub const MEAN_MOTION_MERCURY: f64 = 0.9856;
+pub const MEAN_MOTION_JUPITER: f64 = 0.0831;
+pub const MEAN_MOTION_VENUS: f64 = 0.9856;
+pub const MEAN_MOTION_SATURN: f64 = 0.0335;
+pub const MEAN_MOTION_URANUS: f64 = 0.0117;
+
+// ความเร็วรายวัน (องศา/วัน) ติดลบแปลว่าเดินถอยหลัง
+pub fn daily_motion(position: impl Fn(f64) -> f64, jd: f64) -> f64 {
+    daily_motion_between(position(jd - 0.5), position(jd + 0.5))
+}
+
+// เช่นเดียวกันจากตำแหน่ง ณ jd - 0.5 และ jd + 0.5 ที่คำนวณไว้แล้ว
+pub fn daily_motion_between(before: f64, after: f64) -> f64 {
+    let mut diff = after - before;
+    // ตัดรอยต่อ 360/0 องศา ให้อยู่ในช่วง -180..180
+    if diff > 180.0 { diff -= 360.0; }
+    if diff < -180.0 { diff += 360.0; }
+    diff
+}
+
+pub fn classify_motion(speed: f64, mean_motion: f64, thresholds: &MotionThresholds) -> MotionState {
+    if speed < 0.0 {
+        MotionState::Retrograde
+    } else if speed < mean_motion * thresholds.slow_ratio {
+        MotionState::Slow
+    } else if speed > mean_motion * thresholds.fast_ratio {
+        MotionState::Fast
+    } else {
+        MotionState::Normal
+    }
+}
+
+// รวมสองขั้นตอนข้างบนไว้ให้เรียกสั้นลง
+pub fn motion_state(position: impl Fn(f64) -> f64, jd: f64, mean_motion: f64, thresholds: &MotionThresholds) -> MotionState {
+    classify_motion(daily_motion(position, jd), mean_motion, thresholds)
+}
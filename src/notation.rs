@@ -0,0 +1,101 @@
+// รูปแบบเขียนตำแหน่งดาว
+//   ราศี.องศา.ลิปดา แบบปฏิทินโหร เช่น 5.15.23 = ราศีกันย์ (ลำดับ 5 นับเมษเป็น 0) 15 องศา 23 ลิปดา
+//   องศา-ลิปดา-ฟิลิปดา เช่น 15°23'07"
+// ตัดเศษทิ้ง (ไม่ปัดขึ้น) เหมือนในตำรา ตำแหน่งจึงไม่ข้ามไปราศีถัดไปตอนใกล้ 30 องศา
+
+use crate::locale::{Lang, Message};
+use crate::numerals::to_arabic_digits;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Notation {
+    Decimal, // องศาทศนิยม 15.38
+    Rasi,    // ราศี.องศา.ลิปดา 5.15.23
+    Dms,     // 15°23'07"
+}
+
+impl Notation {
+    pub fn from_key(key: &str) -> Option<Notation> {
+        match key {
+            "decimal" => Some(Notation::Decimal),
+            "rasi" => Some(Notation::Rasi),
+            "dms" => Some(Notation::Dms),
+            _ => None,
+        }
+    }
+
+    pub fn key(self) -> &'static str {
+        match self {
+            Notation::Decimal => "decimal",
+            Notation::Rasi => "rasi",
+            Notation::Dms => "dms",
+        }
+    }
+
+    // คำนำหน้าตัวเลขในตาราง (ราศี.องศา.ลิปดา บอกราศีอยู่แล้ว)
+    pub fn label(self, lang: Lang) -> &'static str {
+        match self {
+            Notation::Rasi => "",
+            _ => lang.text(Message::Degree),
+        }
+    }
+
+    // ตัวเลขตำแหน่งของลองจิจูด (องศาทศนิยมและ dms นับในราศี)
+    pub fn format(self, longitude: f64) -> String {
+        match self {
+            Notation::Decimal => format!("{:.2}", longitude % 30.0),
+            Notation::Rasi => format_rasi(longitude),
+            Notation::Dms => format_dms(longitude % 30.0),
+        }
+    }
+}
+
+pub fn format_rasi(longitude: f64) -> String {
+    let minutes = (longitude.rem_euclid(360.0) * 60.0).floor() as u32;
+    format!("{}.{:02}.{:02}", minutes / 1800, minutes % 1800 / 60, minutes % 60)
+}
+
+pub fn format_dms(degree: f64) -> String {
+    let seconds = (degree.abs() * 3600.0).floor() as u32;
+    let sign = if degree < 0.0 && seconds > 0 { "-" } else { "" };
+    format!("{}{}°{:02}'{:02}\"", sign, seconds / 3600, seconds % 3600 / 60, seconds % 60)
+}
+
+// ราศี.องศา.ลิปดา เป็นลองจิจูด รับเลขไทย เช่น ๕.๑๕.๒๓
+pub fn parse_rasi(text: &str) -> Option<f64> {
+    let text = to_arabic_digits(text.trim());
+    let parts: Vec<u32> = text.split('.').map(|p| p.parse().ok()).collect::<Option<_>>()?;
+    let [sign, degree, minute] = parts[..] else { return None };
+    if sign >= 12 || degree >= 30 || minute >= 60 { return None; }
+    Some(sign as f64 * 30.0 + degree as f64 + minute as f64 / 60.0)
+}
+
+// องศา ลิปดา ฟิลิปดา คั่นด้วย ° ' " หรือ : หรือช่องว่าง เช่น 165°23'07" / 165:23:07 / 165 23
+pub fn parse_dms(text: &str) -> Option<f64> {
+    let text = to_arabic_digits(text.trim());
+    let (negative, text) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest.to_string()),
+        None => (false, text),
+    };
+    let parts: Vec<&str> = text
+        .split(['°', '\'', '"', ':', ' '])
+        .filter(|p| !p.is_empty())
+        .collect();
+    if parts.is_empty() || parts.len() > 3 { return None; }
+
+    let degree: f64 = parts[0].parse().ok()?;
+    let minute: f64 = parts.get(1).map_or(Some(0.0), |p| p.parse().ok())?;
+    let second: f64 = parts.get(2).map_or(Some(0.0), |p| p.parse().ok())?;
+    if minute >= 60.0 || second >= 60.0 { return None; }
+
+    let value = degree + minute / 60.0 + second / 3600.0;
+    Some(if negative { -value } else { value })
+}
+
+// ตำแหน่งที่ป้อนเอง: ราศี.องศา.ลิปดา หรือ องศา-ลิปดา-ฟิลิปดา นับจาก 0 เมษ
+// ข้อความที่มีจุดถือเป็นราศี.องศา.ลิปดาเสมอ 5.15 จึงไม่ถูกอ่านเป็น 5.15 องศา
+pub fn parse_position(text: &str) -> Option<f64> {
+    if text.contains('.') {
+        return parse_rasi(text);
+    }
+    parse_dms(text).filter(|lon| (0.0..360.0).contains(lon))
+}
@@ -0,0 +1,22 @@
+// แปลงเลขอารบิก <-> เลขไทย (๐-๙)
+
+const THAI_DIGITS: [char; 10] = ['๐', '๑', '๒', '๓', '๔', '๕', '๖', '๗', '๘', '๙'];
+
+pub fn to_thai_digits(text: &str) -> String {
+    text.chars()
+        .map(|c| match c.to_digit(10) {
+            Some(d) if c.is_ascii_digit() => THAI_DIGITS[d as usize],
+            _ => c,
+        })
+        .collect()
+}
+
+// เลขไทยในข้อความกลายเป็นเลขอารบิก ตัวอักษรอื่นคงเดิม
+pub fn to_arabic_digits(text: &str) -> String {
+    text.chars()
+        .map(|c| match THAI_DIGITS.iter().position(|&t| t == c) {
+            Some(d) => char::from(b'0' + d as u8),
+            None => c,
+        })
+        .collect()
+}
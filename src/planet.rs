@@ -0,0 +1,115 @@
+// ดาวทั้ง ๑๐ ดวงของโหราศาสตร์ไทย (อาทิตย์ ๑ ... มฤตยู ๐)
+// รวมชื่อ เลขประจำดาว และตัวเรียกฟังก์ชันตำแหน่งดาวไว้ที่เดียว
+
+use crate::motion;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Planet {
+    Sun,
+    Moon,
+    Mars,
+    Mercury,
+    Jupiter,
+    Venus,
+    Saturn,
+    Rahu,
+    Ketu,
+    Uranus,
+}
+
+impl Planet {
+    // เรียงตามเลขประจำดาว ๑-๙ และ ๐
+    pub const ALL: [Planet; 10] = [
+        Planet::Sun,
+        Planet::Moon,
+        Planet::Mars,
+        Planet::Mercury,
+        Planet::Jupiter,
+        Planet::Venus,
+        Planet::Saturn,
+        Planet::Rahu,
+        Planet::Ketu,
+        Planet::Uranus,
+    ];
+
+    pub fn thai_name(self) -> &'static str {
+        match self {
+            Planet::Sun => "อาทิตย์",
+            Planet::Moon => "จันทร์",
+            Planet::Mars => "อังคาร",
+            Planet::Mercury => "พุธ",
+            Planet::Jupiter => "พฤหัสบดี",
+            Planet::Venus => "ศุกร์",
+            Planet::Saturn => "เสาร์",
+            Planet::Rahu => "ราหู",
+            Planet::Ketu => "เกตุ",
+            Planet::Uranus => "มฤตยู",
+        }
+    }
+
+    // เลขไทยประจำดาวที่ใช้วางในดวง
+    pub fn numeral(self) -> &'static str {
+        match self {
+            Planet::Sun => "๑",
+            Planet::Moon => "๒",
+            Planet::Mars => "๓",
+            Planet::Mercury => "๔",
+            Planet::Jupiter => "๕",
+            Planet::Venus => "๖",
+            Planet::Saturn => "๗",
+            Planet::Rahu => "๘",
+            Planet::Ketu => "๙",
+            Planet::Uranus => "๐",
+        }
+    }
+
+    // ชื่ออังกฤษตัวเล็ก ใช้เป็นคีย์ในบรรทัดคำสั่ง
+    pub fn key(self) -> &'static str {
+        match self {
+            Planet::Sun => "sun",
+            Planet::Moon => "moon",
+            Planet::Mars => "mars",
+            Planet::Mercury => "mercury",
+            Planet::Jupiter => "jupiter",
+            Planet::Venus => "venus",
+            Planet::Saturn => "saturn",
+            Planet::Rahu => "rahu",
+            Planet::Ketu => "ketu",
+            Planet::Uranus => "uranus",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Planet> {
+        Planet::ALL.iter().copied().find(|p| p.key() == key)
+    }
+
+    // ลองจิจูดของดาว ณ Julian Day (เวลาไทย)
+    pub fn longitude(self, jd: f64) -> f64 {
+        match self {
+            Planet::Sun => crate::calculate_sun_position(jd),
+            Planet::Moon => crate::calculate_moon_position(jd),
+            Planet::Mars => crate::calculate_mars_position(jd),
+            Planet::Mercury => crate::calculate_mercury_position(jd, crate::calculate_sun_position(jd)),
+            Planet::Jupiter => crate::calculate_jupiter_position(jd),
+            Planet::Venus => crate::calculate_venus_position(jd, crate::calculate_sun_position(jd)),
+            Planet::Saturn => crate::calculate_saturn_position(jd),
+            Planet::Rahu => crate::calculate_rahu_position(jd),
+            Planet::Ketu => crate::calculate_ketu_thai_position(jd),
+            Planet::Uranus => crate::calculate_uranus_position(jd),
+        }
+    }
+
+    // ความเร็วเฉลี่ยสำหรับจัดสถานะพักร์/มนฑ์/เสริด
+    // อาทิตย์ จันทร์ ราหู เกตุ ไม่มีพักร์ จึงไม่จัดสถานะ
+    pub fn mean_motion(self) -> Option<f64> {
+        match self {
+            Planet::Mars => Some(motion::MEAN_MOTION_MARS),
+            Planet::Mercury => Some(motion::MEAN_MOTION_MERCURY),
+            Planet::Jupiter => Some(motion::MEAN_MOTION_JUPITER),
+            Planet::Venus => Some(motion::MEAN_MOTION_VENUS),
+            Planet::Saturn => Some(motion::MEAN_MOTION_SATURN),
+            Planet::Uranus => Some(motion::MEAN_MOTION_URANUS),
+            _ => None,
+        }
+    }
+}
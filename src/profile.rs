@@ -0,0 +1,302 @@
+// แฟ้มลูกดวง: เก็บชื่อ วันเวลาเกิด สถานที่ และตัวเลือกการคำนวณ ไว้เรียกดูดวงซ้ำได้
+// เก็บเป็นไฟล์ JSON ไฟล์เดียว มีเลขรุ่นของรูปแบบกำกับ เปิดอ่านหรือแก้ด้วยมือได้
+//   {
+//     "format": "horathai-profiles",
+//     "version": 1,
+//     "next_id": 2,
+//     "profiles": [
+//       {
+//         "id": 1,
+//         "name": "สมชาย ใจดี",
+//         "birth": { "date": "3/10/2530", "time": "15:30" },
+//         "place": { "province": "bangkok" }   หรือ { "latitude": 13.75, "longitude": 100.5 }
+//         "options": { "notation": "rasi", "slow_ratio": 0.5, "fast_ratio": 1.5 },
+//         "note": ""
+//       }
+//     ]
+//   }
+// วันที่เขียนเป็น วัน/เดือน/ปี พ.ศ. เสมอ เมื่อเปลี่ยนรูปแบบให้เพิ่ม VERSION และแปลงรุ่นเก่าใน from_json
+// next_id คือเลขที่ของโปรไฟล์ถัดไป ไฟล์ที่ไม่มีช่องนี้ (บันทึกก่อนมีช่องนี้) นับต่อจากเลขที่มากที่สุด
+
+use std::path::{Path, PathBuf};
+
+use crate::json::{self, Value};
+use crate::locale::{Lang, Message};
+use crate::motion::MotionThresholds;
+use crate::notation::Notation;
+use crate::thaidate;
+use crate::{find_province, Error, Province};
+
+pub const FORMAT: &str = "horathai-profiles";
+pub const VERSION: u32 = 1;
+
+// ไม่ระบุ --store ใช้ $HORATHAI_PROFILES หรือ ~/.horathai/profiles.json
+pub fn default_path() -> PathBuf {
+    if let Some(path) = std::env::var_os("HORATHAI_PROFILES") {
+        return PathBuf::from(path);
+    }
+    match std::env::var_os("HOME") {
+        Some(home) => Path::new(&home).join(".horathai").join("profiles.json"),
+        None => PathBuf::from("horathai-profiles.json"),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Place {
+    Province(String), // ชื่อใน provinces() เช่น bangkok
+    Coordinates { latitude: f64, longitude: f64 },
+}
+
+impl Place {
+    // สำหรับแจ้งข้อผิดพลาดเมื่อหาจังหวัดไม่พบ
+    pub fn describe(&self) -> String {
+        match self {
+            Place::Province(key) => key.clone(),
+            Place::Coordinates { latitude, longitude } => format!("{}, {}", latitude, longitude),
+        }
+    }
+
+    pub fn resolve(&self) -> Result<Province, Error> {
+        match self {
+            Place::Province(key) => find_province(key),
+            &Place::Coordinates { latitude, longitude } => Ok(Province { name: "", english_name: "", latitude, longitude }),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Profile {
+    pub id: u32,
+    pub name: String,
+    pub date: (u32, u32, u32), // วัน เดือน ปี พ.ศ.
+    pub time: (u32, u32),
+    pub place: Place,
+    pub notation: Notation,
+    pub thresholds: MotionThresholds,
+    pub note: String,
+}
+
+impl Profile {
+    pub fn date_text(&self) -> String {
+        let (d, m, y) = self.date;
+        format!("{}/{}/{}", d, m, y)
+    }
+
+    pub fn time_text(&self) -> String {
+        format!("{:02}:{:02}", self.time.0, self.time.1)
+    }
+
+    // ค้นจากชื่อ หมายเหตุ วันเกิด หรือชื่อจังหวัด (ไม่สนตัวพิมพ์เล็ก/ใหญ่)
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.to_lowercase();
+        let mut fields = vec![self.name.clone(), self.note.clone(), self.date_text()];
+        if let Place::Province(key) = &self.place {
+            fields.push(key.clone());
+            if let Ok(province) = self.place.resolve() {
+                fields.push(province.name.to_string());
+                fields.push(province.english_name.to_string());
+            }
+        }
+        fields.iter().any(|field| field.to_lowercase().contains(&query))
+    }
+
+    pub fn to_json(&self) -> Value {
+        let place = match &self.place {
+            Place::Province(key) => Value::object([("province", Value::from(key.as_str()))]),
+            Place::Coordinates { latitude, longitude } => Value::object([
+                ("latitude", Value::from(*latitude)),
+                ("longitude", Value::from(*longitude)),
+            ]),
+        };
+        Value::object([
+            ("id", Value::from(self.id)),
+            ("name", Value::from(self.name.as_str())),
+            ("birth", Value::object([
+                ("date", Value::from(self.date_text())),
+                ("time", Value::from(self.time_text())),
+            ])),
+            ("place", place),
+            ("options", Value::object([
+                ("notation", Value::from(self.notation.key())),
+                ("slow_ratio", Value::from(self.thresholds.slow_ratio)),
+                ("fast_ratio", Value::from(self.thresholds.fast_ratio)),
+            ])),
+            ("note", Value::from(self.note.as_str())),
+        ])
+    }
+
+    // field คือชื่อที่ใช้บอกตำแหน่งเมื่อผิดพลาด เช่น profiles[2]
+    fn from_json(value: &Value, field: &str) -> Result<Profile, StoreError> {
+        let invalid = |name: &str| StoreError::InvalidField(format!("{}.{}", field, name));
+        let text = |value: Option<&Value>, name: &str| value.and_then(Value::as_str).map(str::to_string).ok_or_else(|| invalid(name));
+
+        let id = value.get("id").and_then(Value::as_f64)
+            .filter(|id| id.fract() == 0.0 && (1.0..=u32::MAX as f64).contains(id))
+            .ok_or_else(|| invalid("id"))? as u32;
+        let name = text(value.get("name"), "name")?;
+        let birth = value.get("birth");
+        let date = thaidate::parse_date(&text(birth.and_then(|b| b.get("date")), "birth.date")?)
+            .map_err(|_| invalid("birth.date"))?;
+        let time = thaidate::parse_time(&text(birth.and_then(|b| b.get("time")), "birth.time")?)
+            .map_err(|_| invalid("birth.time"))?;
+
+        let place = value.get("place").ok_or_else(|| invalid("place"))?;
+        let place = match place.get("province") {
+            Some(key) => Place::Province(text(Some(key), "place.province")?),
+            None => {
+                let latitude = place.get("latitude").and_then(Value::as_f64).filter(|v| v.abs() <= 90.0);
+                let longitude = place.get("longitude").and_then(Value::as_f64).filter(|v| v.abs() <= 180.0);
+                Place::Coordinates {
+                    latitude: latitude.ok_or_else(|| invalid("place.latitude"))?,
+                    longitude: longitude.ok_or_else(|| invalid("place.longitude"))?,
+                }
+            }
+        };
+
+        // ตัวเลือกที่ไม่มีในไฟล์ใช้ค่าปกติ
+        let options = value.get("options");
+        let option = |name: &str| options.and_then(|o| o.get(name));
+        let notation = match option("notation") {
+            None => Notation::Decimal,
+            Some(key) => key.as_str().and_then(Notation::from_key).ok_or_else(|| invalid("options.notation"))?,
+        };
+        // เกณฑ์เดียวกับ --slow-ratio/--fast-ratio: ไม่ติดลบ และมนฑ์ต่ำกว่าเสริด
+        let ratio = |name: &str, default: f64| match option(name) {
+            None => Ok(default),
+            Some(ratio) => ratio.as_f64().filter(|v| v.is_finite() && *v >= 0.0).ok_or_else(|| invalid(&format!("options.{}", name))),
+        };
+        let defaults = MotionThresholds::default();
+        let thresholds = MotionThresholds {
+            slow_ratio: ratio("slow_ratio", defaults.slow_ratio)?,
+            fast_ratio: ratio("fast_ratio", defaults.fast_ratio)?,
+        };
+        if !thresholds.is_valid() {
+            return Err(invalid("options"));
+        }
+        let note = match value.get("note") {
+            None => String::new(),
+            Some(note) => text(Some(note), "note")?,
+        };
+
+        Ok(Profile { id, name, date, time, place, notation, thresholds, note })
+    }
+}
+
+#[derive(Debug)]
+pub enum StoreError {
+    Read(std::io::Error),
+    InvalidJson(json::ParseError),
+    NotProfileFile,
+    UnsupportedVersion(u32),
+    InvalidField(String),
+    DuplicateId(u32),
+}
+
+impl StoreError {
+    pub fn message(&self, lang: Lang, path: &Path) -> String {
+        let path = path.display();
+        match self {
+            StoreError::Read(err) => lang.format(Message::ReadFailed, &[&path, err]),
+            StoreError::InvalidJson(err) => lang.format(Message::InvalidJson, &[&path, &err.position]),
+            StoreError::NotProfileFile => lang.format(Message::NotProfileFile, &[&path]),
+            StoreError::UnsupportedVersion(version) => lang.format(Message::ProfileVersion, &[&path, version, &VERSION]),
+            StoreError::InvalidField(field) => lang.format(Message::ProfileField, &[&path, field]),
+            StoreError::DuplicateId(id) => lang.format(Message::ProfileField, &[&path, &format!("id {}", id)]),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct Store {
+    pub profiles: Vec<Profile>,
+    next_id: u32, // 0 คือยังไม่เคยใส่ ใช้เลขที่มากที่สุด + 1
+}
+
+impl Store {
+    // ยังไม่มีไฟล์ถือเป็นแฟ้มว่าง
+    pub fn load(path: &Path) -> Result<Store, StoreError> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => Store::from_json(&json::parse(&text).map_err(StoreError::InvalidJson)?),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Store::default()),
+            Err(err) => Err(StoreError::Read(err)),
+        }
+    }
+
+    // เขียนไฟล์ชั่วคราวก่อนแล้วจึงแทนที่ ไฟล์เดิมจะไม่เสียถ้าเขียนไม่สำเร็จ
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir)?;
+        }
+        let temporary = path.with_extension("json.tmp");
+        std::fs::write(&temporary, self.to_json().pretty() + "\n")?;
+        std::fs::rename(&temporary, path)
+    }
+
+    pub fn to_json(&self) -> Value {
+        Value::object([
+            ("format", Value::from(FORMAT)),
+            ("version", Value::from(VERSION)),
+            ("next_id", Value::from(self.next_id())),
+            ("profiles", Value::Array(self.profiles.iter().map(Profile::to_json).collect())),
+        ])
+    }
+
+    pub fn from_json(value: &Value) -> Result<Store, StoreError> {
+        if value.get("format").and_then(Value::as_str) != Some(FORMAT) {
+            return Err(StoreError::NotProfileFile);
+        }
+        let version = value.get("version").and_then(Value::as_f64).ok_or(StoreError::InvalidField("version".to_string()))?;
+        if version != VERSION as f64 {
+            return Err(StoreError::UnsupportedVersion(version as u32));
+        }
+        let items = value.get("profiles").and_then(Value::as_array).ok_or(StoreError::InvalidField("profiles".to_string()))?;
+
+        let next_id = match value.get("next_id") {
+            None => 0,
+            Some(id) => id.as_f64()
+                .filter(|id| id.fract() == 0.0 && (1.0..=u32::MAX as f64).contains(id))
+                .ok_or(StoreError::InvalidField("next_id".to_string()))? as u32,
+        };
+
+        let mut store = Store { profiles: Vec::new(), next_id };
+        for (i, item) in items.iter().enumerate() {
+            let profile = Profile::from_json(item, &format!("profiles[{}]", i))?;
+            if store.get(profile.id).is_some() {
+                return Err(StoreError::DuplicateId(profile.id));
+            }
+            store.profiles.push(profile);
+        }
+        Ok(store)
+    }
+
+    // เลขที่ใหม่ไม่นำเลขที่ลบไปแล้วกลับมาใช้ และไม่ต่ำกว่าเลขที่ที่มีอยู่ (เผื่อแก้ไฟล์ด้วยมือ)
+    fn next_id(&self) -> u32 {
+        let largest = self.profiles.iter().map(|p| p.id).max().unwrap_or(0);
+        self.next_id.max(largest.saturating_add(1))
+    }
+
+    pub fn add(&mut self, mut profile: Profile) -> u32 {
+        let id = self.next_id();
+        self.next_id = id.saturating_add(1);
+        profile.id = id;
+        self.profiles.push(profile);
+        id
+    }
+
+    pub fn get(&self, id: u32) -> Option<&Profile> {
+        self.profiles.iter().find(|p| p.id == id)
+    }
+
+    pub fn get_mut(&mut self, id: u32) -> Option<&mut Profile> {
+        self.profiles.iter_mut().find(|p| p.id == id)
+    }
+
+    pub fn remove(&mut self, id: u32) -> Option<Profile> {
+        let index = self.profiles.iter().position(|p| p.id == id)?;
+        Some(self.profiles.remove(index))
+    }
+
+    pub fn search<'a>(&'a self, query: &'a str) -> impl Iterator<Item = &'a Profile> {
+        self.profiles.iter().filter(move |p| p.matches(query))
+    }
+}
@@ -0,0 +1,173 @@
+// โมดูล Python สำหรับงานวิเคราะห์สถิติดวง (build เมื่อเปิด feature python เท่านั้น)
+//   pip install maturin && maturin develop --release
+//   หรือ cargo build --release --features python แล้วคัดลอก target/release/libhorathai.so เป็น horathai.so
+//
+//   >>> import horathai
+//   >>> horathai.chart(3, 10, 2530, 15, 30, province="chiangmai")["lagna"]["sign"]
+//   >>> jds = [horathai.julian_day(1, 1, y) for y in range(2400, 2600)]
+//   >>> horathai.planet_longitudes("saturn", jds)
+//
+// คืนค่าเป็น dict list float ของ Python โดยตรง ข้อมูลไม่ถูกต้องได้ ValueError
+// chart() คืนโครงสร้างเดียวกับ /chart ของบริการ HTTP
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+use crate::json::Value;
+use crate::locale::Lang;
+use crate::motion::MotionThresholds;
+use crate::notation::Notation;
+use crate::planet::Planet;
+use crate::{calculate_jd_astronomical, calculate_thai_ascendant, find_province, jd_to_astronomical_date, provinces as province_table, Error, Province, SolarState, MIN_YEAR_BE};
+
+fn value_error(message: String) -> PyErr {
+    PyValueError::new_err(message)
+}
+
+fn calculation_error(err: Error, lang: Lang) -> PyErr {
+    value_error(err.message(lang))
+}
+
+// แปลงค่า JSON ที่ใช้ร่วมกับบริการ HTTP เป็นวัตถุ Python
+fn to_python<'py>(py: Python<'py>, value: &Value) -> PyResult<Bound<'py, PyAny>> {
+    Ok(match value {
+        Value::Null => py.None().into_bound(py),
+        Value::Bool(value) => value.into_pyobject(py)?.to_owned().into_any(),
+        Value::Number(number) => number.into_pyobject(py)?.into_any(),
+        Value::String(text) => text.into_pyobject(py)?.into_any(),
+        Value::Array(items) => {
+            let list = PyList::empty(py);
+            for item in items {
+                list.append(to_python(py, item)?)?;
+            }
+            list.into_any()
+        }
+        Value::Object(fields) => {
+            let dict = PyDict::new(py);
+            for (key, value) in fields {
+                dict.set_item(key, to_python(py, value)?)?;
+            }
+            dict.into_any()
+        }
+    })
+}
+
+fn planet(key: &str) -> PyResult<Planet> {
+    Planet::from_key(key).ok_or_else(|| value_error(format!("unknown planet: {} ({})", key, planet_keys().join(", "))))
+}
+
+fn planet_keys() -> Vec<&'static str> {
+    Planet::ALL.iter().map(|p| p.key()).collect()
+}
+
+// ดวงชะตาจากวันเวลาเกิด (ปี พ.ศ. เวลาไทย) ที่จังหวัดหรือพิกัดที่ระบุ (ไม่ระบุใช้กรุงเทพฯ)
+#[pyfunction]
+#[pyo3(signature = (day, month, year_be, hour = 6, minute = 0, province = None, latitude = None, longitude = None, notation = "decimal", lang = "th"))]
+#[allow(clippy::too_many_arguments)]
+fn chart<'py>(
+    py: Python<'py>,
+    day: u32,
+    month: u32,
+    year_be: u32,
+    hour: u32,
+    minute: u32,
+    province: Option<&str>,
+    latitude: Option<f64>,
+    longitude: Option<f64>,
+    notation: &str,
+    lang: &str,
+) -> PyResult<Bound<'py, PyAny>> {
+    let lang = Lang::from_key(lang).ok_or_else(|| value_error(format!("unknown language: {} (th, en, rtgs)", lang)))?;
+    let notation = Notation::from_key(notation).ok_or_else(|| value_error(format!("unknown notation: {} (decimal, rasi, dms)", notation)))?;
+    let place = match (latitude, longitude) {
+        (Some(latitude), Some(longitude)) if latitude.abs() <= 90.0 && longitude.abs() <= 180.0 => {
+            Province { name: "", english_name: "", latitude, longitude }
+        }
+        (None, None) => {
+            find_province(province.unwrap_or("bangkok")).map_err(|err| calculation_error(err, lang))?
+        }
+        _ => return Err(value_error("latitude (-90..90) and longitude (-180..180) must be given together".to_string())),
+    };
+    let result = calculate_thai_ascendant(day, month, year_be, hour, minute, &place, &MotionThresholds::default())
+        .map_err(|err| calculation_error(err, lang))?;
+    to_python(py, &result.to_json(&place, (day, month, year_be, hour, minute), notation, lang))
+}
+
+// Julian Day ตามเวลาไทย (UTC+7) ที่ฟังก์ชันตำแหน่งดาวใช้
+// ปี พ.ศ. ติดลบได้ (ก่อน พ.ศ. 0) ก่อน 15 ต.ค. 2125 เป็นปฏิทินจูเลียน
+#[pyfunction]
+#[pyo3(signature = (day, month, year_be, hour = 0, minute = 0))]
+fn julian_day(day: u32, month: u32, year_be: i64, hour: u32, minute: u32) -> PyResult<f64> {
+    i32::try_from(year_be.saturating_sub(543))
+        .map_err(|_| Error::YearOutOfRange { year_be })
+        .and_then(|year| calculate_jd_astronomical(day, month, year, hour, minute))
+        .map_err(|err| calculation_error(err, Lang::English))
+}
+
+// Julian Day กลับเป็นวันเวลา ปัดเป็นนาทีที่ใกล้ที่สุด
+#[pyfunction]
+fn date_from_jd<'py>(py: Python<'py>, jd: f64) -> PyResult<Bound<'py, PyDict>> {
+    let first = julian_day(1, 1, MIN_YEAR_BE, 0, 0)?;
+    if !jd.is_finite() || jd < first {
+        return Err(value_error(format!("julian day out of range: {}", jd)));
+    }
+    let (day, month, year, hour, minute) = jd_to_astronomical_date(jd);
+    let dict = PyDict::new(py);
+    for (key, value) in [("day", day as i64), ("month", month as i64), ("year_be", year as i64 + 543), ("hour", hour as i64), ("minute", minute as i64)] {
+        dict.set_item(key, value)?;
+    }
+    Ok(dict)
+}
+
+#[pyfunction]
+fn planet_longitude(planet_key: &str, jd: f64) -> PyResult<f64> {
+    Ok(planet(planet_key)?.longitude(jd))
+}
+
+// ลองจิจูดของดาวดวงเดียวหลาย Julian Day ในครั้งเดียว (ไม่ถือ GIL ระหว่างคำนวณ)
+#[pyfunction]
+fn planet_longitudes(py: Python<'_>, planet_key: &str, jds: Vec<f64>) -> PyResult<Vec<f64>> {
+    let planet = planet(planet_key)?;
+    Ok(py.detach(|| jds.iter().map(|&jd| planet.longitude(jd)).collect()))
+}
+
+// ลองจิจูดของดาวทุกดวง ณ Julian Day เดียว {"sun": ..., "moon": ..., ...}
+#[pyfunction]
+fn positions<'py>(py: Python<'py>, jd: f64) -> PyResult<Bound<'py, PyDict>> {
+    let dict = PyDict::new(py);
+    for (planet, longitude) in Planet::ALL.iter().zip(SolarState::at(jd).longitudes()) {
+        dict.set_item(planet.key(), longitude)?;
+    }
+    Ok(dict)
+}
+
+#[pyfunction]
+fn provinces<'py>(py: Python<'py>) -> PyResult<Bound<'py, PyList>> {
+    let mut table: Vec<(&str, Province)> = province_table().into_iter().collect();
+    table.sort_by_key(|(key, _)| *key);
+    let list = PyList::empty(py);
+    for (key, province) in table {
+        let dict = PyDict::new(py);
+        dict.set_item("key", key)?;
+        dict.set_item("name", province.name)?;
+        dict.set_item("english_name", province.english_name)?;
+        dict.set_item("latitude", province.latitude)?;
+        dict.set_item("longitude", province.longitude)?;
+        list.append(dict)?;
+    }
+    Ok(list)
+}
+
+#[pymodule]
+fn horathai(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add("PLANETS", planet_keys())?;
+    module.add_function(wrap_pyfunction!(chart, module)?)?;
+    module.add_function(wrap_pyfunction!(julian_day, module)?)?;
+    module.add_function(wrap_pyfunction!(date_from_jd, module)?)?;
+    module.add_function(wrap_pyfunction!(planet_longitude, module)?)?;
+    module.add_function(wrap_pyfunction!(planet_longitudes, module)?)?;
+    module.add_function(wrap_pyfunction!(positions, module)?)?;
+    module.add_function(wrap_pyfunction!(provinces, module)?)?;
+    Ok(())
+}
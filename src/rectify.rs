@@ -0,0 +1,483 @@
+// ตั้งเวลาเกิด (rectification): ผู้ที่รู้เวลาเกิดคร่าว ๆ เช่น "ราว 9-10 โมงเช้า"
+// ลองเวลาเกิดทีละช่วงในกรอบเวลา คำนวณลัคนาและภพของแต่ละเวลา แล้วให้คะแนนว่าเข้ากับเหตุการณ์ที่รู้วันที่แน่นอนเพียงใด
+//
+// เหตุการณ์แต่ละชนิดดูภพของตัวเอง: แต่งงาน = ปัตนิ, มีบุตร = ปุตตะ, เปลี่ยนงาน = กัมมะ
+// คะแนนมาจากสองทาง
+//   ดาวจร: พฤหัสบดี เสาร์ ราหู ในวันเกิดเหตุ ทับ/เล็ง/ตรีโกณราศีภพนั้น หรือทับ/เล็งดาวเจ้าเรือนภพนั้นในดวงกำเนิด
+//          และใกล้องศาต้นภพ (ลัคนาบวกทีละ 30 องศา) ภายใน CUSP_ORB องศา ส่วนนี้แยกเวลาที่ลัคนาราศีเดียวกันออกจากกันได้
+//   มหาทักษา: ดาวเสวยอายุและดาวแทรกในวันเกิดเหตุ เป็นภูมิทักษาที่ตรงกับเหตุการณ์ (ศรี บริวาร เดช) นับจากดาววันเกิด
+//          อยู่ในราศีภพนั้น หรือเป็นเจ้าเรือนภพนั้นในดวงกำเนิด
+// ดาววันเกิดเปลี่ยนเฉพาะเมื่อกรอบเวลาคร่อมอาทิตย์ขึ้นหรือค่ำวันพุธ ภายในวันเดียวกันมหาทักษาจึงแยกเวลาได้ผ่านภพเป็นหลัก
+// คะแนนเป็นเพียงการเรียงลำดับเวลาที่น่าจะเป็น ไม่ใช่ความน่าจะเป็น
+
+use crate::json::Value;
+use crate::locale::{Lang, Message};
+use crate::motion::MotionThresholds;
+use crate::planet::Planet;
+use crate::songkran::YEAR_DAYS;
+use crate::table::{Align, Table};
+use crate::transit::Relation;
+use crate::{calculate_jd, calculate_thai_ascendant, csv, thaidate, Error, Province, SolarState};
+
+// ดาวจรที่ใช้ให้คะแนน: ดาวที่เดินช้าพอให้วันเกิดเหตุคลาดไปสองสามวันแล้วยังได้ผลเดิม
+pub const TRANSIT_PLANETS: [Planet; 3] = [Planet::Jupiter, Planet::Saturn, Planet::Rahu];
+
+// ระยะห่างจากองศาต้นภพที่ยังให้คะแนน (องศา)
+pub const CUSP_ORB: f64 = 5.0;
+
+// ลำดับทักษาและอายุดาว (ปี) รวม ๑๐๘ ปี
+pub const THAKSA: [(Planet, u32); 8] = [
+    (Planet::Sun, 6),
+    (Planet::Moon, 15),
+    (Planet::Mars, 8),
+    (Planet::Mercury, 17),
+    (Planet::Saturn, 10),
+    (Planet::Jupiter, 19),
+    (Planet::Rahu, 12),
+    (Planet::Venus, 21),
+];
+
+const THAKSA_YEARS: u32 = 108;
+
+// ดาวเจ้าเรือนของราศีเมษ (0) ถึงมีน (11) กุมภ์ใช้เสาร์ (ตำราที่ให้ราหูครองกุมภ์ร่วมด้วยไม่นับในที่นี้)
+pub const SIGN_LORDS: [Planet; 12] = [
+    Planet::Mars, Planet::Venus, Planet::Mercury, Planet::Moon, Planet::Sun, Planet::Mercury,
+    Planet::Venus, Planet::Mars, Planet::Jupiter, Planet::Saturn, Planet::Saturn, Planet::Jupiter,
+];
+
+// ภูมิทักษา นับจากดาววันเกิดตามลำดับทักษา
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Bhumi {
+    Boriwan,  // บริวาร: ลูก คู่ครอง ผู้อยู่ใต้ปกครอง
+    Ayu,      // อายุ: สุขภาพ ความเป็นอยู่
+    Det,      // เดช: อำนาจ ยศ ตำแหน่ง
+    Si,       // ศรี: โชคลาภ ความรัก
+    Mula,     // มูละ: ทรัพย์ที่ดิน รากฐาน
+    Utsaha,   // อุตสาหะ: การงาน ความเพียร
+    Montri,   // มนตรี: ผู้อุปถัมภ์
+    Kalakini, // กาลกิณี: อุปสรรค
+}
+
+impl Bhumi {
+    const ALL: [Bhumi; 8] = [
+        Bhumi::Boriwan, Bhumi::Ayu, Bhumi::Det, Bhumi::Si,
+        Bhumi::Mula, Bhumi::Utsaha, Bhumi::Montri, Bhumi::Kalakini,
+    ];
+
+    pub fn thai_name(self) -> &'static str {
+        match self {
+            Bhumi::Boriwan => "บริวาร",
+            Bhumi::Ayu => "อายุ",
+            Bhumi::Det => "เดช",
+            Bhumi::Si => "ศรี",
+            Bhumi::Mula => "มูละ",
+            Bhumi::Utsaha => "อุตสาหะ",
+            Bhumi::Montri => "มนตรี",
+            Bhumi::Kalakini => "กาลกิณี",
+        }
+    }
+
+    pub fn key(self) -> &'static str {
+        match self {
+            Bhumi::Boriwan => "boriwan",
+            Bhumi::Ayu => "ayu",
+            Bhumi::Det => "det",
+            Bhumi::Si => "si",
+            Bhumi::Mula => "mula",
+            Bhumi::Utsaha => "utsaha",
+            Bhumi::Montri => "montri",
+            Bhumi::Kalakini => "kalakini",
+        }
+    }
+}
+
+fn thaksa_index(planet: Planet) -> usize {
+    THAKSA.iter().position(|&(p, _)| p == planet).expect("เกตุและมฤตยูไม่มีในทักษา")
+}
+
+// ภูมิของดาวเมื่อนับจากดาววันเกิด
+pub fn bhumi(birth: Planet, planet: Planet) -> Bhumi {
+    Bhumi::ALL[(thaksa_index(planet) + 8 - thaksa_index(birth)) % 8]
+}
+
+// ดาววันเกิด: วันทางโหราศาสตร์ (เปลี่ยนตอนอาทิตย์ขึ้น) พุธกลางคืนเป็นราหู
+pub fn birth_planet(thai_weekday: usize, rahu: bool) -> Planet {
+    match thai_weekday {
+        _ if rahu => Planet::Rahu,
+        0 => Planet::Sun,
+        1 => Planet::Moon,
+        2 => Planet::Mars,
+        3 => Planet::Mercury,
+        4 => Planet::Jupiter,
+        5 => Planet::Venus,
+        _ => Planet::Saturn,
+    }
+}
+
+// ดาวเสวยอายุและดาวแทรก (อันตรทักษา) เมื่ออายุครบ years ปี
+// เริ่มเสวยดาววันเกิดเต็มอายุตั้งแต่แรกเกิด ครบ ๑๐๘ ปีวนกลับมาใหม่
+// ดาวแทรกแบ่งอายุดาวเสวยออกเป็นแปดช่วงตามลำดับทักษา เริ่มจากดาวเสวยเอง ช่วงละ อายุดาวเสวย x อายุดาวแทรก / ๑๐๘
+pub fn maha_thaksa(birth: Planet, years: f64) -> (Planet, Planet) {
+    let start = thaksa_index(birth);
+    let mut elapsed = years.rem_euclid(THAKSA_YEARS as f64);
+    for i in 0..8 {
+        let (major, major_years) = THAKSA[(start + i) % 8];
+        if elapsed >= major_years as f64 && i < 7 {
+            elapsed -= major_years as f64;
+            continue;
+        }
+        for j in 0..8 {
+            let (minor, minor_years) = THAKSA[(start + i + j) % 8];
+            let length = (major_years * minor_years) as f64 / THAKSA_YEARS as f64;
+            if elapsed < length || j == 7 {
+                return (major, minor);
+            }
+            elapsed -= length;
+        }
+    }
+    unreachable!()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EventKind {
+    Marriage,
+    Child,
+    Career,
+}
+
+impl EventKind {
+    pub const ALL: [EventKind; 3] = [EventKind::Marriage, EventKind::Child, EventKind::Career];
+
+    pub fn key(self) -> &'static str {
+        match self {
+            EventKind::Marriage => "marriage",
+            EventKind::Child => "child",
+            EventKind::Career => "career",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<EventKind> {
+        match key {
+            "job" => Some(EventKind::Career),
+            _ => EventKind::ALL.into_iter().find(|kind| kind.key() == key),
+        }
+    }
+
+    pub fn thai_name(self) -> &'static str {
+        match self {
+            EventKind::Marriage => "แต่งงาน",
+            EventKind::Child => "มีบุตร",
+            EventKind::Career => "เปลี่ยนงาน",
+        }
+    }
+
+    // ภพที่ดู นับจากลัคนา (0 = ตนุ)
+    pub fn house(self) -> usize {
+        match self {
+            EventKind::Marriage => 6,
+            EventKind::Child => 4,
+            EventKind::Career => 9,
+        }
+    }
+
+    pub fn bhumi(self) -> Bhumi {
+        match self {
+            EventKind::Marriage => Bhumi::Si,
+            EventKind::Child => Bhumi::Boriwan,
+            EventKind::Career => Bhumi::Det,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LifeEvent {
+    pub kind: EventKind,
+    pub date: (u32, u32, u32), // วัน เดือน ปี พ.ศ.
+}
+
+#[derive(Debug, PartialEq)]
+pub enum RectifyError {
+    InvalidEvent(String),
+    UnknownEvent(String),
+    NoEvents,
+    InvalidStep(String),
+    EmptyWindow,
+    EventBeforeBirth(LifeEvent),
+    Date(Error),
+}
+
+impl From<Error> for RectifyError {
+    fn from(err: Error) -> RectifyError {
+        RectifyError::Date(err)
+    }
+}
+
+impl RectifyError {
+    pub fn message(&self, lang: Lang) -> String {
+        match self {
+            RectifyError::InvalidEvent(text) => lang.format(Message::InvalidEvent, &[text]),
+            RectifyError::UnknownEvent(key) => {
+                let keys: Vec<&str> = EventKind::ALL.iter().map(|kind| kind.key()).collect();
+                lang.format(Message::UnknownEvent, &[key, &keys.join(", ")])
+            }
+            RectifyError::NoEvents => lang.text(Message::EventRequired).to_string(),
+            RectifyError::InvalidStep(text) => lang.format(Message::InvalidStep, &[text]),
+            RectifyError::EmptyWindow => lang.text(Message::EmptyWindow).to_string(),
+            RectifyError::EventBeforeBirth(event) => {
+                let (d, m, y) = event.date;
+                lang.format(Message::EventBeforeBirth, &[&lang.event(event.kind), &format!("{}/{}/{}", d, m, y)])
+            }
+            RectifyError::Date(err) => err.message(lang),
+        }
+    }
+}
+
+// "marriage:12/5/2555" หรือ "child:3 ก.พ. 2558"
+pub fn parse_event(text: &str) -> Result<LifeEvent, RectifyError> {
+    let (key, date) = text.split_once(':').ok_or_else(|| RectifyError::InvalidEvent(text.trim().to_string()))?;
+    let key = key.trim().to_lowercase();
+    let kind = EventKind::from_key(&key).ok_or(RectifyError::UnknownEvent(key))?;
+    Ok(LifeEvent { kind, date: thaidate::parse_date(date)? })
+}
+
+// คะแนนของเหตุการณ์หนึ่งกับเวลาเกิดหนึ่ง
+pub struct EventScore {
+    pub event: LifeEvent,
+    pub major: Planet, // ดาวเสวยอายุ
+    pub minor: Planet, // ดาวแทรก
+    pub transit: f64,
+    pub thaksa: f64,
+}
+
+impl EventScore {
+    pub fn total(&self) -> f64 {
+        self.transit + self.thaksa
+    }
+}
+
+pub struct Candidate {
+    pub time: (u32, u32),
+    pub lagna: f64,
+    pub birth_planet: Planet,
+    pub events: Vec<EventScore>,
+}
+
+impl Candidate {
+    pub fn score(&self) -> f64 {
+        self.events.iter().map(EventScore::total).sum()
+    }
+
+    pub fn lagna_sign(&self) -> usize {
+        (self.lagna / 30.0) as usize % 12
+    }
+}
+
+fn sign_of(longitude: f64) -> usize {
+    (longitude / 30.0) as usize % 12
+}
+
+fn angular_distance(a: f64, b: f64) -> f64 {
+    let difference = (a - b).rem_euclid(360.0);
+    difference.min(360.0 - difference)
+}
+
+// ดาวจรกับภพ: ราศีภพ ดาวเจ้าเรือนในดวงกำเนิด และองศาต้นภพ (หรือจุดเล็ง)
+fn transit_score(transit: f64, house_sign: usize, lord_sign: usize, cusp: f64) -> f64 {
+    let relation = |natal_sign: usize| Relation::from_distance((natal_sign + 12 - sign_of(transit)) % 12);
+    let mut score = match relation(house_sign) {
+        Some(Relation::Conjunct) => 2.0,
+        Some(Relation::Opposite) => 1.5,
+        Some(Relation::Trine) => 1.0,
+        _ => 0.0,
+    };
+    score += match relation(lord_sign) {
+        Some(Relation::Conjunct) => 1.0,
+        Some(Relation::Opposite) => 0.5,
+        _ => 0.0,
+    };
+    let orb = angular_distance(transit, cusp).min(angular_distance(transit, cusp + 180.0));
+    if orb < CUSP_ORB {
+        score += 2.0 * (1.0 - orb / CUSP_ORB);
+    }
+    score
+}
+
+// ดาวในช่วงมหาทักษากับเหตุการณ์ weight ของดาวเสวยมากกว่าดาวแทรก
+fn thaksa_score(planet: Planet, weight: f64, kind: EventKind, birth: Planet, house_sign: usize, natal_sign: usize) -> f64 {
+    let mut score = 0.0;
+    if bhumi(birth, planet) == kind.bhumi() {
+        score += 2.0;
+    }
+    if natal_sign == house_sign {
+        score += 1.5;
+    }
+    if SIGN_LORDS[house_sign] == planet {
+        score += 1.5;
+    }
+    score * weight
+}
+
+// เวลาเกิดทุก step นาทีจาก from ถึง to (รวมทั้งสองเวลา) ในวันที่ date เรียงจากคะแนนมากไปน้อย
+// คะแนนเท่ากันเรียงตามเวลา
+pub fn rectify(
+    date: (u32, u32, u32),
+    from: (u32, u32),
+    to: (u32, u32),
+    step: u32,
+    prov: &Province,
+    events: &[LifeEvent],
+    thresholds: &MotionThresholds,
+) -> Result<Vec<Candidate>, RectifyError> {
+    if events.is_empty() {
+        return Err(RectifyError::NoEvents);
+    }
+    if step == 0 {
+        return Err(RectifyError::InvalidStep(step.to_string()));
+    }
+    let (first, last) = (from.0 * 60 + from.1, to.0 * 60 + to.1);
+    if first > last {
+        return Err(RectifyError::EmptyWindow);
+    }
+    let (d, m, y) = date;
+    let birth_jd = calculate_jd(d, m, y, from.0, from.1)?;
+
+    // ตำแหน่งดาวจรเที่ยงวันของวันเกิดเหตุ ไม่ขึ้นกับเวลาเกิด คำนวณครั้งเดียว
+    let mut transits = Vec::new();
+    for event in events {
+        let (d, m, y) = event.date;
+        let jd = calculate_jd(d, m, y, 12, 0)?;
+        if jd < birth_jd {
+            return Err(RectifyError::EventBeforeBirth(*event));
+        }
+        let state = SolarState::at(jd);
+        transits.push((jd, TRANSIT_PLANETS.map(|planet| state.longitude(planet))));
+    }
+
+    let mut candidates = Vec::new();
+    for minutes in (first..=last).step_by(step as usize) {
+        let (h, min) = (minutes / 60, minutes % 60);
+        let chart = calculate_thai_ascendant(d, m, y, h, min, prov, thresholds)?;
+        let birth = birth_planet(chart.weekday.thai, chart.weekday.rahu);
+        let lagna_sign = chart.lagna_sign();
+        let natal_sign = |planet: Planet| sign_of(chart.body(planet).longitude);
+
+        let scores = events.iter().zip(&transits).map(|(&event, (jd, longitudes))| {
+            let house = event.kind.house();
+            let house_sign = (lagna_sign + house) % 12;
+            let lord_sign = natal_sign(SIGN_LORDS[house_sign]);
+            let cusp = (chart.lagna + 30.0 * house as f64) % 360.0;
+            let transit = longitudes.iter().map(|&longitude| transit_score(longitude, house_sign, lord_sign, cusp)).sum();
+
+            let (major, minor) = maha_thaksa(birth, (jd - chart.jd) / YEAR_DAYS);
+            let thaksa = thaksa_score(major, 1.0, event.kind, birth, house_sign, natal_sign(major))
+                + thaksa_score(minor, 0.5, event.kind, birth, house_sign, natal_sign(minor));
+            EventScore { event, major, minor, transit, thaksa }
+        }).collect();
+
+        candidates.push(Candidate { time: (h, min), lagna: chart.lagna, birth_planet: birth, events: scores });
+    }
+    candidates.sort_by(|a, b| b.score().total_cmp(&a.score()));
+    Ok(candidates)
+}
+
+fn clock((h, min): (u32, u32)) -> String {
+    format!("{:02}:{:02}", h, min)
+}
+
+fn date_text((d, m, y): (u32, u32, u32)) -> String {
+    format!("{}/{}/{}", d, m, y)
+}
+
+// ตารางเวลาที่ได้คะแนนสูงสุด top อันดับ แล้วแจกแจงเหตุการณ์ของเวลาที่ดีที่สุด
+pub fn to_text(candidates: &[Candidate], top: usize, lang: Lang) -> String {
+    let Some(best) = candidates.first() else { return String::new() };
+    let mut aligns = vec![Align::Right, Align::Left, Align::Left, Align::Right];
+    aligns.extend(best.events.iter().map(|_| Align::Right));
+    let mut table = Table::new(&aligns);
+
+    let mut header: Vec<String> = lang.text(Message::RectifyColumns).split(',').map(str::to_string).collect();
+    header.extend(best.events.iter().map(|score| lang.event(score.event.kind).to_string()));
+    table.row(header);
+    for candidate in candidates.iter().take(top) {
+        let mut row = vec![
+            clock(candidate.time),
+            lang.sign_label(candidate.lagna_sign()),
+            lang.planet(candidate.birth_planet).to_string(),
+            format!("{:.1}", candidate.score()),
+        ];
+        row.extend(candidate.events.iter().map(|score| format!("{:.1}", score.total())));
+        table.row(row);
+    }
+    let mut text = table.render();
+
+    text.push('\n');
+    text.push_str(&lang.format(Message::RectifyBest, &[&clock(best.time), &lang.sign_label(best.lagna_sign())]));
+    text.push('\n');
+    for score in &best.events {
+        let house_sign = (best.lagna_sign() + score.event.kind.house()) % 12;
+        text.push_str(&lang.format(Message::RectifyEvent, &[
+            &lang.event(score.event.kind),
+            &date_text(score.event.date),
+            &lang.house_label(score.event.kind.house()),
+            &lang.sign_label(house_sign),
+            &lang.planet(score.major),
+            &lang.planet(score.minor),
+            &format!("{:.1}", score.transit),
+            &format!("{:.1}", score.thaksa),
+        ]));
+        text.push('\n');
+    }
+    text
+}
+
+// หนึ่งแถวต่อเวลาต่อเหตุการณ์ เรียงตามอันดับ
+pub fn to_csv(candidates: &[Candidate], lang: Lang) -> String {
+    let mut output = csv::line(&[
+        "rank", "time", "lagna", "lagna_sign", "birth_planet", "score",
+        "event", "event_date", "major", "minor", "transit_score", "thaksa_score",
+    ]);
+    for (rank, candidate) in candidates.iter().enumerate() {
+        for score in &candidate.events {
+            output.push_str(&csv::line(&[
+                (rank + 1).to_string(),
+                clock(candidate.time),
+                format!("{:.4}", candidate.lagna),
+                lang.sign(candidate.lagna_sign()).to_string(),
+                candidate.birth_planet.key().to_string(),
+                format!("{:.2}", candidate.score()),
+                score.event.kind.key().to_string(),
+                date_text(score.event.date),
+                score.major.key().to_string(),
+                score.minor.key().to_string(),
+                format!("{:.2}", score.transit),
+                format!("{:.2}", score.thaksa),
+            ]));
+        }
+    }
+    output
+}
+
+pub fn to_json(candidates: &[Candidate], lang: Lang) -> Value {
+    Value::Array(candidates.iter().map(|candidate| {
+        Value::object([
+            ("time", Value::from(clock(candidate.time))),
+            ("lagna", Value::from(candidate.lagna)),
+            ("lagna_sign", Value::from(lang.sign(candidate.lagna_sign()))),
+            ("birth_planet", Value::from(candidate.birth_planet.key())),
+            ("score", Value::from(candidate.score())),
+            ("events", Value::Array(candidate.events.iter().map(|score| {
+                Value::object([
+                    ("event", Value::from(score.event.kind.key())),
+                    ("date", Value::from(date_text(score.event.date))),
+                    ("house", Value::from(lang.house(score.event.kind.house()))),
+                    ("major", Value::from(score.major.key())),
+                    ("major_bhumi", Value::from(bhumi(candidate.birth_planet, score.major).key())),
+                    ("minor", Value::from(score.minor.key())),
+                    ("minor_bhumi", Value::from(bhumi(candidate.birth_planet, score.minor).key())),
+                    ("transit_score", Value::from(score.transit)),
+                    ("thaksa_score", Value::from(score.thaksa)),
+                ])
+            }).collect())),
+        ])
+    }).collect())
+}
@@ -0,0 +1,391 @@
+// บริการ HTTP ในเครื่อง ให้หน้าเว็บหรือ LINE bot เรียกคำนวณได้โดยไม่ต้องเรียกโปรแกรมทีละครั้ง
+// horathai serve [--host 127.0.0.1] [--port 8080] [--slow-ratio 0.5 --fast-ratio 1.5]  (ไม่ต่ออินเทอร์เน็ต ค่าปกติรับเฉพาะจากเครื่องนี้)
+//   GET  /chart?date=3/10/2530&time=15:30&province=bangkok&notation=rasi
+//   POST /chart  {"birth": "3 ต.ค. 2530 เวลา 15:30 น.", "latitude": 13.75, "longitude": 100.5}
+//   GET  /ephemeris?month=10/2569  หรือ ?year=2569  หรือ ?from=1/10/2569&days=30  [&time=06:00]
+//   GET  /provinces
+//   GET  /calendar?date=3/10/2530  หรือ ?lunar=ขึ้น ๕ ค่ำ เดือน ๑๑&year=2530
+// ตัวเลือกชื่อเดียวกับบรรทัดคำสั่ง ส่งเป็น query หรือ JSON object ใน body ก็ได้ (body ทับ query)
+// และเลือกภาษาต่อคำขอด้วย lang=th|en|rtgs  /chart และ /ephemeris รับ slow-ratio fast-ratio ทับเกณฑ์มนฑ์/เสริดที่ตั้งไว้ตอน serve
+// ผลลัพธ์เป็น JSON เสมอ ข้อผิดพลาดตอบ {"error": "..."} พร้อมรหัส 400 404 405 413 หรือ 431
+
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::time::Duration;
+
+use crate::json::{self, Value};
+use crate::locale::{Lang, Message};
+use crate::motion::MotionThresholds;
+use crate::notation::Notation;
+use crate::{calculate_thai_ascendant, check_year, ephemeris, find_province, lunar, provinces, thaidate, Error, Province};
+
+const MAX_HEADER_BYTES: usize = 16 * 1024;
+const MAX_BODY_BYTES: usize = 64 * 1024;
+// ปฏิทินโหรต่อคำขอไม่เกิน ๑๐ ปี
+const MAX_EPHEMERIS_DAYS: u32 = 3660;
+
+struct Request {
+    method: String,
+    path: String,
+    query: String,
+    body: Vec<u8>,
+}
+
+struct HttpError {
+    status: u16,
+    message: String,
+}
+
+impl HttpError {
+    fn new(status: u16, message: String) -> HttpError {
+        HttpError { status, message }
+    }
+}
+
+fn bad_request(message: String) -> HttpError {
+    HttpError::new(400, message)
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        204 => "No Content",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        413 => "Payload Too Large",
+        431 => "Request Header Fields Too Large",
+        _ => "Error",
+    }
+}
+
+pub fn serve(host: &str, port: u16, lang: Lang, thresholds: MotionThresholds) -> std::io::Result<()> {
+    let listener = TcpListener::bind((host, port))?;
+    eprintln!("{}", lang.format(Message::Serving, &[&listener.local_addr()?]));
+    for stream in listener.incoming() {
+        let Ok(stream) = stream else { continue };
+        std::thread::spawn(move || handle_connection(stream, lang, thresholds));
+    }
+    Ok(())
+}
+
+// หนึ่งคำขอต่อการเชื่อมต่อ ตอบแล้วปิด
+fn handle_connection(mut stream: TcpStream, lang: Lang, thresholds: MotionThresholds) {
+    let _ = stream.set_read_timeout(Some(Duration::from_secs(10)));
+    let request = match read_request(&mut stream, lang) {
+        Ok(request) => request,
+        Err(err) => return write_response(&mut stream, err.status, Some(&error_body(&err)), ""),
+    };
+    let allow = allowed_methods(&request.path);
+    let (status, body) = match request.method.as_str() {
+        // ให้หน้าเว็บจากโดเมนอื่นเรียกได้ (CORS preflight)
+        "OPTIONS" if allow.is_some() => (204, None),
+        _ => match route(&request, allow, lang, &thresholds) {
+            Ok(value) => (200, Some(value)),
+            Err(err) => (err.status, Some(error_body(&err))),
+        },
+    };
+    eprintln!("{} {} {}", request.method, request.path, status);
+    write_response(&mut stream, status, body.as_ref(), allow.unwrap_or_default());
+}
+
+fn error_body(err: &HttpError) -> Value {
+    Value::object([("error", Value::from(err.message.as_str()))])
+}
+
+fn write_response(stream: &mut TcpStream, status: u16, body: Option<&Value>, allow: &str) {
+    let body = body.map_or_else(String::new, |value| format!("{}\n", value));
+    let mut head = format!("HTTP/1.1 {} {}\r\n", status, reason(status));
+    if !body.is_empty() {
+        head.push_str("Content-Type: application/json; charset=utf-8\r\n");
+    }
+    if !allow.is_empty() {
+        head.push_str(&format!("Allow: {}\r\n", allow));
+    }
+    head.push_str("Access-Control-Allow-Origin: *\r\n");
+    head.push_str("Access-Control-Allow-Headers: Content-Type\r\n");
+    head.push_str(&format!("Content-Length: {}\r\nConnection: close\r\n\r\n", body.len()));
+    let _ = stream.write_all(head.as_bytes()).and_then(|_| stream.write_all(body.as_bytes()));
+}
+
+fn read_request(stream: &mut TcpStream, lang: Lang) -> Result<Request, HttpError> {
+    let invalid = || bad_request(lang.text(Message::InvalidRequest).to_string());
+    let mut buffer = Vec::new();
+    let mut chunk = [0; 4096];
+    // อ่านจนจบส่วนหัว (บรรทัดว่าง)
+    let header_end = loop {
+        if let Some(i) = buffer.windows(4).position(|w| w == b"\r\n\r\n") {
+            break i;
+        }
+        if buffer.len() > MAX_HEADER_BYTES {
+            return Err(HttpError::new(431, lang.text(Message::HeaderTooLarge).to_string()));
+        }
+        match stream.read(&mut chunk) {
+            Ok(0) | Err(_) => return Err(invalid()),
+            Ok(n) => buffer.extend_from_slice(&chunk[..n]),
+        }
+    };
+
+    let head = std::str::from_utf8(&buffer[..header_end]).map_err(|_| invalid())?;
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next().unwrap_or("").split(' ');
+    let (Some(method), Some(target), Some(version)) = (request_line.next(), request_line.next(), request_line.next()) else {
+        return Err(invalid());
+    };
+    if !version.starts_with("HTTP/1.") {
+        return Err(invalid());
+    }
+    let mut content_length = 0;
+    for line in lines {
+        let Some((name, value)) = line.split_once(':') else { return Err(invalid()) };
+        if name.trim().eq_ignore_ascii_case("content-length") {
+            content_length = value.trim().parse::<usize>().map_err(|_| invalid())?;
+        }
+    }
+    if content_length > MAX_BODY_BYTES {
+        return Err(HttpError::new(413, lang.format(Message::BodyTooLarge, &[&MAX_BODY_BYTES])));
+    }
+
+    let mut body = buffer[header_end + 4..].to_vec();
+    while body.len() < content_length {
+        match stream.read(&mut chunk) {
+            Ok(0) | Err(_) => return Err(invalid()),
+            Ok(n) => body.extend_from_slice(&chunk[..n]),
+        }
+    }
+    body.truncate(content_length);
+
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    Ok(Request { method: method.to_string(), path: path.to_string(), query: query.to_string(), body })
+}
+
+fn allowed_methods(path: &str) -> Option<&'static str> {
+    match path {
+        "/provinces" => Some("GET, OPTIONS"),
+        "/chart" | "/ephemeris" | "/calendar" => Some("GET, POST, OPTIONS"),
+        _ => None,
+    }
+}
+
+fn route(request: &Request, allow: Option<&str>, lang: Lang, thresholds: &MotionThresholds) -> Result<Value, HttpError> {
+    let Some(allow) = allow else {
+        return Err(HttpError::new(404, lang.format(Message::NotFound, &[&request.path])));
+    };
+    if !allow.split(", ").any(|method| method == request.method) {
+        return Err(HttpError::new(405, lang.format(Message::MethodNotAllowed, &[&request.method, &request.path])));
+    }
+    let params = parameters(request, lang)?;
+    let lang = match params.get("lang") {
+        None => lang,
+        Some(key) => Lang::from_key(key).ok_or_else(|| bad_request(lang.format(Message::UnknownLanguage, &[key])))?,
+    };
+    match request.path.as_str() {
+        "/chart" => chart(&params, lang, thresholds),
+        "/ephemeris" => ephemeris(&params, lang, thresholds),
+        "/calendar" => calendar(&params, lang),
+        _ => Ok(province_list()),
+    }
+}
+
+// %XX และ + ใน query string
+fn percent_decode(text: &str) -> Option<String> {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => decoded.push(b' '),
+            b'%' => {
+                let hex = text.get(i + 1..i + 3)?;
+                decoded.push(u8::from_str_radix(hex, 16).ok()?);
+                i += 2;
+            }
+            byte => decoded.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8(decoded).ok()
+}
+
+// รวมค่าจาก query และ JSON body เป็นชื่อ -> ข้อความ (ตัวเลขใน JSON แปลงเป็นข้อความ)
+fn parameters(request: &Request, lang: Lang) -> Result<HashMap<String, String>, HttpError> {
+    let mut params = HashMap::new();
+    for pair in request.query.split('&').filter(|p| !p.is_empty()) {
+        let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+        match (percent_decode(name), percent_decode(value)) {
+            (Some(name), Some(value)) => { params.insert(name, value); }
+            _ => return Err(bad_request(lang.format(Message::InvalidQuery, &[&pair]))),
+        }
+    }
+
+    let body = std::str::from_utf8(&request.body).map_err(|_| bad_request(lang.format(Message::InvalidBody, &[&0])))?;
+    if body.trim().is_empty() {
+        return Ok(params);
+    }
+    let fields = match json::parse(body) {
+        Ok(Value::Object(fields)) => fields,
+        Ok(_) => return Err(bad_request(lang.format(Message::InvalidBody, &[&0]))),
+        Err(err) => return Err(bad_request(lang.format(Message::InvalidBody, &[&err.position]))),
+    };
+    for (name, value) in fields {
+        let text = match value {
+            Value::String(text) => text,
+            Value::Number(_) | Value::Bool(_) => value.to_string(),
+            Value::Null => continue,
+            _ => return Err(bad_request(lang.format(Message::InvalidParameter, &[&name]))),
+        };
+        params.insert(name, text);
+    }
+    Ok(params)
+}
+
+type Params = HashMap<String, String>;
+
+fn get<'a>(params: &'a Params, name: &str) -> Option<&'a str> {
+    params.get(name).map(String::as_str).filter(|value| !value.trim().is_empty())
+}
+
+// วันเวลาเกิดจาก birth หรือ date กับ time (ไม่ระบุเวลาใช้ 06:00 น. เหมือนโหมด batch)
+fn birth(params: &Params, lang: Lang) -> Result<(u32, u32, u32, u32, u32), HttpError> {
+    let message = |err: Error| bad_request(err.message(lang));
+    if let Some(text) = get(params, "birth") {
+        return thaidate::parse_date_time(text).map_err(message);
+    }
+    let Some(date) = get(params, "date") else {
+        return Err(bad_request(lang.format(Message::MissingParameter, &[&"date"])));
+    };
+    let (d, m, y) = thaidate::parse_date(date).map_err(message)?;
+    let (h, min) = match get(params, "time") {
+        Some(time) => thaidate::parse_time(time).map_err(message)?,
+        None => (6, 0),
+    };
+    Ok((d, m, y, h, min))
+}
+
+// จังหวัดจากชื่อภาษาอังกฤษหรือไทย หรือพิกัด latitude/longitude (ไม่ระบุใช้กรุงเทพฯ)
+fn place(params: &Params, lang: Lang) -> Result<Province, HttpError> {
+    let (latitude, longitude) = (get(params, "latitude"), get(params, "longitude"));
+    if latitude.is_some() || longitude.is_some() {
+        let coordinate = |text: Option<&str>, limit: f64| text?.trim().parse::<f64>().ok().filter(|v| v.abs() <= limit);
+        return match (coordinate(latitude, 90.0), coordinate(longitude, 180.0)) {
+            (Some(latitude), Some(longitude)) => Ok(Province { name: "", english_name: "", latitude, longitude }),
+            _ => Err(bad_request(lang.format(Message::InvalidCoordinates, &[
+                &format!("{}, {}", latitude.unwrap_or(""), longitude.unwrap_or("")),
+            ]))),
+        };
+    }
+    find_province(get(params, "province").unwrap_or("bangkok")).map_err(|err| bad_request(err.message(lang)))
+}
+
+fn notation(params: &Params, lang: Lang, default: Notation) -> Result<Notation, HttpError> {
+    match get(params, "notation") {
+        None => Ok(default),
+        Some(key) => Notation::from_key(key).ok_or_else(|| bad_request(lang.format(Message::UnknownNotation, &[&key]))),
+    }
+}
+
+// เกณฑ์ของคำขอนี้ (ไม่ระบุใช้เกณฑ์ที่ตั้งไว้ตอน serve)
+fn thresholds(params: &Params, lang: Lang, base: &MotionThresholds) -> Result<MotionThresholds, HttpError> {
+    base.with_options(|name| get(params, name), lang).map_err(bad_request)
+}
+
+fn chart(params: &Params, lang: Lang, base: &MotionThresholds) -> Result<Value, HttpError> {
+    let birth = birth(params, lang)?;
+    let prov = place(params, lang)?;
+    let notation = notation(params, lang, Notation::Decimal)?;
+    let thresholds = thresholds(params, lang, base)?;
+    let (d, m, y, h, min) = birth;
+    let chart = calculate_thai_ascendant(d, m, y, h, min, &prov, &thresholds).map_err(|err| bad_request(err.message(lang)))?;
+    Ok(chart.to_json(&prov, birth, notation, lang))
+}
+
+fn ephemeris(params: &Params, lang: Lang, base: &MotionThresholds) -> Result<Value, HttpError> {
+    let (start, days) = ephemeris::date_range(|name| get(params, name), lang).map_err(bad_request)?;
+    if days > MAX_EPHEMERIS_DAYS {
+        return Err(bad_request(lang.format(Message::TooManyDays, &[&MAX_EPHEMERIS_DAYS])));
+    }
+    let (hour, minute) = match get(params, "time") {
+        Some(text) => thaidate::parse_time(text).map_err(|err| bad_request(err.message(lang)))?,
+        None => (6, 0),
+    };
+    let notation = notation(params, lang, Notation::Rasi)?;
+    let thresholds = thresholds(params, lang, base)?;
+    let table = ephemeris::ephemeris(start, days, hour, minute, &thresholds);
+    Ok(Value::object([
+        ("time", Value::from(format!("{:02}:{:02}", hour, minute))),
+        ("days", ephemeris::to_json(&table, lang, notation)),
+    ]))
+}
+
+fn calendar(params: &Params, lang: Lang) -> Result<Value, HttpError> {
+    let mut checks = Vec::new();
+    let (d, m, y) = if let Some(text) = get(params, "lunar") {
+        let parsed = lunar::parse_lunar(text).ok_or_else(|| bad_request(lang.format(Message::InvalidLunarDate, &[&text])))?;
+        let year_be = match (parsed.year_be, get(params, "year").map(|y| y.trim().parse::<u32>())) {
+            (Some(year), _) | (None, Some(Ok(year))) => year,
+            (None, Some(Err(_))) => return Err(bad_request(lang.text(Message::InvalidYear).to_string())),
+            (None, None) => return Err(bad_request(lang.format(Message::MissingParameter, &[&"year"]))),
+        };
+        // from_lunar ไล่ทีละปีจาก จ.ศ. 1386 ปีที่ใหญ่มากจะค้างอยู่นาน
+        check_year(year_be as i64).map_err(|err| bad_request(err.message(lang)))?;
+        let date = lunar::from_lunar(parsed.waxing, parsed.day, parsed.month, parsed.second_eighth, year_be)
+            .ok_or_else(|| bad_request(lang.format(Message::NoSuchLunarDay, &[&text, &year_be])))?;
+        // วันและปีนักษัตรที่ระบุมากับข้อความ ตรงกับวันที่ได้หรือไม่
+        let found = lunar::to_lunar(date.0, date.1, date.2);
+        if let Some(weekday) = parsed.weekday {
+            checks.push(("weekday_matches", Value::from(weekday == found.weekday)));
+        }
+        if let Some(zodiac) = parsed.zodiac_year {
+            checks.push(("zodiac_year_matches", Value::from(lunar::ZODIAC_YEAR_NAMES[zodiac] == found.zodiac_year())));
+        }
+        date
+    } else {
+        match get(params, "date") {
+            Some(text) => thaidate::parse_date(text).map_err(|err| bad_request(err.message(lang)))?,
+            None => return Err(bad_request(lang.format(Message::MissingParameter, &[&"date"]))),
+        }
+    };
+
+    let date = lunar::to_lunar(d, m, y);
+    let cs = date.year_be - lunar::BE_CS_OFFSET;
+    let kind = if lunar::is_adhikamasa(cs) {
+        "adhikamasa"
+    } else if lunar::is_adhikavara(cs) {
+        "adhikavara"
+    } else {
+        "normal"
+    };
+    let mut fields = vec![
+        ("date", Value::from(format!("{}/{}/{}", d, m, y))),
+        ("weekday", Value::from(lang.weekday(date.weekday))),
+        ("lunar", Value::object([
+            ("text", Value::from(date.to_thai_string())),
+            ("waxing", Value::from(date.waxing)),
+            ("day", Value::from(date.day)),
+            ("month", Value::from(date.month)),
+            ("second_eighth", Value::from(date.second_eighth)),
+            ("year_be", Value::from(date.year_be)),
+            ("zodiac_year", Value::from(date.zodiac_year())),
+        ])),
+        ("year", Value::object([
+            ("cs", Value::from(cs as f64)),
+            ("kind", Value::from(kind)),
+            ("length", Value::from(lunar::year_length(cs) as f64)),
+        ])),
+    ];
+    fields.extend(checks);
+    Ok(Value::object(fields))
+}
+
+fn province_list() -> Value {
+    let mut list: Vec<(&str, Province)> = provinces().into_iter().collect();
+    list.sort_by_key(|(key, _)| *key);
+    Value::Array(list.into_iter().map(|(key, prov)| Value::object([
+        ("key", Value::from(key)),
+        ("name", Value::from(prov.name)),
+        ("english_name", Value::from(prov.english_name)),
+        ("latitude", Value::from(prov.latitude)),
+        ("longitude", Value::from(prov.longitude)),
+    ])).collect())
+}
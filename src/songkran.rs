@@ -0,0 +1,39 @@
+// มหาสงกรานต์ วันเนา และวันเถลิงศก ตามจุลศักราช (คัมภีร์สุริยยาตร์)
+// ปีสุริยยาตร์ยาว 292207/800 วัน เวลาเถลิงศก = หรคุณ + (800 - กัมมัชพล)/800 วัน
+// มหาสงกรานต์ (อาทิตย์ยกเข้าเมษ) อยู่ก่อนเถลิงศก 2.165 วัน (2 วัน 3 ชม. 57 นาที 36 วินาที)
+
+use crate::lunar::{horakhun, kammachapon, BE_CS_OFFSET};
+
+// ความยาวปีสุริยยาตร์ (วัน)
+pub const YEAR_DAYS: f64 = 292207.0 / 800.0;
+
+// Julian Day (เวลาไทย) ของเที่ยงคืนต้นวันหรคุณ 0
+const CS_EPOCH_JD: f64 = 1954166.5;
+
+const SONGKRAN_BEFORE_THALOENGSOK: f64 = 2.165;
+
+pub struct Songkran {
+    pub cs: i64,               // จุลศักราชที่เริ่มในปีนี้
+    pub moment_jd: f64,        // มหาสงกรานต์
+    pub wan_nao: Vec<f64>,     // วันเนา (เที่ยงคืนต้นวัน) อาจมี 1 หรือ 2 วัน
+    pub thaloengsok_jd: f64,   // เวลาเถลิงศก
+}
+
+// สงกรานต์ของปี พ.ศ. year_be (จ.ศ. ขึ้นใหม่ = พ.ศ. - 1181)
+pub fn songkran(year_be: u32) -> Songkran {
+    let cs = year_be as i64 - BE_CS_OFFSET;
+    let thaloengsok = horakhun(cs) as f64 + (800 - kammachapon(cs)) as f64 / 800.0;
+    let moment = thaloengsok - SONGKRAN_BEFORE_THALOENGSOK;
+
+    // วันเนาคือวันที่อยู่ระหว่างวันมหาสงกรานต์กับวันเถลิงศก
+    let wan_nao = (moment.floor() as i64 + 1..thaloengsok.floor() as i64)
+        .map(|day| CS_EPOCH_JD + day as f64)
+        .collect();
+
+    Songkran {
+        cs,
+        moment_jd: CS_EPOCH_JD + moment,
+        wan_nao,
+        thaloengsok_jd: CS_EPOCH_JD + thaloengsok,
+    }
+}
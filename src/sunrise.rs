@@ -0,0 +1,35 @@
+// เวลาอาทิตย์ขึ้น-ตกจริงตามพิกัด (สูตรดาราศาสตร์แบบย่อ คลาดเคลื่อนราว 1-2 นาที)
+// ใช้ตำแหน่งอาทิตย์แบบสายัน (tropical) ไม่ใช่สุริยยาตร์ เพราะต้องการเวลาบนท้องฟ้าจริง
+
+use crate::julian_day;
+
+// ขอบบนของดวงอาทิตย์แตะขอบฟ้า รวมการหักเหของบรรยากาศ
+const SUNRISE_ALTITUDE: f64 = -0.833;
+
+// เวลาอาทิตย์ขึ้นและตก (นาทีนับจากเที่ยงคืน เวลาไทย UTC+7) ของวันที่ให้มา
+pub fn sunrise_sunset(d: u32, m: u32, y_be: u32, latitude: f64, longitude: f64) -> (f64, f64) {
+    // ใช้ตำแหน่งอาทิตย์ตอนเที่ยงวัน (UT = เที่ยงไทย - 7 ชม.)
+    let n = julian_day(d, m, y_be, 12, 0) - 7.0 / 24.0 - 2451545.0;
+
+    let mean_long = (280.460 + 0.9856474 * n).rem_euclid(360.0);
+    let anomaly = (357.528 + 0.9856003 * n).to_radians();
+    let ecliptic_long = (mean_long + 1.915 * anomaly.sin() + 0.020 * (2.0 * anomaly).sin()).to_radians();
+    let obliquity = (23.439 - 0.0000004 * n).to_radians();
+
+    let declination = (obliquity.sin() * ecliptic_long.sin()).asin();
+    let right_ascension = (obliquity.cos() * ecliptic_long.sin()).atan2(ecliptic_long.cos()).to_degrees().rem_euclid(360.0);
+
+    // สมการเวลา (นาที)
+    let mut equation_of_time = 4.0 * (mean_long - right_ascension);
+    if equation_of_time > 720.0 { equation_of_time -= 1440.0; }
+    if equation_of_time < -720.0 { equation_of_time += 1440.0; }
+
+    // มุมชั่วโมงตอนขึ้น-ตก (ละติจูดในไทยไม่มีวันที่อาทิตย์ไม่ขึ้น)
+    let lat = latitude.to_radians();
+    let cos_h0 = (SUNRISE_ALTITUDE.to_radians().sin() - lat.sin() * declination.sin()) / (lat.cos() * declination.cos());
+    let half_day = cos_h0.clamp(-1.0, 1.0).acos().to_degrees() * 4.0;
+
+    // เที่ยงสุริยะตามเวลาไทย (อิงเส้น 105E)
+    let solar_noon = 720.0 + (105.0 - longitude) * 4.0 - equation_of_time;
+    (solar_noon - half_day, solar_noon + half_day)
+}
@@ -0,0 +1,115 @@
+// วาดดวงชะตาเป็น SVG สำหรับพิมพ์ให้ลูกค้า
+//   wheel:  ราศีจักรวงกลม ๑๒ ช่อง เมษอยู่บนสุด เวียนทวนเข็มนาฬิกา
+//   square: ตาราง ๑๒ ช่องรอบกรอบ ๔x๔ แบบตำรา (ราศีอยู่ช่องเดิมเสมอ)
+// ลัคนาเขียนเป็น ล ดาวเขียนเป็นเลขไทย ๑-๙ และ ๐ ดาวที่พักร์/มนฑ์/เสริดมีอักษรย่อตัวเล็กกำกับ
+
+use std::fmt::Write;
+
+use crate::locale::Lang;
+use crate::motion::MotionState;
+use crate::Chart;
+
+const FONT: &str = "Sarabun, Tahoma, sans-serif";
+
+// ดาวในราศีเดียวกันเรียงไม่เกินบรรทัดละ ๔ ตัว
+const MARKERS_PER_LINE: usize = 4;
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+// ข้อความของดาวในหนึ่งราศี แยกเป็นบรรทัด
+fn marker_lines(contents: &[(String, MotionState)], lang: Lang) -> Vec<String> {
+    contents.chunks(MARKERS_PER_LINE).map(|line| {
+        line.iter().map(|(text, motion)| {
+            let mark = lang.motion_mark(*motion);
+            if mark.is_empty() {
+                text.clone()
+            } else {
+                format!(r#"{}<tspan font-size="60%" baseline-shift="super">{}</tspan>"#, text, mark)
+            }
+        }).collect::<Vec<_>>().join(" ")
+    }).collect()
+}
+
+// เขียนข้อความหลายบรรทัดให้จุดกึ่งกลางอยู่ที่ (x, y)
+fn centered_lines(svg: &mut String, x: f64, y: f64, lines: &[String], size: f64, color: &str) {
+    let top = y - (lines.len() as f64 - 1.0) * size * 0.6;
+    for (i, line) in lines.iter().enumerate() {
+        let _ = writeln!(
+            svg,
+            r#"  <text x="{:.1}" y="{:.1}" font-size="{}" fill="{}" text-anchor="middle" dominant-baseline="middle">{}</text>"#,
+            x, top + i as f64 * size * 1.2, size, color, line,
+        );
+    }
+}
+
+fn header(size: f64) -> String {
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{0}\" height=\"{0}\" viewBox=\"0 0 {0} {0}\" font-family=\"{1}\">\n  <rect width=\"{0}\" height=\"{0}\" fill=\"white\"/>\n",
+        size, FONT,
+    )
+}
+
+pub fn render_wheel(chart: &Chart, title: &str, lang: Lang) -> String {
+    let (cx, cy) = (200.0, 200.0);
+    let (outer, inner) = (185.0, 55.0);
+    let contents = chart.sign_contents(lang);
+    let mut svg = header(400.0);
+
+    let _ = writeln!(svg, r#"  <circle cx="{}" cy="{}" r="{}" fill="none" stroke="black" stroke-width="2"/>"#, cx, cy, outer);
+    let _ = writeln!(svg, r#"  <circle cx="{}" cy="{}" r="{}" fill="none" stroke="black"/>"#, cx, cy, inner);
+
+    // มุมแบบคณิตศาสตร์ (ทวนเข็มจากแกน x) แปลงเป็นพิกัดจอที่ y ชี้ลง
+    let point = |angle: f64, r: f64| (cx + r * angle.to_radians().cos(), cy - r * angle.to_radians().sin());
+
+    for (sign, markers) in contents.iter().enumerate() {
+        // เมษกึ่งกลางอยู่บนสุด (90 องศา) ราศีถัดไปเวียนทวนเข็ม
+        let center = 90.0 + 30.0 * sign as f64;
+        let (x1, y1) = point(center - 15.0, inner);
+        let (x2, y2) = point(center - 15.0, outer);
+        let _ = writeln!(svg, r#"  <line x1="{:.1}" y1="{:.1}" x2="{:.1}" y2="{:.1}" stroke="black"/>"#, x1, y1, x2, y2);
+
+        let (nx, ny) = point(center, outer - 18.0);
+        centered_lines(&mut svg, nx, ny, &[lang.sign(sign).to_string()], 12.0, "gray");
+
+        let (px, py) = point(center, (outer + inner) / 2.0 - 8.0);
+        let color = if sign == chart.lagna_sign() { "darkred" } else { "black" };
+        centered_lines(&mut svg, px, py, &marker_lines(markers, lang), 18.0, color);
+    }
+
+    centered_lines(&mut svg, cx, cy, &[escape(title)], 10.0, "black");
+    svg.push_str("</svg>\n");
+    svg
+}
+
+// ตำแหน่ง (คอลัมน์, แถว) ของแต่ละราศีในตาราง ๔x๔ เรียงจากเมษ
+// มีน เมษ พฤษภ มิถุน อยู่แถวบน แล้ววนตามเข็มนาฬิกา
+pub const SQUARE_CELLS: [(usize, usize); 12] = [
+    (1, 0), (2, 0), (3, 0), (3, 1), (3, 2), (3, 3),
+    (2, 3), (1, 3), (0, 3), (0, 2), (0, 1), (0, 0),
+];
+
+pub fn render_square(chart: &Chart, title: &str, lang: Lang) -> String {
+    let cell = 100.0;
+    let contents = chart.sign_contents(lang);
+    let mut svg = header(cell * 4.0);
+
+    for (sign, &(col, row)) in SQUARE_CELLS.iter().enumerate() {
+        let (x, y) = (col as f64 * cell, row as f64 * cell);
+        let _ = writeln!(svg, r#"  <rect x="{}" y="{}" width="{}" height="{}" fill="none" stroke="black"/>"#, x, y, cell, cell);
+        let _ = writeln!(
+            svg,
+            r#"  <text x="{}" y="{}" font-size="11" fill="gray">{}</text>"#,
+            x + 5.0, y + 14.0, lang.sign(sign),
+        );
+        let color = if sign == chart.lagna_sign() { "darkred" } else { "black" };
+        centered_lines(&mut svg, x + cell / 2.0, y + cell / 2.0 + 6.0, &marker_lines(&contents[sign], lang), 18.0, color);
+    }
+
+    // ช่องกลาง ๒x๒ ใช้เขียนหัวดวง
+    let _ = writeln!(svg, r#"  <rect x="{0}" y="{0}" width="{1}" height="{1}" fill="none" stroke="black" stroke-width="2"/>"#, cell, cell * 2.0);
+    centered_lines(&mut svg, cell * 2.0, cell * 2.0, &[escape(title)], 11.0, "black");
+    svg.push_str("</svg>\n");
+    svg
+}
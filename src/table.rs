@@ -0,0 +1,92 @@
+// จัดตารางข้อความภาษาไทยให้คอลัมน์ตรงกันในเทอร์มินัล
+// สระบน-ล่างและวรรณยุกต์ซ้อนอยู่บนตัวอักษรเดิม นับจำนวน char แบบ {: <5} จึงเพี้ยน
+// ต้องวัดความกว้างที่แสดงจริงแทน
+
+// สระบน-ล่าง (ั ิ ี ึ ื ุ ู ฺ) และวรรณยุกต์/เครื่องหมาย (็ ่ ้ ๊ ๋ ์ ํ ๎) ไม่กินที่
+fn is_combining(c: char) -> bool {
+    matches!(c, '\u{0E31}' | '\u{0E34}'..='\u{0E3A}' | '\u{0E47}'..='\u{0E4E}')
+}
+
+// ความกว้างที่แสดงในเทอร์มินัล (จำนวนคอลัมน์)
+pub fn display_width(text: &str) -> usize {
+    text.chars().filter(|&c| !is_combining(c)).count()
+}
+
+// เติมช่องว่างด้านขวาให้กว้าง width คอลัมน์ (ยาวเกินตัดทิ้ง โดยไม่ทิ้งสระที่ซ้อนอยู่บนตัวสุดท้าย)
+pub fn pad(text: &str, width: usize) -> String {
+    let mut result = String::new();
+    let mut used = 0;
+    for c in text.chars() {
+        let w = if is_combining(c) { 0 } else { 1 };
+        if used + w > width { break; }
+        result.push(c);
+        used += w;
+    }
+    result + &" ".repeat(width - used)
+}
+
+// เติมช่องว่างด้านซ้าย (ชิดขวา) สำหรับตัวเลข
+pub fn pad_left(text: &str, width: usize) -> String {
+    " ".repeat(width.saturating_sub(display_width(text))) + text
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Align {
+    Left,
+    Right,
+}
+
+enum Row {
+    Cells(Vec<String>),
+    Line(String), // บรรทัดแทรกเต็มบรรทัด ไม่นำมาคิดความกว้างคอลัมน์
+}
+
+pub struct Table {
+    aligns: Vec<Align>,
+    rows: Vec<Row>,
+}
+
+impl Table {
+    // aligns: การจัดของแต่ละคอลัมน์ คอลัมน์ที่ไม่ได้ระบุชิดซ้าย
+    pub fn new(aligns: &[Align]) -> Table {
+        Table { aligns: aligns.to_vec(), rows: Vec::new() }
+    }
+
+    pub fn row<S: Into<String>>(&mut self, cells: impl IntoIterator<Item = S>) {
+        self.rows.push(Row::Cells(cells.into_iter().map(Into::into).collect()));
+    }
+
+    pub fn line(&mut self, text: impl Into<String>) {
+        self.rows.push(Row::Line(text.into()));
+    }
+
+    pub fn render(&self) -> String {
+        let mut widths: Vec<usize> = Vec::new();
+        for row in &self.rows {
+            if let Row::Cells(cells) = row {
+                for (i, cell) in cells.iter().enumerate() {
+                    if i == widths.len() { widths.push(0); }
+                    widths[i] = widths[i].max(display_width(cell));
+                }
+            }
+        }
+
+        let mut out = String::new();
+        for row in &self.rows {
+            match row {
+                Row::Cells(cells) => {
+                    let line: Vec<String> = cells.iter().enumerate().map(|(i, cell)| {
+                        match self.aligns.get(i).copied().unwrap_or(Align::Left) {
+                            Align::Left => pad(cell, widths[i]),
+                            Align::Right => pad_left(cell, widths[i]),
+                        }
+                    }).collect();
+                    out.push_str(line.join(" ").trim_end());
+                }
+                Row::Line(text) => out.push_str(text),
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
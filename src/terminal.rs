@@ -0,0 +1,114 @@
+// วาดดวงชะตาในเทอร์มินัลด้วยเส้นกรอบ Unicode แบบ ๑๒ ช่องตามปฏิทินโหร
+// ช่องละหนึ่งราศี (ตำแหน่งเดียวกับ svg::SQUARE_CELLS) ช่องกลาง ๒x๒ เป็นหัวดวง
+// ดาวที่พักร์/มนฑ์/เสริดมีอักษรย่อต่อท้ายเลขดาว เช่น ๕พ
+
+use crate::svg::SQUARE_CELLS;
+use crate::table::{display_width, pad};
+use crate::locale::Lang;
+use crate::Chart;
+
+const CELL_WIDTH: usize = 12;
+const CELL_HEIGHT: usize = 4; // บรรทัดชื่อราศี ๑ + บรรทัดดาว ๓
+const MARKERS_PER_LINE: usize = 4;
+
+// ทิศของเส้นที่ออกจากจุดหนึ่งบนผืนวาด
+const LEFT: u8 = 1;
+const RIGHT: u8 = 2;
+const UP: u8 = 4;
+const DOWN: u8 = 8;
+
+fn box_char(mask: u8) -> &'static str {
+    match mask {
+        m if m == LEFT | RIGHT => "─",
+        m if m == UP | DOWN => "│",
+        m if m == RIGHT | DOWN => "┌",
+        m if m == LEFT | DOWN => "┐",
+        m if m == RIGHT | UP => "└",
+        m if m == LEFT | UP => "┘",
+        m if m == LEFT | RIGHT | DOWN => "┬",
+        m if m == LEFT | RIGHT | UP => "┴",
+        m if m == UP | DOWN | RIGHT => "├",
+        m if m == UP | DOWN | LEFT => "┤",
+        m if m == LEFT | RIGHT | UP | DOWN => "┼",
+        _ => " ",
+    }
+}
+
+// ผืนวาดที่แต่ละตำแหน่งเก็บเป็นสตริง ข้อความยาวจะอยู่ที่ช่องแรกแล้วช่องที่เหลือว่าง
+struct Canvas {
+    cells: Vec<Vec<String>>,
+    lines: Vec<Vec<u8>>,
+}
+
+impl Canvas {
+    fn new(width: usize, height: usize) -> Canvas {
+        Canvas { cells: vec![vec![" ".to_string(); width]; height], lines: vec![vec![0; width]; height] }
+    }
+
+    // กรอบสี่เหลี่ยมจากมุม (x0, y0) ถึง (x1, y1)
+    fn frame(&mut self, x0: usize, y0: usize, x1: usize, y1: usize) {
+        for x in x0..x1 {
+            for y in [y0, y1] {
+                self.lines[y][x] |= RIGHT;
+                self.lines[y][x + 1] |= LEFT;
+            }
+        }
+        for y in y0..y1 {
+            for x in [x0, x1] {
+                self.lines[y][x] |= DOWN;
+                self.lines[y + 1][x] |= UP;
+            }
+        }
+    }
+
+    fn text(&mut self, x: usize, y: usize, width: usize, text: &str) {
+        self.cells[y][x] = pad(text, width);
+        for cell in &mut self.cells[y][x + 1..x + width] {
+            cell.clear();
+        }
+    }
+
+    fn render(&self) -> String {
+        let mut out = String::new();
+        for (cells, lines) in self.cells.iter().zip(&self.lines) {
+            for (cell, &mask) in cells.iter().zip(lines) {
+                out.push_str(if mask != 0 { box_char(mask) } else { cell });
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+// title: บรรทัดที่เขียนในช่องกลาง
+pub fn draw_chart(chart: &Chart, title: &[String], lang: Lang) -> String {
+    let (step_x, step_y) = (CELL_WIDTH + 1, CELL_HEIGHT + 1);
+    let mut canvas = Canvas::new(4 * step_x + 1, 4 * step_y + 1);
+    let contents = chart.sign_contents(lang);
+
+    for (sign, &(col, row)) in SQUARE_CELLS.iter().enumerate() {
+        let (x, y) = (col * step_x, row * step_y);
+        canvas.frame(x, y, x + step_x, y + step_y);
+        canvas.text(x + 1, y + 1, CELL_WIDTH, lang.sign(sign));
+
+        let markers: Vec<String> = contents[sign].iter()
+            .map(|(text, motion)| format!("{}{}", text, lang.motion_mark(*motion)))
+            .collect();
+        for (i, line) in markers.chunks(MARKERS_PER_LINE).take(CELL_HEIGHT - 1).enumerate() {
+            canvas.text(x + 1, y + 2 + i, CELL_WIDTH, &format!(" {}", line.join(" ")));
+        }
+    }
+
+    // ช่องกลางรวม ๒x๒ ช่อง เขียนหัวดวงกึ่งกลางแนวตั้ง
+    let (x0, y0) = (step_x, step_y);
+    canvas.frame(x0, y0, x0 + 2 * step_x, y0 + 2 * step_y);
+    let inner_width = 2 * step_x - 1;
+    let inner_height = 2 * step_y - 1;
+    let top = y0 + 1 + inner_height.saturating_sub(title.len()) / 2;
+    for (i, line) in title.iter().take(inner_height).enumerate() {
+        let left = inner_width.saturating_sub(display_width(line)) / 2;
+        canvas.text(x0 + 1, top + i, inner_width, &format!("{}{}", " ".repeat(left), line));
+    }
+
+    canvas.render()
+}
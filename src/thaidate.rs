@@ -0,0 +1,208 @@
+// อ่านวันเวลาเกิดแบบที่เจ้าหน้าที่ได้รับมาจริง เช่น
+//   3/10/2530   ๓/๑๐/๒๕๓๐   3 ต.ค. 2530   วันเสาร์ที่ 3 ตุลาคม พ.ศ. 2530   3 ต.ค. 1987 (ค.ศ.)
+//   15:30   15.30 น.   ๑๕.๓๐ น.   15 นาฬิกา 30 นาที
+// ปีที่ไม่มี พ.ศ./ค.ศ. กำกับถือเป็น พ.ศ. เฉพาะเมื่อเป็นไปไม่ได้ที่จะเป็น ค.ศ. (ตั้งแต่ 2200 ขึ้นไป)
+// ปี ๒ หลัก เช่น 30 ไม่รู้ว่าเป็น 2430 หรือ 2530 จึงไม่เดาให้
+// จ.ศ. ร.ศ. ม.ศ. ยังไม่แปลงให้ แจ้งเป็น Error::UnsupportedEra แทนที่จะอ่านผิดเป็น พ.ศ.
+
+use crate::calendar::{self, Calendar};
+use crate::locale::{Lang, Message};
+use crate::numerals::to_arabic_digits;
+use crate::{check_date_time, julian_day, Error, WEEKDAY_NAMES};
+
+const BE_CE_OFFSET: u32 = 543;
+
+// ปีที่ไม่มีตัวกำกับ ตั้งแต่ปีนี้ขึ้นไปถือเป็น พ.ศ. ต่ำกว่านี้อาจเป็น ค.ศ. ก็ได้
+const UNMARKED_BE_FROM: u32 = 2200;
+
+const MONTH_NAMES: [&str; 12] = [
+    "มกราคม", "กุมภาพันธ์", "มีนาคม", "เมษายน", "พฤษภาคม", "มิถุนายน",
+    "กรกฎาคม", "สิงหาคม", "กันยายน", "ตุลาคม", "พฤศจิกายน", "ธันวาคม",
+];
+
+// ชื่อย่อเดือนเมื่อตัดจุดออก (ม.ค. -> มค)
+const MONTH_ABBREVIATIONS: [&str; 12] = ["มค", "กพ", "มีค", "เมย", "พค", "มิย", "กค", "สค", "กย", "ตค", "พย", "ธค"];
+
+// ศักราชอื่นที่พบในเอกสารเก่า (คำที่ตัดจุดแล้ว, ชื่อที่แสดง)
+const OTHER_ERAS: [(&str, &str); 3] = [("จศ", "จ.ศ."), ("รศ", "ร.ศ."), ("มศ", "ม.ศ.")];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DateError {
+    InvalidDate(String),
+    InvalidTime(String),
+    UnknownWord(String),
+    TwoDigitYear(u32),
+    AmbiguousYear(u32),
+    NoSuchDay { day: u32, month: u32, year_be: i64 },
+    NoSuchTime { hour: u32, minute: u32 },
+    WeekdayMismatch { given: usize, actual: usize },
+}
+
+impl DateError {
+    pub fn message(&self, lang: Lang) -> String {
+        match self {
+            DateError::InvalidDate(text) => lang.format(Message::InvalidDate, &[text]),
+            DateError::InvalidTime(text) => lang.format(Message::InvalidTime, &[text]),
+            DateError::UnknownWord(word) => lang.format(Message::UnknownDateWord, &[word]),
+            DateError::TwoDigitYear(year) => lang.format(Message::TwoDigitYear, &[year]),
+            DateError::AmbiguousYear(year) => lang.format(Message::AmbiguousYear, &[year, year, year]),
+            DateError::NoSuchDay { day, month, year_be } => lang.format(Message::NoSuchDay, &[day, month, year_be]),
+            DateError::NoSuchTime { hour, minute } => lang.format(Message::NoSuchTime, &[&format!("{:02}:{:02}", hour, minute)]),
+            DateError::WeekdayMismatch { given, actual } => {
+                lang.format(Message::WeekdayMismatch, &[&lang.weekday_label(*actual), &lang.weekday_label(*given)])
+            }
+        }
+    }
+}
+
+enum Token {
+    Number(u32, usize), // ค่าและจำนวนหลัก
+    Word(String),
+}
+
+// แยกข้อความเป็นกลุ่มตัวเลขและกลุ่มตัวอักษร / - , และช่องว่างเป็นตัวคั่น
+fn tokenize(text: &str) -> Vec<Token> {
+    let text = to_arabic_digits(text);
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let flush = |current: &mut String, tokens: &mut Vec<Token>| {
+        if current.is_empty() { return; }
+        let token = match current.parse::<u32>() {
+            Ok(value) => Token::Number(value, current.len()),
+            // จุดในชื่อย่อไม่มีความหมาย ม.ค. = มค
+            Err(_) => Token::Word(current.replace('.', "")),
+        };
+        if !matches!(&token, Token::Word(w) if w.is_empty()) {
+            tokens.push(token);
+        }
+        current.clear();
+    };
+
+    let mut last_digit = None;
+    for c in text.chars() {
+        if c.is_whitespace() || matches!(c, '/' | '-' | ',' | '(' | ')') {
+            flush(&mut current, &mut tokens);
+            last_digit = None;
+            continue;
+        }
+        // 3ต.ค.2530 ไม่มีช่องว่างก็แยกได้
+        let digit = c.is_ascii_digit();
+        if last_digit.is_some_and(|d| d != digit) {
+            flush(&mut current, &mut tokens);
+        }
+        current.push(c);
+        last_digit = Some(digit);
+    }
+    flush(&mut current, &mut tokens);
+    tokens
+}
+
+fn month_from_word(word: &str) -> Option<u32> {
+    MONTH_NAMES.iter().position(|&m| m == word)
+        .or_else(|| MONTH_ABBREVIATIONS.iter().position(|&m| m == word))
+        .map(|i| i as u32 + 1)
+}
+
+// ก่อน ค.ศ. 1582 ใช้ปีอธิกสุรทินแบบจูเลียน (ดู calendar.rs)
+pub fn days_in_month(month: u32, year_be: u32) -> u32 {
+    calendar::days_in_month((year_be as i64 - BE_CE_OFFSET as i64) as i32, month, Calendar::Historical)
+}
+
+// วันที่เป็น (วัน, เดือน, ปี พ.ศ.)
+pub fn parse_date(text: &str) -> Result<(u32, u32, u32), Error> {
+    let invalid = || DateError::InvalidDate(text.trim().to_string());
+    let mut numbers: Vec<(u32, usize)> = Vec::new();
+    let mut month_word = None;
+    let mut weekday = None;
+    let mut era: Option<bool> = None; // true = พ.ศ., false = ค.ศ.
+
+    for token in tokenize(text) {
+        match token {
+            Token::Number(value, digits) => numbers.push((value, digits)),
+            Token::Word(word) => {
+                let lower = word.to_lowercase();
+                if let Some(month) = month_from_word(&word) {
+                    if month_word.replace(month).is_some() { return Err(invalid().into()); }
+                    // เลขก่อนชื่อเดือนคือวัน ตัวที่เหลือคือปี
+                    if numbers.len() != 1 { return Err(invalid().into()); }
+                } else if word == "พศ" || lower == "be" {
+                    era = Some(true);
+                } else if word == "คศ" || lower == "ce" || lower == "ad" {
+                    era = Some(false);
+                } else if let Some(&(_, era)) = OTHER_ERAS.iter().find(|(key, _)| *key == word) {
+                    return Err(Error::UnsupportedEra(era.to_string()));
+                } else if let Some(name) = word.strip_prefix("วัน") {
+                    let name = name.strip_suffix("ที่").unwrap_or(name);
+                    if name.is_empty() { continue; }
+                    weekday = Some(WEEKDAY_NAMES.iter().position(|&w| w == name)
+                        .ok_or_else(|| DateError::UnknownWord(word.clone()))?);
+                } else if !matches!(word.as_str(), "ที่" | "เกิด" | "วันที่") {
+                    return Err(DateError::UnknownWord(word).into());
+                }
+            }
+        }
+    }
+
+    let (day, month, (year, year_digits)) = match (month_word, numbers.as_slice()) {
+        (Some(month), &[(day, _), year]) => (day, month, year),
+        (None, &[(day, _), (month, _), year]) => (day, month, year),
+        _ => return Err(invalid().into()),
+    };
+
+    let year_be = match era {
+        Some(true) => year,
+        // ปี ค.ศ. ที่ใหญ่มากบวก 543 แล้วล้น u32 ก็อยู่นอกช่วงที่คำนวณได้อยู่แล้ว
+        Some(false) => year.checked_add(BE_CE_OFFSET)
+            .ok_or(Error::YearOutOfRange { year_be: year as i64 + BE_CE_OFFSET as i64 })?,
+        None if year_digits <= 2 => return Err(DateError::TwoDigitYear(year).into()),
+        None if year < UNMARKED_BE_FROM => return Err(DateError::AmbiguousYear(year).into()),
+        None => year,
+    };
+
+    check_date_time(day, month, year_be, 0, 0)?;
+    if let Some(given) = weekday {
+        let actual = (julian_day(day, month, year_be, 12, 0) as i64 + 1).rem_euclid(7) as usize;
+        if given != actual {
+            return Err(DateError::WeekdayMismatch { given, actual }.into());
+        }
+    }
+    Ok((day, month, year_be))
+}
+
+// เวลาเป็น (ชั่วโมง, นาที)
+pub fn parse_time(text: &str) -> Result<(u32, u32), Error> {
+    let invalid = || DateError::InvalidTime(text.trim().to_string());
+    let mut numbers = Vec::new();
+    for token in tokenize(&text.replace(':', " ")) {
+        match token {
+            Token::Number(value, _) => numbers.push(value),
+            // 15.30 น. -> 15 . 30 น ; จุดถูกตัดทิ้งตอนแยกคำ
+            Token::Word(word) if matches!(word.as_str(), "น" | "นาฬิกา" | "นาที" | "เวลา" | "โมง") => {}
+            Token::Word(_) => return Err(invalid().into()),
+        }
+    }
+    let (hour, minute) = match numbers[..] {
+        [hour] => (hour, 0),
+        [hour, minute] => (hour, minute),
+        _ => return Err(invalid().into()),
+    };
+    if hour > 23 || minute > 59 {
+        return Err(DateError::NoSuchTime { hour, minute }.into());
+    }
+    Ok((hour, minute))
+}
+
+// วันและเวลาในข้อความเดียว เช่น "3 ต.ค. 2530 เวลา 15:30 น."
+// แยกที่คำว่า เวลา หรือถ้าไม่มี ใช้คำที่มี : เป็นต้นของเวลา เช่น "3/10/2530 15:30 น."
+pub fn parse_date_time(text: &str) -> Result<(u32, u32, u32, u32, u32), Error> {
+    let (date, time) = match text.split_once("เวลา") {
+        Some(parts) => parts,
+        None => match text.find(':') {
+            Some(colon) => text.split_at(text[..colon].rfind(char::is_whitespace).unwrap_or(0)),
+            None => return Err(DateError::InvalidTime(text.trim().to_string()).into()),
+        },
+    };
+    let (d, m, y) = parse_date(date)?;
+    let (h, min) = parse_time(time)?;
+    Ok((d, m, y, h, min))
+}
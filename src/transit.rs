@@ -0,0 +1,103 @@
+// ดวงจร: นำดาว ณ อีกวันเวลาหนึ่งมาวางลงในภพของดวงกำเนิด
+// แล้วหาความสัมพันธ์แบบนับราศี เช่น เสาร์จรทับลัคนา ราหูจรเล็งจันทร์
+
+use crate::motion::MotionState;
+use crate::planet::Planet;
+use crate::Chart;
+
+// ภพทั้ง ๑๒ นับจากราศีลัคนา (ตนุ = ราศีเดียวกับลัคนา)
+pub const HOUSE_NAMES: [&str; 12] = [
+    "ตนุ", "กดุมภะ", "สหัชชะ", "พันธุ", "ปุตตะ", "อริ",
+    "ปัตนิ", "มรณะ", "ศุภะ", "กัมมะ", "ลาภะ", "วินาศ",
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Relation {
+    Conjunct, // ทับ: อยู่ราศีเดียวกัน
+    Opposite, // เล็ง: ห่าง ๗ ราศี
+    Trine,    // ตรีโกณ: ห่าง ๕ หรือ ๙ ราศี
+    Square,   // ฉาก: ห่าง ๔ หรือ ๑๐ ราศี
+    Sextile,  // โยค: ห่าง ๓ หรือ ๑๑ ราศี
+}
+
+impl Relation {
+    pub fn thai_name(self) -> &'static str {
+        match self {
+            Relation::Conjunct => "ทับ",
+            Relation::Opposite => "เล็ง",
+            Relation::Trine => "ตรีโกณ",
+            Relation::Square => "ฉาก",
+            Relation::Sextile => "โยค",
+        }
+    }
+
+    // ระยะห่างเป็นจำนวนราศี (0 = ราศีเดียวกัน)
+    pub(crate) fn from_distance(distance: usize) -> Option<Relation> {
+        match distance {
+            0 => Some(Relation::Conjunct),
+            6 => Some(Relation::Opposite),
+            4 | 8 => Some(Relation::Trine),
+            3 | 9 => Some(Relation::Square),
+            2 | 10 => Some(Relation::Sextile),
+            _ => None,
+        }
+    }
+}
+
+// จุดในดวงกำเนิดที่ดาวจรไปสัมพันธ์ด้วย
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NatalPoint {
+    Lagna,
+    Planet(Planet),
+}
+
+impl NatalPoint {
+    pub fn thai_name(self) -> &'static str {
+        match self {
+            NatalPoint::Lagna => "ลัคนา",
+            NatalPoint::Planet(planet) => planet.thai_name(),
+        }
+    }
+}
+
+pub struct TransitAspect {
+    pub relation: Relation,
+    pub natal: NatalPoint,
+}
+
+// ดาวจรหนึ่งดวงเมื่อวางลงในดวงกำเนิด
+pub struct TransitPlacement {
+    pub planet: Planet,
+    pub longitude: f64,
+    pub motion: MotionState,
+    pub house: usize, // ภพในดวงกำเนิด 0 = ตนุ
+    pub aspects: Vec<TransitAspect>,
+}
+
+fn sign_of(longitude: f64) -> usize {
+    (longitude / 30.0) as usize % 12
+}
+
+pub fn overlay(natal: &Chart, transit: &Chart) -> Vec<TransitPlacement> {
+    let lagna_sign = natal.lagna_sign();
+    // จุดกำเนิดทั้งหมด: ลัคนาก่อน ตามด้วยดาว ๑-๐
+    let natal_points: Vec<(NatalPoint, usize)> = std::iter::once((NatalPoint::Lagna, lagna_sign))
+        .chain(natal.bodies.iter().map(|b| (NatalPoint::Planet(b.planet), sign_of(b.longitude))))
+        .collect();
+
+    transit.bodies.iter().map(|body| {
+        let sign = sign_of(body.longitude);
+        let aspects = natal_points.iter().filter_map(|&(natal, natal_sign)| {
+            Relation::from_distance((natal_sign + 12 - sign) % 12)
+                .map(|relation| TransitAspect { relation, natal })
+        }).collect();
+
+        TransitPlacement {
+            planet: body.planet,
+            longitude: body.longitude,
+            motion: body.motion,
+            house: (sign + 12 - lagna_sign) % 12,
+            aspects,
+        }
+    }).collect()
+}
@@ -0,0 +1,26 @@
+// วันเกิดทางโหราศาสตร์ไทย: วันเปลี่ยนตอนอาทิตย์ขึ้น ไม่ใช่เที่ยงคืน
+// เช่น เกิด 04:00 น. วันอังคาร นับเป็นคนเกิดวันจันทร์
+// วันพุธหลังอาทิตย์ตกจนถึงอาทิตย์ขึ้นวันพฤหัสบดี นับเป็นวันพุธกลางคืน (ราหู)
+
+use crate::julian_day;
+use crate::sunrise::sunrise_sunset;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BirthWeekday {
+    pub civil: usize, // วันตามปฏิทิน 0 = อาทิตย์
+    pub thai: usize,  // วันทางโหราศาสตร์
+    pub rahu: bool,   // พุธกลางคืน
+}
+
+pub fn birth_weekday(d: u32, m: u32, y_be: u32, h: u32, min: u32, latitude: f64, longitude: f64) -> BirthWeekday {
+    let civil = (julian_day(d, m, y_be, 12, 0) as i64 + 1).rem_euclid(7) as usize;
+    let (sunrise, sunset) = sunrise_sunset(d, m, y_be, latitude, longitude);
+    let minute_of_day = (h * 60 + min) as f64;
+
+    // ก่อนอาทิตย์ขึ้นยังเป็นกลางคืนของวันก่อน
+    let before_sunrise = minute_of_day < sunrise;
+    let thai = if before_sunrise { (civil + 6) % 7 } else { civil };
+    let night = before_sunrise || minute_of_day >= sunset;
+
+    BirthWeekday { civil, thai, rahu: thai == 3 && night }
+}
@@ -0,0 +1,57 @@
+// ยามอัฐกาล: แบ่งกลางวัน (อาทิตย์ขึ้น-ตก) และกลางคืน (อาทิตย์ตก-ขึ้นวันรุ่งขึ้น) ออกเป็นอย่างละ ๘ ยาม
+// ใช้เวลาอาทิตย์ขึ้น-ตกจริงของจังหวัด ไม่ใช่ 06:00/18:00 ตายตัว
+// ดาวประจำยามกลางวันเริ่มที่ดาวประจำวัน แล้วเวียน ๑ ๖ ๔ ๒ ๗ ๕ ๓
+// กลางคืนเริ่มที่ดาวลำดับที่ ๕ นับจากดาวประจำวัน แล้วเวียน ๑ ๕ ๒ ๖ ๓ ๗ ๔
+// ยามที่ ๘ กลับมาเป็นดาวเดียวกับยามแรก
+
+use crate::planet::Planet;
+use crate::sunrise::sunrise_sunset;
+use crate::{jd_to_date, julian_day};
+
+pub struct Yam {
+    pub number: usize, // ยามที่ ๑-๘
+    pub night: bool,
+    pub start: f64,    // นาทีนับจากเที่ยงคืนของวันที่ขอ (กลางคืนเกิน 1440 ได้)
+    pub end: f64,
+    pub ruler: Planet,
+    pub favourable: bool,
+}
+
+// ยามที่ตกศุภเคราะห์ (จันทร์ พุธ พฤหัสบดี ศุกร์) ถือเป็นยามดี
+fn is_favourable(planet: Planet) -> bool {
+    matches!(planet, Planet::Moon | Planet::Mercury | Planet::Jupiter | Planet::Venus)
+}
+
+// ดาว ๑-๗ ตามเลขประจำวัน (0 = อาทิตย์)
+fn weekday_planet(weekday: usize) -> Planet {
+    Planet::ALL[weekday % 7]
+}
+
+fn divide(start: f64, end: f64, night: bool, first: usize, step: usize) -> Vec<Yam> {
+    let length = (end - start) / 8.0;
+    (0..8).map(|i| {
+        let ruler = weekday_planet(first + (i % 7) * step);
+        Yam {
+            number: i + 1,
+            night,
+            start: start + i as f64 * length,
+            end: start + (i + 1) as f64 * length,
+            ruler,
+            favourable: is_favourable(ruler),
+        }
+    }).collect()
+}
+
+// ยามอัฐกาลของวันที่ให้มา ๑๖ ยาม (กลางวัน ๘ ตามด้วยกลางคืน ๘)
+pub fn yam_atthakan(d: u32, m: u32, y_be: u32, latitude: f64, longitude: f64) -> Vec<Yam> {
+    let jd = julian_day(d, m, y_be, 12, 0);
+    let weekday = (jd as i64 + 1).rem_euclid(7) as usize;
+    let (sunrise, sunset) = sunrise_sunset(d, m, y_be, latitude, longitude);
+    let (next_d, next_m, next_y, _, _) = jd_to_date(jd + 1.0);
+    let (next_sunrise, _) = sunrise_sunset(next_d, next_m, next_y, latitude, longitude);
+
+    // ๑ ๖ ๔ ๒ ๗ ๕ ๓ คือเดินหน้าทีละ ๕ วัน / ๑ ๕ ๒ ๖ ๓ ๗ ๔ คือเดินหน้าทีละ ๔ วัน
+    let mut yams = divide(sunrise, sunset, false, weekday, 5);
+    yams.extend(divide(sunset, next_sunrise + 1440.0, true, weekday + 4, 4));
+    yams
+}
@@ -0,0 +1,32 @@
+// ตารางอ้างอิงของ horathai accuracy: ช่องว่างคือไม่มีค่า และไม่นับเป็นจุดที่ตรวจ
+
+use horathai::accuracy::{self, Coverage, BUNDLED_REFERENCE};
+use horathai::planet::Planet;
+
+#[test]
+fn blank_cells_are_not_compared() {
+    let reference = accuracy::parse_reference("date,time,sun,mars\n1/1/2543,06:00,256.0,\n1/2/2543,06:00,287.0,300.0\n").unwrap();
+    assert_eq!(reference.rows[0].longitudes, vec![Some(256.0), None]);
+
+    let report = accuracy::compare(&reference, &[]);
+    let mars = report.iter().find(|body| body.planet == Planet::Mars).unwrap();
+    assert_eq!(mars.overall.count, 1);
+    assert_eq!((mars.overall.first, mars.overall.last), ((1, 2, 2543), (1, 2, 2543)));
+    assert_eq!(
+        accuracy::partial(&reference, &report),
+        vec![Coverage { first: (1, 2, 2543), last: (1, 2, 2543), planets: vec![Planet::Mars] }],
+    );
+}
+
+// Keplerian elements ที่ใช้สร้างตารางใช้ได้ถึง ค.ศ. 2050 (พ.ศ. 2593) เท่านั้น
+#[test]
+fn bundled_planet_rows_stay_within_the_elements_range() {
+    let reference = accuracy::parse_reference(BUNDLED_REFERENCE).unwrap();
+    let report = accuracy::compare(&reference, &[]);
+    for body in &report {
+        match body.planet {
+            Planet::Sun | Planet::Moon | Planet::Rahu => assert_eq!(body.overall.count, reference.rows.len()),
+            _ => assert!(body.overall.last.2 <= 2593, "{:?} มีค่าถึง {:?}", body.planet, body.overall.last),
+        }
+    }
+}
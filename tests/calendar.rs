@@ -0,0 +1,92 @@
+// แปลงวันที่กับ Julian Day ข้ามการปฏิรูปปฏิทิน ค.ศ. 1582 และย้อนไปก่อน ค.ศ.
+// ค่าอ้างอิงจาก Meeus, Astronomical Algorithms บทที่ 7
+
+use horathai::calendar::{self, Calendar};
+use horathai::thaidate::{self, DateError};
+use horathai::{calculate_jd, calculate_jd_astronomical, jd_to_astronomical_date, jd_to_date, Error};
+
+#[test]
+fn julian_days_from_meeus() {
+    // (ปีดาราศาสตร์, เดือน, วัน, Julian Day ของ 0:00)
+    let examples = [
+        (2000, 1, 1, 2451544.5),
+        (1987, 1, 27, 2446822.5),
+        (1988, 6, 19, 2447331.5),
+        (1600, 1, 1, 2305447.5),
+        (1600, 12, 31, 2305812.5),
+        (837, 4, 10, 2026871.5),
+        (333, 1, 27, 1842712.5),
+        (-123, 12, 31, 1676496.5),
+        (-122, 1, 1, 1676497.5),
+        (-1000, 7, 12, 1356000.5),
+        (-1000, 2, 29, 1355866.5),
+        (-4712, 1, 1, -0.5),
+    ];
+    for (year, month, day, jd) in examples {
+        assert_eq!(calendar::julian_day(year, month, day, Calendar::Historical), jd, "{}/{}/{}", day, month, year);
+        assert_eq!(calendar::from_julian_day(jd, Calendar::Historical), (year, month, day), "JD {}", jd);
+    }
+}
+
+// 4 ต.ค. 1582 (จูเลียน) ตามด้วย 15 ต.ค. 1582 (เกรกอเรียน)
+#[test]
+fn reform_skips_ten_days() {
+    assert_eq!(calculate_jd(4, 10, 2125, 0, 0), Ok(2299159.5));
+    assert_eq!(calculate_jd(15, 10, 2125, 0, 0), Ok(2299160.5));
+    for day in 5..15 {
+        assert_eq!(
+            calculate_jd(day, 10, 2125, 0, 0),
+            Err(Error::InvalidDate(DateError::NoSuchDay { day, month: 10, year_be: 2125 })),
+        );
+    }
+    assert_eq!(jd_to_date(2299160.0), (4, 10, 2125, 12, 0));
+    assert_eq!(jd_to_date(2299160.5), (15, 10, 2125, 0, 0));
+}
+
+#[test]
+fn leap_years_follow_the_calendar_in_use() {
+    // 1500 เป็นอธิกสุรทินตามจูเลียน 1700 ไม่เป็นตามเกรกอเรียน
+    assert!(calculate_jd(29, 2, 2043, 0, 0).is_ok());
+    assert!(calculate_jd(29, 2, 2243, 0, 0).is_err());
+    assert!(thaidate::parse_date("29/2/1500 ค.ศ.").is_ok());
+    assert!(calendar::is_leap_year(1500, Calendar::Julian));
+    assert!(!calendar::is_leap_year(1500, Calendar::Gregorian));
+    assert!(calendar::is_leap_year(-4, Calendar::Historical)); // 5 ปีก่อน ค.ศ.
+    assert!(calendar::is_valid_date(1582, 10, 10, Calendar::Gregorian));
+    assert!(calendar::is_valid_date(1582, 10, 10, Calendar::Julian));
+    assert!(!calendar::is_valid_date(1582, 10, 10, Calendar::Historical));
+}
+
+// ปฏิทินแบบ proleptic ทั้งสองแบบต่างกันเท่าที่ควร (10 วันในปี 1582, 2 วันในปี 1 ก่อน ค.ศ.)
+#[test]
+fn proleptic_calendars() {
+    let difference = |year, month, day| calendar::julian_day(year, month, day, Calendar::Julian) - calendar::julian_day(year, month, day, Calendar::Gregorian);
+    assert_eq!(difference(1582, 10, 15), 10.0);
+    assert_eq!(difference(2000, 1, 1), 13.0);
+    assert_eq!(difference(0, 1, 1), -2.0);
+    assert_eq!(calendar::from_julian_day(2299159.5, Calendar::Gregorian), (1582, 10, 14));
+    assert_eq!(calendar::from_julian_day(2451544.5, Calendar::Julian), (1999, 12, 19));
+}
+
+// วันเวลา -> JD -> วันเวลา ได้ค่าเดิมทุกวันตั้งแต่ JD 0 ถึง ค.ศ. 9999
+#[test]
+fn round_trip_over_the_whole_range() {
+    let last = calculate_jd_astronomical(31, 12, calendar::MAX_YEAR, 0, 0).unwrap();
+    let mut jd = 0.5;
+    while jd <= last {
+        let (day, month, year, hour, minute) = jd_to_astronomical_date(jd + 0.25);
+        assert_eq!((hour, minute), (6, 0));
+        assert_eq!(calculate_jd_astronomical(day, month, year, 0, 0), Ok(jd), "{}/{}/{}", day, month, year);
+        jd += 1.0;
+    }
+}
+
+// ก่อน พ.ศ. 0 ปี พ.ศ. แบบ u32 แทนไม่ได้ ต้องใช้ปีดาราศาสตร์
+#[test]
+fn buddhist_and_astronomical_years() {
+    assert_eq!(calculate_jd(1, 1, 1, 0, 0), calculate_jd_astronomical(1, 1, -542, 0, 0));
+    let jd = calculate_jd_astronomical(18, 2, -3101, 6, 0).unwrap(); // กลียุค 18 ก.พ. 3102 ปีก่อน ค.ศ.
+    assert_eq!(jd, 588465.75);
+    assert_eq!(jd_to_astronomical_date(jd), (18, 2, -3101, 6, 0));
+    assert_eq!(jd_to_date(jd).2, 0);
+}
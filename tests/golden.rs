@@ -0,0 +1,127 @@
+// ชุดดวงอ้างอิง (golden charts) กันไม่ให้การปรับค่าคงที่ของดาวดวงหนึ่งทำให้วันอื่นเพี้ยนไปโดยไม่รู้ตัว
+//
+// tests/data/golden_charts.csv: วันที่ (พ.ศ.) เวลาไทย จังหวัด ที่มาของค่า max_error แล้วตามด้วยลองจิจูดลัคนาและดาวแต่ละดวง (องศา 0-360)
+// หัวคอลัมน์ของดาวใช้ชื่อเดียวกับ Planet::key() จะเพิ่มแถวหรือเรียงคอลัมน์ใหม่ก็ได้ ช่องว่างคือไม่มีค่าให้เทียบ
+//
+// ที่มา "recorded" คือค่าที่บันทึกจากผลคำนวณของโปรแกรมเอง ครอบคลุม พ.ศ. 2443-2643 (ค.ศ. 1900-2100)
+// ใช้กันการถดถอยด้วยเกณฑ์แคบตามดาว (max_error ว่าง) ถ้าตั้งใจแก้สูตรจนค่าเปลี่ยน ให้บันทึกค่าใหม่ลงไฟล์ในคอมมิตเดียวกัน พร้อมเหตุผล
+// ค่าลัคนาทุกค่ายังเป็น recorded ยังไม่มีตัวอย่างลัคนาจากตำราไทยที่อ้างอิงที่มาได้
+// เมื่อได้มาให้เพิ่มเป็นแถวใหม่พร้อมชื่อตำราและหน้า
+//
+// แถวอื่นเป็นค่าที่ตีพิมพ์ แปลงเป็นนิรายนะด้วยอยนางศ์ลาหิรีแบบเดียวกับ accuracy.rs (23.853 องศา ณ J2000 บวก 50.29 ฟิลิปดาต่อปี)
+//   Meeus 1998: Astronomical Algorithms พิมพ์ครั้งที่ 2 ตัวอย่างตามเลขที่ระบุ (ตำแหน่งปรากฏ ณ 0h TD แปลงเป็นเวลาสากลด้วย ΔT)
+//     ตัวอย่าง 22.a คือจุดโหนดขึ้นเฉลี่ยของจันทร์ (ราหู) ตัวอย่าง 27.a คือเวลาครีษมายันปี ค.ศ. 1962
+//   USNO Earth's Seasons: เวลาวิษุวัตและอายันจากหอดูดาวกองทัพเรือสหรัฐ อาทิตย์อยู่ที่ 0 90 180 270 องศาสายนะพอดี
+//   NASA: เวลาดาวเคราะห์ตรงข้ามอาทิตย์ (อาทิตย์ + 180) การผ่านหน้าดวงอาทิตย์ และสุริยุปราคา (เท่ากับอาทิตย์)
+//     จันทรุปราคา (อาทิตย์ + 180) ตำแหน่งอาทิตย์ ณ เวลานั้นคิดด้วยสูตรของ Meeus บทที่ 25 (คลาดราว 0.01 องศา)
+// สูตรของโปรแกรมเป็นแบบเฉลี่ยตามตำราไทย จึงคลาดจากค่าที่ตีพิมพ์ได้มาก (ดู horathai accuracy)
+// max_error ของแถวเหล่านี้คือความคลาดปัจจุบันปัดขึ้นเป็นทศนิยมหนึ่งตำแหน่งแล้วบวก 0.1 องศา
+// สูตรที่เพี้ยนไปจากเดิมจึงถูกจับได้ ถ้าแก้สูตรจนเข้าใกล้ค่าที่ตีพิมพ์ ให้ลด max_error ตามลงไป
+
+use horathai::motion::MotionThresholds;
+use horathai::planet::Planet;
+use horathai::{calculate_thai_ascendant, csv, provinces, thaidate, Chart};
+
+const DATA: &str = include_str!("data/golden_charts.csv");
+
+// ความคลาดเคลื่อนที่ยอมได้ (องศา) เทียบกับค่าที่บันทึกเอง ตามความเร็วของดาว
+// ลัคนาเดินราว 1 องศาต่อ 4 นาที จันทร์ราว 1 องศาต่อ 2 ชั่วโมง จึงกว้างกว่าดาวช้า
+fn recorded_tolerance(column: &str) -> f64 {
+    match column {
+        "lagna" => 0.25,
+        "moon" => 0.1,
+        "mercury" | "venus" | "mars" => 0.05,
+        "sun" => 0.02,
+        _ => 0.01, // พฤหัสบดี เสาร์ ราหู เกตุ มฤตยู
+    }
+}
+
+// ระยะห่างเชิงมุมที่สั้นที่สุด (359.9 กับ 0.1 ห่างกัน 0.2 องศา)
+fn angular_distance(a: f64, b: f64) -> f64 {
+    let difference = (a - b).rem_euclid(360.0);
+    difference.min(360.0 - difference)
+}
+
+fn chart_at(date: &str, time: &str, province: &str) -> Chart {
+    let (d, m, y) = thaidate::parse_date(date).unwrap_or_else(|_| panic!("วันที่ไม่ถูกต้อง: {}", date));
+    let (h, min) = thaidate::parse_time(time).unwrap_or_else(|_| panic!("เวลาไม่ถูกต้อง: {}", time));
+    let place = provinces().remove(province).unwrap_or_else(|| panic!("ไม่รู้จักจังหวัด: {}", province));
+    calculate_thai_ascendant(d, m, y, h, min, &place, &MotionThresholds::default()).expect("วันเวลาในดวงอ้างอิงต้องคำนวณได้")
+}
+
+#[test]
+fn golden_charts() {
+    let records = csv::parse(DATA);
+    let (header, rows) = records.split_first().expect("ไฟล์ดวงอ้างอิงว่าง");
+    let columns = &header.fields[5..];
+    for column in columns {
+        assert!(column == "lagna" || Planet::from_key(column).is_some(), "คอลัมน์ {} ไม่ใช่ lagna หรือชื่อดาว", column);
+    }
+    assert!(rows.len() >= 20, "ดวงอ้างอิงน้อยเกินไป: {} ดวง", rows.len());
+
+    // รวบรวมทุกค่าที่เกินเกณฑ์ก่อน จะได้เห็นว่าเพี้ยนเฉพาะดาวใดหรือช่วงปีใด
+    let mut failures = Vec::new();
+    for row in rows {
+        let [date, time, province, source, max_error] = [0, 1, 2, 3, 4].map(|i| row.fields[i].as_str());
+        let max_error = match max_error {
+            "" => None,
+            text => Some(text.parse::<f64>().unwrap_or_else(|_| panic!("บรรทัด {}: max_error {} ไม่ใช่ตัวเลข", row.line, text))),
+        };
+        assert!(source == "recorded" || max_error.is_some(), "บรรทัด {}: ค่าที่ตีพิมพ์ต้องระบุ max_error", row.line);
+        let tolerance = |column: &str| max_error.unwrap_or_else(|| recorded_tolerance(column));
+        let chart = chart_at(date, time, province);
+        for (column, expected) in columns.iter().zip(&row.fields[5..]) {
+            if expected.is_empty() {
+                continue;
+            }
+            let expected: f64 = expected.parse().unwrap_or_else(|_| panic!("บรรทัด {}: {} ไม่ใช่ตัวเลข", row.line, expected));
+            let actual = match Planet::from_key(column) {
+                Some(planet) => chart.body(planet).longitude,
+                None => chart.lagna,
+            };
+            let error = angular_distance(actual, expected);
+            if error > tolerance(column) {
+                failures.push(format!(
+                    "บรรทัด {} {} {} {} ({}): {} ได้ {:.4} ควรเป็น {:.4} (ต่าง {:.4} เกิน {})",
+                    row.line, date, time, province, source, column, actual, expected, error, tolerance(column)
+                ));
+            }
+        }
+    }
+    assert!(failures.is_empty(), "ตำแหน่งเปลี่ยนจากดวงอ้างอิง {} ค่า:\n{}", failures.len(), failures.join("\n"));
+}
+
+// ค่าที่ใช้ปรับ mean motion ของดาวศุกร์ (ดูความเห็นใน calculate_venus_position)
+#[test]
+fn venus_tuning_reference() {
+    let chart = chart_at("3/10/2530", "15:30", "bangkok");
+    let venus = chart.body(Planet::Venus).longitude;
+    assert!(angular_distance(venus, 174.25) < 0.25, "ศุกร์ 3 ต.ค. 2530 ได้ {:.4} ควรใกล้ 174.25", venus);
+}
+
+// ตามตำรา เมื่ออาทิตย์ขึ้น (06:00 เวลาท้องถิ่น) ลัคนาอยู่ที่องศาเดียวกับอาทิตย์
+// กรุงเทพฯ ลองจิจูด 100.5 เวลาท้องถิ่นช้ากว่าเวลาไทย 18 นาที จึงเป็น 06:18 น.
+// ตรวจเพียงว่าโปรแกรมทำตามกฎนี้ ไม่ได้เทียบกับลัคนาที่ตีพิมพ์ จึงไม่ได้ยืนยันความแม่นของลัคนา
+#[test]
+fn lagna_equals_sun_at_local_sunrise() {
+    for date in ["1/1/2443", "13/4/2500", "3/10/2530", "22/6/2567", "31/12/2643"] {
+        let chart = chart_at(date, "06:18", "bangkok");
+        let sun = chart.body(Planet::Sun).longitude;
+        assert!(angular_distance(chart.lagna, sun) < 0.01, "{}: ลัคนา {:.4} อาทิตย์ {:.4}", date, chart.lagna, sun);
+    }
+}
+
+// ลัคนาเดินครบ ๑๒ ราศีในหนึ่งวัน ทุกสองชั่วโมงโดยเฉลี่ยต้องขยับไปข้างหน้าเสมอ
+#[test]
+fn lagna_advances_through_the_day() {
+    let mut previous = chart_at("3/10/2530", "06:18", "bangkok").lagna;
+    let mut travelled = 0.0;
+    for hour in [8, 10, 12, 14, 16, 18, 20, 22] {
+        let lagna = chart_at("3/10/2530", &format!("{}:18", hour), "bangkok").lagna;
+        let step = (lagna - previous).rem_euclid(360.0);
+        assert!(step > 0.0 && step < 90.0, "{}:18 ลัคนาขยับ {:.4} องศา", hour, step);
+        travelled += step;
+        previous = lagna;
+    }
+    assert!(travelled < 360.0, "ลัคนาเดินเกินหนึ่งรอบใน 16 ชั่วโมง: {:.4}", travelled);
+}
@@ -0,0 +1,13 @@
+// include/horathai.h ที่แจกไปกับซอร์สต้องตรงกับที่ build.rs สร้างจาก src/ffi.rs
+
+const GENERATED: &str = include_str!(concat!(env!("OUT_DIR"), "/horathai.h"));
+const COMMITTED: &str = include_str!("../include/horathai.h");
+
+#[test]
+fn committed_header_matches_ffi() {
+    assert!(
+        GENERATED == COMMITTED,
+        "include/horathai.h ไม่ตรงกับ src/ffi.rs ให้คัดลอกไฟล์ใหม่ด้วย\n  cp {}/horathai.h include/horathai.h",
+        env!("OUT_DIR"),
+    );
+}
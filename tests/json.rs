@@ -0,0 +1,33 @@
+// อ่าน JSON: ค่าพื้นฐาน การซ้อน และตำแหน่งที่อ่านไม่ได้
+
+use horathai::json::{self, ParseError, Value};
+
+#[test]
+fn parses_nested_values() {
+    let value = json::parse(r#" {"birth": "3 ต.ค. 2530", "latitude": 13.75, "tags": [true, null, "ก"]} "#).unwrap();
+    assert_eq!(
+        value,
+        Value::Object(vec![
+            ("birth".to_string(), Value::String("3 ต.ค. 2530".to_string())),
+            ("latitude".to_string(), Value::Number(13.75)),
+            ("tags".to_string(), Value::Array(vec![Value::Bool(true), Value::Null, Value::String("ก".to_string())])),
+        ]),
+    );
+}
+
+#[test]
+fn reports_position_of_invalid_input() {
+    assert_eq!(json::parse(r#"{"a": }"#), Err(ParseError { position: 6 }));
+    assert_eq!(json::parse("[1, 2"), Err(ParseError { position: 5 }));
+    assert_eq!(json::parse("[1] x"), Err(ParseError { position: 4 }));
+}
+
+#[test]
+fn limits_nesting_depth() {
+    let nested = |depth: usize| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+    assert!(json::parse(&nested(64)).is_ok());
+    assert_eq!(json::parse(&nested(65)), Err(ParseError { position: 64 }));
+    // ไม่ปิดวงเล็บ ต้องหยุดที่ชั้นที่ 65 ไม่ใช่ไล่ลงไปจนสแต็กล้น
+    assert_eq!(json::parse(&"[".repeat(60_000)), Err(ParseError { position: 64 }));
+    assert_eq!(json::parse(&"{\"a\":".repeat(60_000)), Err(ParseError { position: 64 * 5 }));
+}
@@ -0,0 +1,68 @@
+// แฟ้มลูกดวง: เลขที่ไม่ซ้ำกับที่ลบไปแล้ว และอ่านไฟล์ที่เขียนเองหรือแก้ด้วยมือ
+
+use horathai::json;
+use horathai::motion::MotionThresholds;
+use horathai::notation::Notation;
+use horathai::profile::{Place, Profile, Store, StoreError};
+
+fn profile(name: &str) -> Profile {
+    Profile {
+        id: 0,
+        name: name.to_string(),
+        date: (3, 10, 2530),
+        time: (15, 30),
+        place: Place::Province("bangkok".to_string()),
+        notation: Notation::Decimal,
+        thresholds: MotionThresholds::default(),
+        note: String::new(),
+    }
+}
+
+fn load(text: &str) -> Result<Store, StoreError> {
+    Store::from_json(&json::parse(text).unwrap())
+}
+
+#[test]
+fn removed_ids_are_not_reused() {
+    let mut store = Store::default();
+    for name in ["A", "B", "C"] {
+        store.add(profile(name));
+    }
+    store.remove(3);
+    assert_eq!(store.add(profile("D")), 4);
+
+    // เลขที่ถัดไปติดไปกับไฟล์ แม้โปรไฟล์เลขที่มากที่สุดถูกลบ
+    store.remove(4);
+    let mut reloaded = load(&store.to_json().to_string()).unwrap();
+    assert_eq!(reloaded.add(profile("E")), 5);
+}
+
+#[test]
+fn files_without_next_id_continue_from_the_largest_id() {
+    let text = r#"{"format": "horathai-profiles", "version": 1, "profiles": [
+        {"id": 7, "name": "A", "birth": {"date": "3/10/2530", "time": "15:30"}, "place": {"province": "bangkok"}}
+    ]}"#;
+    let mut store = load(text).unwrap();
+    assert_eq!(store.add(profile("B")), 8);
+}
+
+#[test]
+fn motion_ratios_are_validated() {
+    let with_options = |options: &str| {
+        load(&format!(
+            r#"{{"format": "horathai-profiles", "version": 1, "profiles": [
+                {{"id": 1, "name": "A", "birth": {{"date": "3/10/2530", "time": "15:30"}}, "place": {{"province": "bangkok"}}, "options": {}}}
+            ]}}"#,
+            options
+        ))
+    };
+    assert!(with_options(r#"{"slow_ratio": 0.3, "fast_ratio": 2}"#).is_ok());
+    assert!(matches!(
+        with_options(r#"{"slow_ratio": -1}"#),
+        Err(StoreError::InvalidField(field)) if field == "profiles[0].options.slow_ratio"
+    ));
+    assert!(matches!(
+        with_options(r#"{"slow_ratio": 2, "fast_ratio": 1}"#),
+        Err(StoreError::InvalidField(field)) if field == "profiles[0].options"
+    ));
+}
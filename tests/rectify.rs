@@ -0,0 +1,81 @@
+// ตั้งเวลาเกิดจากเหตุการณ์ในชีวิต: ลำดับมหาทักษา ภูมิทักษา และการเรียงเวลาตามคะแนน
+
+use horathai::motion::MotionThresholds;
+use horathai::planet::Planet;
+use horathai::rectify::{self, Bhumi, EventKind, LifeEvent, RectifyError};
+use horathai::{find_province, Error};
+
+#[test]
+fn maha_thaksa_periods_follow_birth_planet() {
+    // เกิดวันพุธกลางวัน: พุธ 17 ปี เสาร์ 10 ปี พฤหัสบดี 19 ปี ...
+    assert_eq!(rectify::maha_thaksa(Planet::Mercury, 0.0), (Planet::Mercury, Planet::Mercury));
+    assert_eq!(rectify::maha_thaksa(Planet::Mercury, 16.9).0, Planet::Mercury);
+    assert_eq!(rectify::maha_thaksa(Planet::Mercury, 17.0), (Planet::Saturn, Planet::Saturn));
+    assert_eq!(rectify::maha_thaksa(Planet::Mercury, 31.0).0, Planet::Jupiter);
+    // ดาวแทรกแรกของพุธยาว 17 x 17 / 108 = 2.68 ปี ตามด้วยเสาร์
+    assert_eq!(rectify::maha_thaksa(Planet::Mercury, 2.6).1, Planet::Mercury);
+    assert_eq!(rectify::maha_thaksa(Planet::Mercury, 2.7).1, Planet::Saturn);
+    // ศุกร์เป็นดาวสุดท้ายก่อนครบรอบ ๑๐๘ ปี ดาวแทรกสุดท้ายของศุกร์คือราหู
+    assert_eq!(rectify::maha_thaksa(Planet::Sun, 107.9), (Planet::Venus, Planet::Rahu));
+    assert_eq!(rectify::maha_thaksa(Planet::Sun, 108.0), (Planet::Sun, Planet::Sun));
+}
+
+#[test]
+fn bhumi_counts_from_birth_planet() {
+    assert_eq!(rectify::bhumi(Planet::Sun, Planet::Sun), Bhumi::Boriwan);
+    assert_eq!(rectify::bhumi(Planet::Sun, Planet::Mercury), Bhumi::Si);
+    assert_eq!(rectify::bhumi(Planet::Mercury, Planet::Jupiter), Bhumi::Det);
+    assert_eq!(rectify::bhumi(Planet::Moon, Planet::Sun), Bhumi::Kalakini);
+    assert_eq!(rectify::birth_planet(3, true), Planet::Rahu);
+    assert_eq!(rectify::birth_planet(6, false), Planet::Saturn);
+}
+
+#[test]
+fn parses_events() {
+    assert_eq!(
+        rectify::parse_event("marriage:12/5/2555"),
+        Ok(LifeEvent { kind: EventKind::Marriage, date: (12, 5, 2555) }),
+    );
+    assert_eq!(rectify::parse_event("job: 1 มิ.ย. 2560").map(|e| e.kind), Ok(EventKind::Career));
+    assert_eq!(rectify::parse_event("wedding:1/1/2550"), Err(RectifyError::UnknownEvent("wedding".to_string())));
+    assert_eq!(rectify::parse_event("12/5/2555"), Err(RectifyError::InvalidEvent("12/5/2555".to_string())));
+    assert!(matches!(rectify::parse_event("child:31/2/2558"), Err(RectifyError::Date(Error::InvalidDate(_)))));
+}
+
+#[test]
+fn ranks_every_candidate_in_the_window() {
+    let prov = find_province("bangkok").unwrap();
+    let thresholds = MotionThresholds::default();
+    let events = [
+        rectify::parse_event("marriage:12/5/2555").unwrap(),
+        rectify::parse_event("child:3/2/2558").unwrap(),
+        rectify::parse_event("career:1/6/2560").unwrap(),
+    ];
+    let candidates = rectify::rectify((22, 4, 2524), (14, 30), (17, 30), 4, &prov, &events, &thresholds).unwrap();
+
+    assert_eq!(candidates.len(), 46);
+    assert!(candidates.windows(2).all(|pair| pair[0].score() >= pair[1].score()));
+    for candidate in &candidates {
+        assert_eq!(candidate.events.len(), events.len());
+        assert_eq!(candidate.birth_planet, Planet::Mercury);
+        // อายุ 31 ปีตอนแต่งงาน อยู่ในช่วงพฤหัสบดีเสวยอายุ
+        assert_eq!(candidate.events[0].major, Planet::Jupiter);
+    }
+    // ลัคนาเดียวกันยังแยกกันได้ด้วยระยะจากองศาต้นภพ
+    let scores: Vec<f64> = candidates.iter().map(|c| c.score()).collect();
+    assert!(scores.first() > scores.last());
+}
+
+#[test]
+fn rejects_invalid_input() {
+    let prov = find_province("bangkok").unwrap();
+    let thresholds = MotionThresholds::default();
+    let marriage = rectify::parse_event("marriage:12/5/2555").unwrap();
+    let run = |from, to, step, events: &[LifeEvent]| rectify::rectify((22, 4, 2524), from, to, step, &prov, events, &thresholds).err();
+
+    assert_eq!(run((9, 0), (10, 0), 4, &[]), Some(RectifyError::NoEvents));
+    assert_eq!(run((9, 0), (10, 0), 0, &[marriage]), Some(RectifyError::InvalidStep("0".to_string())));
+    assert_eq!(run((10, 0), (9, 0), 4, &[marriage]), Some(RectifyError::EmptyWindow));
+    let early = LifeEvent { kind: EventKind::Child, date: (1, 1, 2520) };
+    assert_eq!(run((9, 0), (10, 0), 4, &[marriage, early]), Some(RectifyError::EventBeforeBirth(early)));
+}
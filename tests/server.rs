@@ -0,0 +1,54 @@
+// บริการ HTTP ผ่านการเชื่อมต่อจริงบนพอร์ตว่างของเครื่อง
+
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use horathai::locale::Lang;
+use horathai::motion::MotionThresholds;
+use horathai::server;
+
+// เปิดบริการครั้งเดียวต่อชุดทดสอบ คืนพอร์ต
+fn port() -> u16 {
+    static PORT: OnceLock<u16> = OnceLock::new();
+    *PORT.get_or_init(|| {
+        let port = TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+        std::thread::spawn(move || server::serve("127.0.0.1", port, Lang::English, MotionThresholds::default()));
+        port
+    })
+}
+
+fn request(method: &str, path: &str, body: &str) -> String {
+    let mut stream = (0..50)
+        .find_map(|_| TcpStream::connect(("127.0.0.1", port())).ok().or_else(|| {
+            std::thread::sleep(Duration::from_millis(20));
+            None
+        }))
+        .expect("เชื่อมต่อบริการไม่ได้");
+    write!(stream, "{} {} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{}", method, path, body.len(), body).unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    response
+}
+
+#[test]
+fn answers_chart_requests() {
+    let response = request("POST", "/chart", r#"{"date": "3/10/2530", "time": "15:30"}"#);
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"), "{}", response);
+}
+
+#[test]
+fn rejects_deeply_nested_body() {
+    let response = request("POST", "/chart", &"[".repeat(60_000));
+    assert!(response.starts_with("HTTP/1.1 400 Bad Request\r\n"), "{}", response);
+    // บริการยังตอบคำขอถัดไปได้
+    assert!(request("GET", "/provinces", "").starts_with("HTTP/1.1 200 OK\r\n"));
+}
+
+#[test]
+fn rejects_inverted_motion_ratios() {
+    let response = request("GET", "/chart?date=3/10/2530&time=15:30&slow-ratio=2&fast-ratio=1", "");
+    assert!(response.starts_with("HTTP/1.1 400 Bad Request\r\n"), "{}", response);
+    assert!(response.contains("slow-ratio 2 >= fast-ratio 1"), "{}", response);
+}
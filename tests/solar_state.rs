@@ -0,0 +1,25 @@
+// SolarState กับ calculate_bodies ต้องได้ผลเดียวกับฟังก์ชันตำแหน่งดาวทีละดวง
+
+use horathai::motion::{motion_state, MotionState, MotionThresholds};
+use horathai::planet::Planet;
+use horathai::{calculate_bodies, SolarState};
+
+// SolarState คำนวณโลกครั้งเดียวแล้วใช้ร่วมกัน ต้องได้ค่าเดียวกับฟังก์ชันทีละดวงทุกบิต
+#[test]
+fn shared_state_matches_single_planet_functions() {
+    let thresholds = MotionThresholds::default();
+    let mut jd = 2415020.5; // 1 ม.ค. 2443
+    while jd < 2488069.5 {  // 1 ม.ค. 2643
+        let longitudes = SolarState::at(jd).longitudes();
+        for (body, (&planet, longitude)) in calculate_bodies(jd, &thresholds).iter().zip(Planet::ALL.iter().zip(longitudes)) {
+            assert_eq!(longitude, planet.longitude(jd), "{:?} JD {}", planet, jd);
+            assert_eq!(body.longitude, longitude, "{:?} JD {}", planet, jd);
+            let motion = match planet.mean_motion() {
+                Some(mean) => motion_state(|t| planet.longitude(t), jd, mean, &thresholds),
+                None => MotionState::Normal,
+            };
+            assert_eq!(body.motion, motion, "{:?} JD {}", planet, jd);
+        }
+        jd += 37.3;
+    }
+}
@@ -0,0 +1,72 @@
+// ข้อมูลวันเวลาและสถานที่ที่ผิดต้องได้ Error ที่ตรงชนิด ไม่ panic และไม่คำนวณต่อเงียบ ๆ
+
+use horathai::locale::Lang;
+use horathai::motion::MotionThresholds;
+use horathai::thaidate::{self, DateError};
+use horathai::{calculate_jd, calculate_jd_astronomical, calculate_thai_ascendant, check_year, find_province, Error, MAX_YEAR_BE, MIN_YEAR_BE};
+
+#[test]
+fn impossible_dates_are_rejected() {
+    assert_eq!(
+        calculate_jd(31, 2, 2567, 0, 0),
+        Err(Error::InvalidDate(DateError::NoSuchDay { day: 31, month: 2, year_be: 2567 })),
+    );
+    assert!(calculate_jd(29, 2, 2567, 0, 0).is_ok());
+    assert!(matches!(calculate_jd(29, 2, 2566, 0, 0), Err(Error::InvalidDate(DateError::NoSuchDay { .. }))));
+    assert!(matches!(calculate_jd(1, 13, 2567, 0, 0), Err(Error::InvalidDate(_))));
+    assert!(matches!(calculate_jd(1, 1, 2567, 24, 0), Err(Error::InvalidDate(DateError::NoSuchTime { hour: 24, minute: 0 }))));
+    assert!(matches!(thaidate::parse_date("31 ก.พ. 2567"), Err(Error::InvalidDate(DateError::NoSuchDay { .. }))));
+}
+
+// ปีก่อน ค.ศ. 1 เคยทำให้ y_be - 543 ของ u32 panic ตอนนี้คำนวณได้ถึง 4713 ปีก่อน ค.ศ.
+#[test]
+fn years_outside_the_supported_range_are_errors() {
+    for year_be in [0, 1, 543] {
+        assert!(calculate_jd(1, 1, year_be, 0, 0).is_ok(), "พ.ศ. {}", year_be);
+    }
+    for year_be in [MAX_YEAR_BE as u32 + 1, u32::MAX] {
+        assert_eq!(calculate_jd(1, 1, year_be, 0, 0), Err(Error::YearOutOfRange { year_be: year_be as i64 }));
+    }
+    assert!(calculate_jd(31, 12, MAX_YEAR_BE as u32, 23, 59).is_ok());
+    assert!(calculate_jd_astronomical(1, 1, (MIN_YEAR_BE - 543) as i32, 0, 0).is_ok());
+    assert_eq!(calculate_jd_astronomical(31, 12, -4713, 0, 0), Err(Error::YearOutOfRange { year_be: MIN_YEAR_BE - 1 }));
+    assert_eq!(thaidate::parse_date("1/1/12000 พ.ศ."), Err(Error::YearOutOfRange { year_be: 12000 }));
+    assert_eq!(thaidate::parse_date("1/1/4294967295 ค.ศ."), Err(Error::YearOutOfRange { year_be: 4294967838 }));
+    assert_eq!(check_year(MAX_YEAR_BE), Ok(()));
+    assert_eq!(check_year(u32::MAX as i64), Err(Error::YearOutOfRange { year_be: u32::MAX as i64 }));
+
+    let bangkok = find_province("bangkok").unwrap();
+    let chart = calculate_thai_ascendant(1, 1, 20000, 6, 0, &bangkok, &MotionThresholds::default());
+    assert!(matches!(chart, Err(Error::YearOutOfRange { year_be: 20000 })));
+}
+
+#[test]
+fn other_eras_are_reported_not_misread() {
+    assert_eq!(thaidate::parse_date("1 ม.ค. จ.ศ. 1349"), Err(Error::UnsupportedEra("จ.ศ.".to_string())));
+    assert_eq!(thaidate::parse_date("ร.ศ. 100 1/1"), Err(Error::UnsupportedEra("ร.ศ.".to_string())));
+}
+
+#[test]
+fn provinces_are_found_by_key_or_name() {
+    for name in ["chiangmai", "ChiangMai", "เชียงใหม่", "Chiang Mai", " chiang mai "] {
+        assert_eq!(find_province(name).map(|p| p.english_name), Ok("Chiang Mai"), "{}", name);
+    }
+    assert_eq!(find_province("narnia").map(|p| p.name), Err(Error::UnknownPlace("narnia".to_string())));
+}
+
+#[test]
+fn motion_ratios_must_keep_slow_below_fast() {
+    let options = |pairs: &'static [(&'static str, &'static str)]| {
+        MotionThresholds::default().with_options(|name| pairs.iter().find(|(key, _)| *key == name).map(|(_, value)| *value), Lang::English)
+    };
+    let thresholds = options(&[("slow-ratio", "0.3"), ("fast-ratio", "2")]).unwrap();
+    assert_eq!((thresholds.slow_ratio, thresholds.fast_ratio), (0.3, 2.0));
+    assert_eq!(options(&[("fast-ratio", "-1")]).err().as_deref(), Some("Invalid ratio: -1"));
+    assert_eq!(options(&[("slow-ratio", "inf")]).err().as_deref(), Some("Invalid ratio: inf"));
+    assert_eq!(
+        options(&[("slow-ratio", "2"), ("fast-ratio", "1")]).err().as_deref(),
+        Some("Invalid ratio: slow-ratio 2 >= fast-ratio 1"),
+    );
+    // ระบุตัวเดียวก็เทียบกับอีกตัวที่คงค่าเดิม
+    assert!(options(&[("fast-ratio", "0.5")]).is_err());
+}